@@ -0,0 +1,56 @@
+use cpuid::cpuid::System;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use std::hint::black_box;
+use std::path::PathBuf;
+
+fn dump_path(name: &str) -> String {
+    let mut pathbuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    pathbuf.push("resources/test/dumps");
+    pathbuf.push(name);
+    pathbuf.as_path().to_str().unwrap().to_string()
+}
+
+// The Rome dump covers 256 logical CPUs and is by far the largest fixture.
+const DUMPS: [(&str, &str); 3] = [
+    ("rome", "AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt"),
+    ("knightslanding", "GenuineIntel/GenuineIntel0050671_KnightsLanding_CPUID.txt"),
+    ("tigerlake", "GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"),
+];
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    for (name, path) in DUMPS.iter() {
+        let path = dump_path(path);
+        group.bench_function(*name, |b| b.iter(|| System::from_file(black_box(&path)).unwrap()));
+    }
+    group.finish();
+}
+
+fn bench_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");
+    for (name, path) in DUMPS.iter() {
+        let path = dump_path(path);
+        group.bench_function(*name, |b| {
+            b.iter_batched(
+                || System::from_file(&path).unwrap(),
+                |system| system.with_decoded(),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_parse_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_decode");
+    for (name, path) in DUMPS.iter() {
+        let path = dump_path(path);
+        group.bench_function(*name, |b| {
+            b.iter(|| System::from_file(black_box(&path)).unwrap().with_decoded())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_decode, bench_parse_decode);
+criterion_main!(benches);
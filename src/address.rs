@@ -0,0 +1,103 @@
+#![allow(dead_code)]
+
+use core::fmt;
+use log::*;
+use modular_bitfield::prelude::*;
+
+use crate::cpuid::{Processor, VendorMask};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes the physical and linear address sizes supported by the processor.
+pub struct AddressSizes {
+    /// Maximum physical address size, in bits.
+    pub physical_bits: u8,
+
+    /// Maximum linear (virtual) address size, in bits.
+    pub linear_bits: u8,
+
+    /// Maximum guest physical address size, in bits. Zero means the guest
+    /// physical address size is the same as `physical_bits`.
+    pub guest_physical_bits: u8,
+}
+
+impl fmt::Display for AddressSizes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}-bit physical, {}-bit virtual",
+            self.physical_bits, self.linear_bits
+        )
+    }
+}
+
+#[bitfield(bits = 32)]
+struct EaxAddressSizes {
+    physical_bits: B8,
+    linear_bits: B8,
+    guest_physical_bits: B8,
+    #[skip]
+    __: B8,
+}
+
+#[bitfield(bits = 32)]
+struct EcxSizeIdentifiers {
+    cores: B8,
+    #[skip]
+    __: B4,
+    apic_id_size: B4,
+    #[skip]
+    __: B16,
+}
+
+#[bitfield(bits = 32)]
+struct EbxComputeUnitIdentifiers {
+    compute_unit_id: B8,
+    threads_per_compute_unit: B8,
+    #[skip]
+    __: B16,
+}
+
+pub(crate) fn describe_address_sizes(cpu: &Processor) -> Option<AddressSizes> {
+    if let Some(raw) = cpu.get_subleaf(0x8000_0008, 0) {
+        let eax = EaxAddressSizes::from_bytes(raw.output.eax.to_le_bytes());
+        if eax.physical_bits() == 0 {
+            return None;
+        }
+        let sizes = AddressSizes {
+            physical_bits: eax.physical_bits(),
+            linear_bits: eax.linear_bits(),
+            guest_physical_bits: eax.guest_physical_bits(),
+        };
+        debug!("describe_address_sizes() found {:?}", sizes);
+        Some(sizes)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn describe_physical_core_count(cpu: &Processor, vendor: VendorMask) -> Option<u32> {
+    // Only AMD defines the core count field in this leaf, it is reserved on
+    // Intel.
+    if !vendor.contains(VendorMask::AMD) {
+        return None;
+    }
+    let raw = cpu.get_subleaf(0x8000_0008, 0)?;
+    let ecx = EcxSizeIdentifiers::from_bytes(raw.output.ecx.to_le_bytes());
+    let count = ecx.cores() as u32 + 1;
+    if cpu.signature.family < 0x17 {
+        return Some(count);
+    }
+    // Starting with family 17h the field counts threads rather than cores, so
+    // divide by the threads per core from leaf 0x8000_001E.
+    let raw = cpu.get_subleaf(0x8000_001E, 0)?;
+    let ebx = EbxComputeUnitIdentifiers::from_bytes(raw.output.ebx.to_le_bytes());
+    let threads_per_core = ebx.threads_per_compute_unit() as u32 + 1;
+    if count % threads_per_core != 0 {
+        warn!(
+            "{} threads per package is not a multiple of {} threads per core",
+            count, threads_per_core
+        );
+        return None;
+    }
+    Some(count / threads_per_core)
+}
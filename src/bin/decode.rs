@@ -1,13 +1,24 @@
 use getopts::Options;
 use std::env;
+use std::process;
 
 use cpuid::cpuid::System;
+use cpuid::feature::FeatureCategory;
 
 fn print_usage(program: &str, opts: Options) {
     let brief = format!("Usage: {} [options]", program);
     print!("{}", opts.usage(&brief));
 }
 
+/// Quotes `field` if it contains characters with a special meaning in CSV.
+fn csv_field(field: &str) -> String {
+    if field.contains(|c| c == ',' || c == '"' || c == '\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
 fn main() {
     let args: Vec<String> = env::args().collect();
     let program = args[0].clone();
@@ -19,6 +30,25 @@ fn main() {
         "Parse and import dump file instead of reading from local CPUs",
         "FILE",
     );
+    opts.optopt(
+        "c",
+        "cpu",
+        "Which CPUs to decode: an index (21), a range (21-35), a list (21,22,23) or \"all\"",
+        "CPUS",
+    );
+    opts.optopt(
+        "",
+        "category",
+        "Only print features in this category (simd, crypto, security, virtualization, power, memory, debug, misc)",
+        "NAME",
+    );
+    opts.optopt(
+        "",
+        "format",
+        "Output format: text (default), or csv to only print the features as CSV",
+        "FORMAT",
+    );
+    opts.optflag("", "tree", "Print the CPU topology as a tree of sockets, cores and threads");
     opts.optflag("v", "verbose", "Print more details");
     opts.optflag("h", "help", "Print this help text");
     let matches = match opts.parse(&args[1..]) {
@@ -40,22 +70,199 @@ fn main() {
     }
     .with_decoded();
 
-    println!("{: >16}: {:?}", "Vendor(s)", system.vendor);
+    let selection: Vec<u32> = match matches.opt_str("cpu") {
+        Some(spec) => match system.select_cpus(&spec) {
+            Ok(selection) => selection,
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        },
+        None => system.cpus.iter().map(|cpu| cpu.index).take(1).collect(),
+    };
+    if selection.is_empty() {
+        eprintln!("no CPUs to decode");
+        process::exit(1);
+    }
+    let category: Option<FeatureCategory> = match matches.opt_str("category") {
+        Some(name) => match name.parse::<FeatureCategory>() {
+            Ok(category) => Some(category),
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        },
+        None => None,
+    };
+    let csv = match matches.opt_str("format").as_deref() {
+        None | Some("text") => false,
+        Some("csv") => true,
+        Some(format) => {
+            eprintln!("unknown output format {:?}", format);
+            process::exit(1);
+        }
+    };
+    let cpu = system.cpu(selection[0]).unwrap();
+
+    if matches.opt_present("tree") {
+        let tree = system.topology_tree();
+        if tree.is_empty() {
+            println!("Topology IDs are not available");
+        } else {
+            print!("{}", tree);
+        }
+        return;
+    }
+
+    if csv {
+        println!("cpu,leaf,register,bit,shortname,name");
+        for cpu_index in selection.iter() {
+            let mut features = system.features_for(*cpu_index).unwrap();
+            if let Some(category) = category {
+                features = features.by_category(category);
+            }
+            for row in features.rows().iter() {
+                println!(
+                    "{},{:08x}:{:02x},{},{},{},{}",
+                    cpu_index,
+                    row.leaf.eax,
+                    row.leaf.ecx,
+                    row.register,
+                    row.bit,
+                    csv_field(row.shortname),
+                    csv_field(row.name)
+                );
+            }
+        }
+        return;
+    }
+
+    println!("{: >16}: {}", "Vendor(s)", system.vendor);
     println!("{: >16}: {}", "Processor Name", system.name_string);
-    println!("{: >16}: {}", "Signature", system.cpus[0].signature);
+    println!("{: >16}: {}", "Signature", cpu.signature);
+    if let Some(frequency) = &system.frequency {
+        println!("{: >16}: {}", "Frequency", frequency);
+    }
     if system.topology.valid() {
         println!("{: >16}: {}", "Topology", system.topology);
+        if !system.topology_consistent() {
+            println!(
+                "{: >16}: {} (does not match topology)",
+                "Logical CPUs", system.cpu_count
+            );
+        }
     } else {
         println!("{: >16}: {}", "Logical CPUs", system.cpu_count);
     }
+    if system.smt_capable() && !system.smt_enabled() {
+        println!("{: >16}: capable, disabled", "SMT");
+    }
     if matches.opt_present("v") {
+        if let Some(address_sizes) = &system.address_sizes {
+            println!("{: >16}: {}", "Address Sizes", address_sizes);
+        }
+        if let Some(cores) = system.physical_core_count() {
+            println!("{: >16}: {}", "Physical Cores", cores);
+        }
+        if let Some(tsc) = &system.tsc {
+            println!("{: >16}: {}", "TSC Frequency", tsc);
+        }
+        if let Some(stride) = system.prefetch_stride {
+            println!("{: >16}: {} bytes", "Prefetch Stride", stride);
+        }
+        if let Some(xsave) = &system.xsave {
+            println!("{: >16}: {}", "XSAVE Area Size", xsave);
+        }
+        if let Some(rdt) = &system.rdt {
+            if let Some(monitoring) = &rdt.l3_monitoring {
+                println!("{: >16}: {}", "L3 Monitoring", monitoring);
+            }
+            if let Some(allocation) = &rdt.l3_allocation {
+                println!("{: >16}: {}", "L3 CAT", allocation);
+            }
+            if let Some(allocation) = &rdt.l2_allocation {
+                println!("{: >16}: {}", "L2 CAT", allocation);
+            }
+            if let Some(allocation) = &rdt.memory_bandwidth_allocation {
+                println!("{: >16}: {}", "MBA", allocation);
+            }
+        }
+        if let Some(qos) = &system.amd_qos {
+            println!("{: >16}: {}", "QoS Enforcement", qos);
+        }
+        if let Some(sev) = &system.sev {
+            println!("{: >16}: {}", "Mem Encryption", sev);
+        }
+        if let Some(pconfig) = &system.pconfig {
+            println!("{: >16}: {}", "PCONFIG Targets", pconfig);
+        }
+        if let Some(svm) = &system.svm {
+            println!("{: >16}: {}", "SVM", svm);
+        }
+        if let Some(hyperv) = &system.hyperv {
+            println!("{: >16}: {}", "Hyper-V", hyperv);
+        }
+        if let Some(vmware) = &system.vmware {
+            println!("{: >16}: {}", "VMware", vmware);
+        }
+        if let Some(timing) = &system.hypervisor_timing {
+            if timing.tsc_khz != 0 {
+                println!("{: >16}: {} kHz", "Virtual TSC", timing.tsc_khz);
+            }
+            if timing.apic_khz != 0 {
+                println!("{: >16}: {} kHz", "Virtual APIC Bus", timing.apic_khz);
+            }
+        }
         println!("\nLogical CPU topology IDs:");
         for cpu in system.cpus.iter() {
             if let Some(topology) = cpu.topology() {
-                println!("  CPU {}: {}", cpu.index, topology);
+                match cpu.core_type() {
+                    Some(core_type) => println!("  CPU {}: {}, {}", cpu.index, topology, core_type),
+                    None => println!("  CPU {}: {}", cpu.index, topology),
+                }
+            }
+        }
+        let apic_ids = system.apic_id_table();
+        if !apic_ids.is_empty() {
+            println!("\nLogical CPU APIC IDs:");
+            for (index, apic_id) in apic_ids.iter() {
+                println!("  CPU {}: {:#x}", index, apic_id);
+            }
+        }
+        let warnings = system.validate();
+        if !warnings.is_empty() {
+            println!("\nValidation warnings:");
+            for warning in warnings.iter() {
+                println!("  {}", warning);
             }
         }
+        if !system.unknown_features.is_empty() {
+            println!("\nUnknown feature bits:");
+            for (leaf, register, bit) in system.unknown_features.iter() {
+                println!(
+                    "  Leaf {:08x}:{:02x}, register {}, bit {}",
+                    leaf.eax, leaf.ecx, register, bit
+                );
+            }
+        }
+    }
+    // Too long for the aligned header lines above, so printed on its own.
+    println!(
+        "\nRunning under hypervisor: {}",
+        match system.hypervisor_present() {
+            true => "yes",
+            false => "no",
+        }
+    );
+    for cpu_index in selection.iter() {
+        if selection.len() > 1 {
+            println!("\nCPU {}:", cpu_index);
+        }
+        println!("\n{}", system.caches_for(*cpu_index).unwrap());
+        let features = system.features_for(*cpu_index).unwrap();
+        match category {
+            Some(category) => println!("{}", features.by_category(category)),
+            None => println!("{}", features),
+        }
     }
-    println!("\n{}", system.caches);
-    println!("{}", system.features);
 }
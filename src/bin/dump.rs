@@ -1,5 +1,6 @@
 use getopts::Options;
 use std::env;
+use std::process;
 
 use cpuid::cpuid::System;
 
@@ -19,7 +20,32 @@ fn main() {
         "Parse and import dump file instead of reading from local CPUs",
         "FILE",
     );
-    opts.optopt("c", "cpu", "Which CPU to decode CPUID information from", "INDEX");
+    opts.optopt(
+        "c",
+        "cpu",
+        "Which CPUs to dump: an index (21), a range (21-35), a list (21,22,23) or \"all\"",
+        "CPUS",
+    );
+    opts.optflag(
+        "",
+        "anonymize",
+        "Zero the processor serial number (leaf 0x3) and initial APIC IDs (leaf 0x1 ebx bits 31:24)",
+    );
+    opts.optflag(
+        "",
+        "blank-name",
+        "With --anonymize, also zero the brand string leaves (0x8000_0002 to 0x8000_0004)",
+    );
+    opts.optflag(
+        "",
+        "no-ascii",
+        "Leave out the ASCII rendering of the registers at the end of each line",
+    );
+    opts.optflag(
+        "",
+        "sort",
+        "Sort each CPU's leaves by leaf and subleaf instead of keeping collection order",
+    );
     opts.optflag("h", "help", "Print this help text");
     let matches = match opts.parse(&args[1..]) {
         Ok(m) => m,
@@ -32,46 +58,38 @@ fn main() {
         return;
     }
 
-    // TODO: This kinda sucks because it will silently eat bogus values. We want
-    // it to eventually accept integer values (21), integer ranges (21-35),
-    // integer lists (21,22,23), or the string "all" (or similar).
-    let cpu_index: i32 = matches
-        .opt_str("cpu")
-        .unwrap_or("-1".to_string())
-        .parse::<i32>()
-        .unwrap_or(-1);
-
-    let mut cpu_start: u32 = 0;
-    let mut cpu_end: u32 = num_cpus::get() as u32 - 1;
-
-    if cpu_index > cpu_end as i32 {
-        panic!(
-            "CPU {} does not exist (valid range: {} to {})",
-            cpu_index, cpu_start, cpu_end
-        );
-    }
-
-    // For now we only accept a single CPU index in the --cpu argument, and set
-    // the range to only include that value.
-    if cpu_index >= 0 {
-        cpu_start = cpu_index as u32;
-        cpu_end = cpu_index as u32;
-    }
-
     env_logger::init();
 
-    let system = match matches.opt_str("file") {
+    let mut system = match matches.opt_str("file") {
         Some(filename) => System::from_file(&filename).unwrap(),
         _ => System::from_local(),
     };
+    if matches.opt_present("anonymize") {
+        system = system.anonymized_with(matches.opt_present("blank-name"));
+    }
+    if matches.opt_present("sort") {
+        system = system.sorted();
+    }
+
+    let selection = match system.select_cpus(&matches.opt_str("cpu").unwrap_or("all".to_string())) {
+        Ok(selection) => selection,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
 
     for processor in system.cpus.iter() {
-        if processor.index < cpu_start || processor.index > cpu_end {
+        if !selection.contains(&processor.index) {
             continue;
         }
         println!("CPU {}:", processor.index);
-        for entry in processor.leaves.iter() {
-            println!("{}", entry);
+        for entry in processor.iter_leaves() {
+            if matches.opt_present("no-ascii") {
+                println!("{:#}", entry);
+            } else {
+                println!("{}", entry);
+            }
         }
     }
 }
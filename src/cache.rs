@@ -1,13 +1,14 @@
 #![allow(dead_code)]
 
+use core::cmp::Ordering;
+use core::fmt;
 use log::*;
 use modular_bitfield::prelude::*;
-use std::cmp::Ordering;
-use std::fmt;
-use textwrap::indent;
 
 use crate::cpuid::{Processor, RegisterName, System, VendorMask};
-use crate::internal::cache_descriptors::lookup_descriptors;
+use crate::internal::cache_descriptors::{lookup_descriptor_internal, lookup_descriptors};
+use crate::internal::prelude::*;
+use crate::internal::util::indent;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(u8)]
@@ -34,7 +35,7 @@ impl Default for CacheType {
 impl CacheType {
     /// Coalesce types for sorting reasons -- we want caches and then TLBs together.
 
-    fn is_tlb(&self) -> bool {
+    pub(crate) fn is_tlb(&self) -> bool {
         match self {
             CacheType::DataTLB
             | CacheType::CodeTLB
@@ -46,24 +47,58 @@ impl CacheType {
     }
 }
 
+/// Names accepted and produced for each [CacheType](enum.CacheType.html).
+static CACHE_TYPE_NAMES: [(CacheType, &str); 10] = [
+    (CacheType::Unknown, "unknown cache"),
+    (CacheType::Data, "data cache"),
+    (CacheType::Code, "code cache"),
+    (CacheType::Unified, "unified cache"),
+    (CacheType::Trace, "trace cache"),
+    (CacheType::DataTLB, "data TLB"),
+    (CacheType::CodeTLB, "code TLB"),
+    (CacheType::SharedTLB, "shared TLB"),
+    (CacheType::LoadOnlyTLB, "load-only TLB"),
+    (CacheType::StoreOnlyTLB, "store-only TLB"),
+];
+
 impl fmt::Display for CacheType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                CacheType::Unknown => "unknown cache",
-                CacheType::Data => "data cache",
-                CacheType::Code => "code cache",
-                CacheType::Unified => "unified cache",
-                CacheType::Trace => "trace cache",
-                CacheType::DataTLB => "data TLB",
-                CacheType::CodeTLB => "code TLB",
-                CacheType::SharedTLB => "shared TLB",
-                CacheType::LoadOnlyTLB => "load-only TLB",
-                CacheType::StoreOnlyTLB => "store-only TLB",
-            }
-        )
+        let name = CACHE_TYPE_NAMES
+            .iter()
+            .find(|(cachetype, _)| cachetype == self)
+            .map_or("", |(_, name)| name);
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Error returned when parsing a [CacheType](enum.CacheType.html) from a
+/// string fails.
+pub struct ParseCacheTypeError {
+    /// The cache type name which was not recognized.
+    pub name: String,
+}
+
+impl fmt::Display for ParseCacheTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown cache type {:?}", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCacheTypeError {}
+
+impl core::str::FromStr for CacheType {
+    type Err = ParseCacheTypeError;
+
+    /// Parses a cache type name as produced by the `Display` implementation,
+    /// e.g. `"data cache"`, ignoring case.
+    fn from_str(s: &str) -> Result<CacheType, ParseCacheTypeError> {
+        let name = s.trim();
+        match CACHE_TYPE_NAMES.iter().find(|(_, other)| other.eq_ignore_ascii_case(name)) {
+            Some((cachetype, _)) => Ok(*cachetype),
+            None => Err(ParseCacheTypeError { name: name.to_string() }),
+        }
     }
 }
 
@@ -85,6 +120,57 @@ impl Default for CacheLevel {
     }
 }
 
+/// Names accepted and produced for each [CacheLevel](enum.CacheLevel.html).
+static CACHE_LEVEL_NAMES: [(CacheLevel, &str); 6] = [
+    (CacheLevel::L0, "L0"),
+    (CacheLevel::L1, "L1"),
+    (CacheLevel::Unknown, "unknown level"),
+    (CacheLevel::L2, "L2"),
+    (CacheLevel::L3, "L3"),
+    (CacheLevel::L4, "L4"),
+];
+
+impl fmt::Display for CacheLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = CACHE_LEVEL_NAMES
+            .iter()
+            .find(|(level, _)| level == self)
+            .map_or("", |(_, name)| name);
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Error returned when parsing a [CacheLevel](enum.CacheLevel.html) from a
+/// string fails.
+pub struct ParseCacheLevelError {
+    /// The cache level name which was not recognized.
+    pub name: String,
+}
+
+impl fmt::Display for ParseCacheLevelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown cache level {:?}", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCacheLevelError {}
+
+impl core::str::FromStr for CacheLevel {
+    type Err = ParseCacheLevelError;
+
+    /// Parses a cache level name as produced by the `Display` implementation,
+    /// e.g. `"L2"`, ignoring case.
+    fn from_str(s: &str) -> Result<CacheLevel, ParseCacheLevelError> {
+        let name = s.trim();
+        match CACHE_LEVEL_NAMES.iter().find(|(_, other)| other.eq_ignore_ascii_case(name)) {
+            Some((level, _)) => Ok(*level),
+            None => Err(ParseCacheLevelError { name: name.to_string() }),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(u8)]
 /// Describes the associativity type of the cache.
@@ -132,6 +218,23 @@ impl CacheAssociativity {
             ways: id as u16,
         }
     }
+
+    /// Number of ways of associativity as a single number: 1 for a
+    /// direct-mapped cache, and [ways](#structfield.ways) for an N-way set
+    /// associative one. A fully associative cache is a single set holding every
+    /// block, so its way count is the total number of blocks (the cache size
+    /// divided by the line size, or the number of TLB entries), which must be
+    /// passed in `blocks` as it isn't part of the associativity. `blocks` is
+    /// ignored for other caches. Returns `None` if the associativity is
+    /// unknown, or if the cache is fully associative and `blocks` is `None`.
+    pub fn effective_ways(&self, blocks: Option<u32>) -> Option<u32> {
+        match self.mapping {
+            CacheAssociativityType::Unknown => None,
+            CacheAssociativityType::DirectMapped => Some(1),
+            CacheAssociativityType::NWay => Some(self.ways as u32),
+            CacheAssociativityType::FullyAssociative => blocks,
+        }
+    }
 }
 
 impl fmt::Display for CacheAssociativity {
@@ -189,8 +292,12 @@ pub struct CacheFlags {
     __: B4,
 }
 
-#[derive(Debug, Default, Eq)]
+#[derive(Debug, Clone, Default, Eq)]
 /// Describes a cache or TLB.
+///
+/// Comparing with `==` only considers the geometry of the cache, not its
+/// flags, sharing or instance counts. Use
+/// [eq_exact](#method.eq_exact) to compare every field.
 pub struct CacheDescription {
     /// Level of the cache.
     pub level: CacheLevel,
@@ -222,6 +329,11 @@ pub struct CacheDescription {
     /// the `max_threads_sharing` field and the number of logical processors in
     /// the [System](struct.System.html)
     pub instances: usize,
+
+    /// Inferred number of instances of this cache/TLB in a single socket. This
+    /// is the same as `instances` unless the decoded topology has more than
+    /// one socket.
+    pub instances_per_socket: usize,
 }
 
 impl Ord for CacheDescription {
@@ -296,6 +408,47 @@ impl CacheVec {
     pub fn new() -> CacheVec {
         CacheVec(vec![])
     }
+
+    /// Returns an iterator over the contained [CacheDescriptions](struct.CacheDescription.html).
+    pub fn iter(&self) -> core::slice::Iter<'_, CacheDescription> {
+        self.0.iter()
+    }
+
+    /// Returns the number of contained [CacheDescriptions](struct.CacheDescription.html).
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no [CacheDescriptions](struct.CacheDescription.html).
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl core::ops::Index<usize> for CacheVec {
+    type Output = CacheDescription;
+
+    fn index(&self, index: usize) -> &CacheDescription {
+        &self.0[index]
+    }
+}
+
+impl IntoIterator for CacheVec {
+    type Item = CacheDescription;
+    type IntoIter = alloc::vec::IntoIter<CacheDescription>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a CacheVec {
+    type Item = &'a CacheDescription;
+    type IntoIter = core::slice::Iter<'a, CacheDescription>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
 }
 
 impl fmt::Display for CacheVec {
@@ -317,7 +470,131 @@ impl fmt::Display for CacheVec {
     }
 }
 
-fn size_str(kb: u32, cachetype: CacheType) -> String {
+#[derive(Debug, Clone, Default)]
+/// Table of legacy cache and TLB descriptors, as reported by leaf
+/// `0x0000_0002`. Overrides take priority over the built-in descriptors, so
+/// they can be used to add missing descriptors or to correct existing ones.
+pub struct CacheDescriptorTable {
+    overrides: Vec<(u8, CacheDescription)>,
+}
+
+impl CacheDescriptorTable {
+    /// Creates a table containing only the built-in descriptors.
+    pub fn new() -> CacheDescriptorTable {
+        CacheDescriptorTable { overrides: vec![] }
+    }
+
+    /// Adds or replaces the description for `descriptor`.
+    pub fn with_override(mut self, descriptor: u8, description: CacheDescription) -> CacheDescriptorTable {
+        self.overrides.retain(|(d, _)| *d != descriptor);
+        self.overrides.push((descriptor, description));
+        self
+    }
+
+    /// Looks up the description for `descriptor`, consulting the overrides
+    /// before the built-in descriptors. Descriptors which describe more than
+    /// one cache or TLB are not covered by this lookup unless overridden.
+    pub fn lookup(&self, descriptor: u8) -> Option<CacheDescription> {
+        for (d, description) in self.overrides.iter() {
+            if *d == descriptor {
+                return Some(description.clone());
+            }
+        }
+        lookup_descriptor_internal(descriptor)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Describes the logical CPUs sharing one instance of a cache, as returned by
+/// [System::cache_domains](../cpuid/struct.System.html#method.cache_domains).
+pub struct CacheDomain {
+    /// The shared cache.
+    pub cache: CacheDescription,
+
+    /// Index of this instance among the instances of the same cache, ordered
+    /// by APIC ID.
+    pub domain: u32,
+
+    /// Indices of the logical CPUs sharing this instance, in ascending order.
+    pub cpus: Vec<u32>,
+}
+
+impl fmt::Display for CacheDomain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} domain {}: CPUs {}",
+            self.cache.level,
+            self.cache.cachetype,
+            self.domain,
+            cpu_list_str(&self.cpus)
+        )
+    }
+}
+
+/// Formats ascending CPU indices as a comma separated list, collapsing
+/// consecutive runs into ranges, e.g. `0-3,8`.
+fn cpu_list_str(cpus: &[u32]) -> String {
+    let mut ranges: Vec<(u32, u32)> = vec![];
+    for cpu in cpus.iter() {
+        match ranges.last_mut() {
+            Some((_, last)) if *last + 1 == *cpu => *last = *cpu,
+            _ => ranges.push((*cpu, *cpu)),
+        }
+    }
+    ranges
+        .iter()
+        .map(|(first, last)| match first == last {
+            true => format!("{}", first),
+            false => format!("{}-{}", first, last),
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// Groups the processors of `system` by the instance of each cache they
+/// share. Logical CPUs share an instance when their APIC IDs only differ in
+/// the low bits covering the cache's
+/// [max_threads_sharing](struct.CacheDescription.html#structfield.max_threads_sharing),
+/// rounded up to a power of two. Caches without sharing information and TLBs
+/// are skipped. Only the processors in `system.cpus` which report an APIC ID
+/// are included.
+pub(crate) fn describe_cache_domains(system: &System) -> Vec<CacheDomain> {
+    let apic_ids = system.apic_id_table();
+    let mut domains: Vec<CacheDomain> = vec![];
+    for cache in system.caches.iter() {
+        if cache.cachetype.is_tlb() || cache.max_threads_sharing == 0 {
+            continue;
+        }
+        let shift = 32 - (cache.max_threads_sharing as u32 - 1).leading_zeros();
+        let mut members: Vec<(u32, u32)> = apic_ids
+            .iter()
+            .map(|(index, apic_id)| (apic_id >> shift, *index))
+            .collect();
+        members.sort_unstable();
+
+        let mut last: Option<u32> = None;
+        let mut domain: u32 = 0;
+        for (key, index) in members.iter() {
+            if last != Some(*key) {
+                if last.is_some() {
+                    domain += 1;
+                }
+                domains.push(CacheDomain {
+                    cache: cache.clone(),
+                    domain,
+                    cpus: vec![],
+                });
+                last = Some(*key);
+            }
+            domains.last_mut().unwrap().cpus.push(*index);
+        }
+    }
+    debug!("describe_cache_domains() found {} domains", domains.len());
+    domains
+}
+
+pub(crate) fn size_str(kb: u32, cachetype: CacheType) -> String {
     if cachetype == CacheType::Trace {
         return format!("{}K-µop", kb);
     }
@@ -361,6 +638,62 @@ fn first_letter_to_uppercase(s1: String) -> String {
 }
 
 impl CacheDescription {
+    /// Starts building a [CacheDescription](struct.CacheDescription.html).
+    /// Fields which aren't set keep their default values.
+    ///
+    /// ```
+    /// use cpuid::cache::{CacheAssociativity, CacheDescription, CacheLevel, CacheType};
+    ///
+    /// let cache = CacheDescription::builder()
+    ///     .level(CacheLevel::L1)
+    ///     .cachetype(CacheType::Data)
+    ///     .size(32)
+    ///     .linesize(64)
+    ///     .associativity(CacheAssociativity::from_identifier(0x08))
+    ///     .build();
+    /// assert_eq!(cache.total_size_bytes(), Some(32768));
+    /// assert_eq!(cache.max_threads_sharing, 0);
+    /// ```
+    pub fn builder() -> CacheDescriptionBuilder {
+        CacheDescriptionBuilder::default()
+    }
+
+    /// Compares every field of this cache or TLB with `other`, unlike `==`.
+    /// The `PartialEq` implementation only compares the geometry (level, type,
+    /// size, line size, associativity and partitions), so that the same cache
+    /// reported by different leaves or processors compares equal. This also
+    /// compares the [flags](#structfield.flags),
+    /// [max_threads_sharing](#structfield.max_threads_sharing) and the
+    /// inferred instance counts, e.g. to catch a cache which only differs in
+    /// whether it has ECC.
+    pub fn eq_exact(&self, other: &CacheDescription) -> bool {
+        self == other
+            && self.flags == other.flags
+            && self.max_threads_sharing == other.max_threads_sharing
+            && self.instances == other.instances
+            && self.instances_per_socket == other.instances_per_socket
+    }
+
+    /// Total size of this cache in bytes. The `size` field is overloaded: it
+    /// holds KB for caches, K-µops for trace caches and the entry count for
+    /// TLBs, so this returns `None` for TLBs and trace caches.
+    pub fn total_size_bytes(&self) -> Option<u64> {
+        match self.cachetype {
+            CacheType::Data | CacheType::Code | CacheType::Unified => Some(self.size as u64 * 1024),
+            _ => None,
+        }
+    }
+
+    /// Number of entries in this TLB. The `size` field is overloaded: it holds
+    /// the entry count only for TLBs, so this returns `None` for caches.
+    pub fn tlb_entries(&self) -> Option<u32> {
+        if self.cachetype.is_tlb() {
+            Some(self.size)
+        } else {
+            None
+        }
+    }
+
     fn fmt_cache(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.instances > 0 {
             // e.g. 8 x 48KB L1 data cache
@@ -407,7 +740,10 @@ impl CacheDescription {
         if self.flags.undocumented() {
             write!(f, "\n{: >13}Undocumented descriptor", "")?;
         }
-        //write!(f, "{: >11}Shared by max {} threads\n", "", self.max_threads_sharing);
+        if self.max_threads_sharing > 1 {
+            // e.g. Shared by max 16 threads
+            write!(f, "\n{: >13}Shared by max {} threads", "", self.max_threads_sharing)?;
+        }
         Ok(())
     }
 
@@ -439,6 +775,68 @@ impl CacheDescription {
     }
 }
 
+#[derive(Debug, Clone, Default)]
+/// Builds a [CacheDescription](struct.CacheDescription.html) field by field,
+/// as returned by [CacheDescription::builder](struct.CacheDescription.html#method.builder).
+pub struct CacheDescriptionBuilder {
+    desc: CacheDescription,
+}
+
+impl CacheDescriptionBuilder {
+    /// Sets the level of the cache.
+    pub fn level(mut self, level: CacheLevel) -> Self {
+        self.desc.level = level;
+        self
+    }
+
+    /// Sets the type of cache or TLB.
+    pub fn cachetype(mut self, cachetype: CacheType) -> Self {
+        self.desc.cachetype = cachetype;
+        self
+    }
+
+    /// Sets the size, in KB for caches or in entries for TLBs.
+    pub fn size(mut self, size: u32) -> Self {
+        self.desc.size = size;
+        self
+    }
+
+    /// Sets the cache line size.
+    pub fn linesize(mut self, linesize: u16) -> Self {
+        self.desc.linesize = linesize;
+        self
+    }
+
+    /// Sets the cache or TLB flags.
+    pub fn flags(mut self, flags: CacheFlags) -> Self {
+        self.desc.flags = flags;
+        self
+    }
+
+    /// Sets the associativity type and ways.
+    pub fn associativity(mut self, associativity: CacheAssociativity) -> Self {
+        self.desc.associativity = associativity;
+        self
+    }
+
+    /// Sets the number of cache partitions.
+    pub fn partitions(mut self, partitions: u16) -> Self {
+        self.desc.partitions = partitions;
+        self
+    }
+
+    /// Sets the maximum number of logical CPUs sharing this cache or TLB.
+    pub fn max_threads_sharing(mut self, max_threads_sharing: u16) -> Self {
+        self.desc.max_threads_sharing = max_threads_sharing;
+        self
+    }
+
+    /// Returns the finished [CacheDescription](struct.CacheDescription.html).
+    pub fn build(self) -> CacheDescription {
+        self.desc
+    }
+}
+
 impl fmt::Display for CacheDescription {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.cachetype {
@@ -448,12 +846,15 @@ impl fmt::Display for CacheDescription {
             | CacheType::SharedTLB
             | CacheType::LoadOnlyTLB
             | CacheType::StoreOnlyTLB => self.fmt_tlb(f),
-            _ => panic!("Don't know how to describe cache type {:#?}", self.cachetype),
+            _ => write!(f, "{:?} cache", self.cachetype),
         }
-        //write!(f, "SOME KINDA CACHE LOL\n")?;
     }
 }
 
+/// Legacy L2/L3 associativity value meaning the associativity should be read
+/// from the Extended Cache Topology leaf (0x8000_001D) instead.
+const AMD_ASSOCIATIVITY_EXTENDED: u8 = 0x9;
+
 fn translate_amd_l2_associativity(raw: u8) -> u8 {
     match raw {
         0x0 => 0x0,
@@ -463,7 +864,11 @@ fn translate_amd_l2_associativity(raw: u8) -> u8 {
         0x4 => 0x4,
         0x5 => 0x6,
         0x6 => 0x8,
+        // Reserved
+        0x7 => 0x0,
         0x8 => 0x10,
+        // Determined by leaf 0x8000_001D, see amd_legacy_cache_associativity()
+        AMD_ASSOCIATIVITY_EXTENDED => 0x0,
         0xA => 0x20,
         0xB => 0x30,
         0xC => 0x40,
@@ -474,6 +879,40 @@ fn translate_amd_l2_associativity(raw: u8) -> u8 {
     }
 }
 
+/// Translates the associativity of a cache described by leaf 0x8000_0006. If
+/// the associativity is deferred to the Extended Cache Topology leaf, the
+/// matching unified cache of the given level is looked up there, even if the
+/// Topology Extensions feature bit is not set.
+fn amd_legacy_cache_associativity(cpu: &Processor, level: u32, raw: u8) -> CacheAssociativity {
+    if raw != AMD_ASSOCIATIVITY_EXTENDED {
+        return CacheAssociativity::from_identifier(translate_amd_l2_associativity(raw));
+    }
+
+    let mut subleaf: u32 = 0;
+    while let Some(leaf) = cpu.get_subleaf(0x8000_001D, subleaf) {
+        let cachetype = leaf.output.eax & 0x1F;
+        if cachetype == 0 {
+            break;
+        }
+        if cachetype == 3 && (leaf.output.eax >> 5) & 0x7 == level {
+            if (leaf.output.eax >> 9) & 0x1 != 0 {
+                return CacheAssociativity {
+                    mapping: CacheAssociativityType::FullyAssociative,
+                    ways: 0xFF,
+                };
+            }
+            return CacheAssociativity {
+                mapping: CacheAssociativityType::NWay,
+                ways: (leaf.output.ebx >> 22) as u16 + 1,
+            };
+        }
+        subleaf += 1;
+    }
+
+    debug!("amd_legacy_cache_associativity() found no L{} cache in leaf 0x8000_001D", level);
+    CacheAssociativity::default()
+}
+
 fn walk_amd_cache_extended(system: &System, cpu: &Processor, out: &mut CacheVec) -> bool {
     if !system.vendor.contains(VendorMask::AMD) {
         debug!("walk_amd_cache_extended() skipped on non-AMD CPU");
@@ -544,6 +983,7 @@ fn walk_amd_cache_extended(system: &System, cpu: &Processor, out: &mut CacheVec)
             1 => CacheLevel::L1,
             2 => CacheLevel::L2,
             3 => CacheLevel::L3,
+            4 => CacheLevel::L4,
             _ => CacheLevel::Unknown,
         };
         desc.cachetype = match eax.cachetype() {
@@ -599,15 +1039,10 @@ fn walk_amd_cache_legacy(system: &System, cpu: &Processor, out: &mut CacheVec) {
     if let Some(raw) = cpu.get_subleaf(0x8000_0005, 0) {
         let level = CacheLevel::L1;
 
-        for register in vec![RegisterName::ECX, RegisterName::EDX] {
-            let cachetype = match register {
-                RegisterName::ECX => CacheType::Data,
-                RegisterName::EDX => CacheType::Code,
-                _ => panic!("Invalid register name!"),
-            };
-
-            let regbytes = raw.output.register(register).to_le_bytes();
-            let cache = L1CacheDesc::from_bytes(regbytes);
+        for (value, cachetype) in
+            [(raw.output.ecx, CacheType::Data), (raw.output.edx, CacheType::Code)].iter()
+        {
+            let cache = L1CacheDesc::from_bytes(value.to_le_bytes());
 
             if cache.size() != 0 {
                 let desc = CacheDescription {
@@ -650,9 +1085,7 @@ fn walk_amd_cache_legacy(system: &System, cpu: &Processor, out: &mut CacheVec) {
             let desc = CacheDescription {
                 level: CacheLevel::L2,
                 cachetype: CacheType::Unified,
-                associativity: CacheAssociativity::from_identifier(translate_amd_l2_associativity(
-                    l2cache.associativity(),
-                )),
+                associativity: amd_legacy_cache_associativity(cpu, 2, l2cache.associativity()),
                 size: l2cache.size() as u32,
                 linesize: l2cache.linesize() as u16,
                 ..Default::default()
@@ -674,9 +1107,7 @@ fn walk_amd_cache_legacy(system: &System, cpu: &Processor, out: &mut CacheVec) {
             let desc = CacheDescription {
                 level: CacheLevel::L3,
                 cachetype: CacheType::Unified,
-                associativity: CacheAssociativity::from_identifier(translate_amd_l2_associativity(
-                    l3cache.associativity(),
-                )),
+                associativity: amd_legacy_cache_associativity(cpu, 3, l3cache.associativity()),
                 size: l3size,
                 linesize: l3cache.linesize() as u16,
                 ..Default::default()
@@ -722,15 +1153,13 @@ fn walk_amd_tlb(system: &System, cpu: &Processor, out: &mut CacheVec) {
     if let Some(raw) = cpu.get_subleaf(0x8000_0005, 0) {
         let level = CacheLevel::L1;
 
-        for register in vec![RegisterName::EBX, RegisterName::EAX] {
-            let cacheflags = match register {
-                RegisterName::EBX => CacheFlags::new().with_pages_4k(true),
-                RegisterName::EAX => CacheFlags::new().with_pages_2m(true).with_pages_4m(true),
-                _ => panic!("Invalid register name!"),
-            };
-
-            let regbytes = raw.output.register(register).to_le_bytes();
-            let tlb = L1TlbDesc::from_bytes(regbytes);
+        for (value, cacheflags) in [
+            (raw.output.ebx, CacheFlags::new().with_pages_4k(true)),
+            (raw.output.eax, CacheFlags::new().with_pages_2m(true).with_pages_4m(true)),
+        ]
+        .iter()
+        {
+            let tlb = L1TlbDesc::from_bytes(value.to_le_bytes());
 
             if tlb.dtlb_entries() > 0 {
                 let desc = CacheDescription {
@@ -771,15 +1200,13 @@ fn walk_amd_tlb(system: &System, cpu: &Processor, out: &mut CacheVec) {
     if let Some(raw) = cpu.get_subleaf(0x8000_0006, 0) {
         let level = CacheLevel::L2;
 
-        for register in vec![RegisterName::EBX, RegisterName::EAX] {
-            let cacheflags = match register {
-                RegisterName::EBX => CacheFlags::new().with_pages_4k(true),
-                RegisterName::EAX => CacheFlags::new().with_pages_2m(true).with_pages_4m(true),
-                _ => panic!("Invalid register name!"),
-            };
-
-            let regbytes = raw.output.register(register).to_le_bytes();
-            let tlb = L2TlbDesc::from_bytes(regbytes);
+        for (value, cacheflags) in [
+            (raw.output.ebx, CacheFlags::new().with_pages_4k(true)),
+            (raw.output.eax, CacheFlags::new().with_pages_2m(true).with_pages_4m(true)),
+        ]
+        .iter()
+        {
+            let tlb = L2TlbDesc::from_bytes(value.to_le_bytes());
 
             if tlb.dtlb_entries() > 0 {
                 let desc = CacheDescription {
@@ -812,43 +1239,50 @@ fn walk_amd_tlb(system: &System, cpu: &Processor, out: &mut CacheVec) {
         }
     }
 
+    // Leaf 0x8000_0019 describes the 1GB page TLBs, with the L1 TLBs in EAX and
+    // the L2 TLBs in EBX. Associativity uses the same encoding as leaf
+    // 0x8000_0006.
+    #[bitfield(bits = 32)]
+    #[derive(Debug)]
+    struct Tlb1GDesc {
+        itlb_1g_entries: B12,
+        itlb_1g_associativity: B4,
+        dtlb_1g_entries: B12,
+        dtlb_1g_associativity: B4,
+    }
+
     if let Some(raw) = cpu.get_subleaf(0x8000_0019, 0) {
-        for register in vec![RegisterName::EBX, RegisterName::EAX] {
-            let level = match register {
-                RegisterName::EAX => CacheLevel::L1,
-                RegisterName::EBX => CacheLevel::L2,
-                _ => panic!("Invalid register name!"),
-            };
+        let cacheflags = CacheFlags::new().with_pages_1g(true);
 
-            let regbytes = raw.output.register(register).to_le_bytes();
-            let tlb = L2TlbDesc::from_bytes(regbytes);
+        for (value, level) in [(raw.output.eax, CacheLevel::L1), (raw.output.ebx, CacheLevel::L2)].iter() {
+            let tlb = Tlb1GDesc::from_bytes(value.to_le_bytes());
 
-            if tlb.dtlb_entries() > 0 {
+            if tlb.dtlb_1g_entries() > 0 {
                 let desc = CacheDescription {
                     level: level.clone(),
                     cachetype: CacheType::DataTLB,
                     associativity: CacheAssociativity::from_identifier(translate_amd_l2_associativity(
-                        tlb.dtlb_associativity(),
+                        tlb.dtlb_1g_associativity(),
                     )),
-                    size: tlb.dtlb_entries() as u32,
-                    flags: CacheFlags::new().with_pages_1g(true),
+                    size: tlb.dtlb_1g_entries() as u32,
+                    flags: cacheflags,
                     ..Default::default()
                 };
-                debug!("walk_amd_tlb() found 1G dtlb {:?}", desc);
+                debug!("walk_amd_tlb() found {:?} 1G dtlb {:?}", level, desc);
                 out.0.push(desc);
             }
-            if tlb.itlb_entries() > 0 {
+            if tlb.itlb_1g_entries() > 0 {
                 let desc = CacheDescription {
                     level: level.clone(),
                     cachetype: CacheType::CodeTLB,
                     associativity: CacheAssociativity::from_identifier(translate_amd_l2_associativity(
-                        tlb.itlb_associativity(),
+                        tlb.itlb_1g_associativity(),
                     )),
-                    size: tlb.itlb_entries() as u32,
-                    flags: CacheFlags::new().with_pages_1g(true),
+                    size: tlb.itlb_1g_entries() as u32,
+                    flags: cacheflags,
                     ..Default::default()
                 };
-                debug!("walk_amd_tlb() found 1G itlb {:?}", desc);
+                debug!("walk_amd_tlb() found {:?} 1G itlb {:?}", level, desc);
                 out.0.push(desc);
             }
         }
@@ -934,6 +1368,7 @@ fn walk_intel_dcp(system: &System, cpu: &Processor, out: &mut CacheVec) -> bool
                 1 => CacheLevel::L1,
                 2 => CacheLevel::L2,
                 3 => CacheLevel::L3,
+                4 => CacheLevel::L4,
                 _ => CacheLevel::default(),
             },
 
@@ -1021,8 +1456,14 @@ fn walk_intel_dat(system: &System, cpu: &Processor, out: &mut CacheVec) -> bool
 
     let mut retval: bool = false;
 
-    let mut subleaf: u32 = 0;
-    while let Some(raw) = cpu.get_subleaf(0x0000_0018, subleaf) {
+    // Subleaf 0 reports the maximum subleaf in eax. Subleaves below it with a
+    // TLB type of zero are reserved, and valid subleaves may follow them.
+    let max_subleaf = match cpu.get_subleaf(0x0000_0018, 0) {
+        Some(raw) => raw.output.eax,
+        None => return false,
+    };
+    let subleaves = cpu.get(0x0000_0018);
+    for raw in subleaves.iter().filter(|raw| raw.input.ecx <= max_subleaf) {
         let _eax = EaxTLB::from_bytes(raw.output.eax.to_le_bytes());
         let ebx = EbxTLB::from_bytes(raw.output.ebx.to_le_bytes());
         let ecx = EcxTLB::from_bytes(raw.output.ecx.to_le_bytes());
@@ -1083,27 +1524,93 @@ fn walk_intel_dat(system: &System, cpu: &Processor, out: &mut CacheVec) -> bool
             debug!("walk_intel_dat() found TLB {:?}", desc);
             out.0.push(desc);
         }
-
-        subleaf += 1;
     }
 
     retval
 }
 
-fn walk_intel_legacy_cache(_system: &System, cpu: &Processor, out: &mut CacheVec, filter: &Vec<CacheType>) {
-    if let Some(raw) = cpu.get_subleaf(0x0000_0002, 0) {
-        let mut bytes: Vec<u8> = vec![];
-        bytes.extend_from_slice(&raw.output.eax.to_le_bytes());
-        bytes.extend_from_slice(&raw.output.ebx.to_le_bytes());
-        bytes.extend_from_slice(&raw.output.ecx.to_le_bytes());
-        bytes.extend_from_slice(&raw.output.edx.to_le_bytes());
-        bytes.sort_unstable();
-        bytes.dedup();
-        lookup_descriptors(out, bytes, filter);
+/// Collects the sorted, deduplicated descriptor bytes of leaf `0x0000_0002`.
+fn legacy_descriptors(cpu: &Processor) -> Option<Vec<u8>> {
+    let raw = cpu.get_subleaf(0x0000_0002, 0)?;
+    let mut bytes: Vec<u8> = vec![];
+    let registers = [raw.output.eax, raw.output.ebx, raw.output.ecx, raw.output.edx];
+    for (index, value) in registers.iter().enumerate() {
+        // Bit 31 set means the register does not contain valid descriptors.
+        if (value & 0x8000_0000) != 0 {
+            continue;
+        }
+
+        let regbytes = value.to_le_bytes();
+        if index == 0 {
+            // The low byte of eax is the number of times this leaf must be
+            // queried, not a descriptor.
+            bytes.extend_from_slice(&regbytes[1..]);
+        } else {
+            bytes.extend_from_slice(&regbytes);
+        }
+    }
+    bytes.sort_unstable();
+    bytes.dedup();
+    Some(bytes)
+}
+
+/// Decodes the hardware prefetch stride in bytes from the legacy descriptors
+/// 0xF0 (64-byte prefetching) and 0xF1 (128-byte prefetching) of leaf
+/// `0x0000_0002`.
+pub(crate) fn describe_prefetch_stride(cpu: &Processor) -> Option<u16> {
+    let bytes = legacy_descriptors(cpu)?;
+    if bytes.contains(&0xF1) {
+        Some(128)
+    } else if bytes.contains(&0xF0) {
+        Some(64)
+    } else {
+        None
+    }
+}
+
+fn walk_intel_legacy_cache(
+    _system: &System,
+    cpu: &Processor,
+    out: &mut CacheVec,
+    filter: &Vec<CacheType>,
+    table: &CacheDescriptorTable,
+) {
+    if let Some(mut bytes) = legacy_descriptors(cpu) {
+        // Descriptor 0x49 depends on the processor signature, so it can't be
+        // covered by the descriptor table unless it's overridden there.
+        #[cfg(feature = "legacy-cache-descriptors")]
+        if table.lookup(0x49).is_none() && bytes.contains(&0x49) {
+            bytes.retain(|byte| *byte != 0x49);
+            if filter.contains(&CacheType::Unified) {
+                let desc = describe_descriptor_49(cpu);
+                debug!("walk_intel_legacy_cache() descriptor 49: {:?}", desc);
+                out.0.push(desc);
+            }
+        }
+
+        lookup_descriptors(out, bytes, filter, table);
     }
 }
 
-fn walk_intel_cache(system: &System, cpu: &Processor, out: &mut CacheVec) {
+/// Describes the 4MB 16-way cache reported by legacy descriptor 0x49. Per
+/// Intel's AP-485, this is the L3 on the Intel Xeon processor MP with family
+/// 0Fh and model 06h, and the L2 on every other processor.
+#[cfg(feature = "legacy-cache-descriptors")]
+fn describe_descriptor_49(cpu: &Processor) -> CacheDescription {
+    let level = match (cpu.signature.family, cpu.signature.model) {
+        (0xF, 0x6) => CacheLevel::L3,
+        _ => CacheLevel::L2,
+    };
+    CacheDescription::builder()
+        .cachetype(CacheType::Unified)
+        .level(level)
+        .size(4096)
+        .linesize(64)
+        .associativity(CacheAssociativity::from_identifier(0x10))
+        .build()
+}
+
+fn walk_intel_cache(system: &System, cpu: &Processor, out: &mut CacheVec, table: &CacheDescriptorTable) {
     if !walk_intel_dcp(system, cpu, out) {
         {
             let cache_types: Vec<CacheType> = vec![
@@ -1112,12 +1619,12 @@ fn walk_intel_cache(system: &System, cpu: &Processor, out: &mut CacheVec) {
                 CacheType::Unified,
                 CacheType::Trace,
             ];
-            walk_intel_legacy_cache(system, cpu, out, &cache_types);
+            walk_intel_legacy_cache(system, cpu, out, &cache_types, table);
         }
     }
 }
 
-fn walk_intel_tlb(system: &System, cpu: &Processor, out: &mut CacheVec) {
+fn walk_intel_tlb(system: &System, cpu: &Processor, out: &mut CacheVec, table: &CacheDescriptorTable) {
     if !walk_intel_dat(system, cpu, out) {
         {
             let cache_types: Vec<CacheType> = vec![
@@ -1127,20 +1634,41 @@ fn walk_intel_tlb(system: &System, cpu: &Processor, out: &mut CacheVec) {
                 CacheType::LoadOnlyTLB,
                 CacheType::StoreOnlyTLB,
             ];
-            walk_intel_legacy_cache(system, cpu, out, &cache_types);
+            walk_intel_legacy_cache(system, cpu, out, &cache_types, table);
         }
     }
 }
 
-fn walk_intel(system: &System, cpu: &Processor, out: &mut CacheVec) {
-    walk_intel_cache(system, cpu, out);
-    walk_intel_tlb(system, cpu, out);
+fn walk_intel(system: &System, cpu: &Processor, out: &mut CacheVec, table: &CacheDescriptorTable) {
+    walk_intel_cache(system, cpu, out, table);
+    walk_intel_tlb(system, cpu, out, table);
 }
 
 pub(crate) fn describe_caches(system: &System, cpu: &Processor) -> CacheVec {
+    describe_caches_with(system, cpu, &CacheDescriptorTable::new())
+}
+
+pub(crate) fn describe_caches_with(
+    system: &System,
+    cpu: &Processor,
+    table: &CacheDescriptorTable,
+) -> CacheVec {
     let mut caches: CacheVec = CacheVec(vec![]);
     walk_amd(system, cpu, &mut caches);
-    walk_intel(system, cpu, &mut caches);
+    walk_intel(system, cpu, &mut caches, table);
     caches.0.sort();
+    fill_instances_per_socket(system, &mut caches);
     caches
 }
+
+/// Divides the inferred instance counts in `caches` across the sockets of the
+/// decoded topology, if there is more than one.
+pub(crate) fn fill_instances_per_socket(system: &System, caches: &mut CacheVec) {
+    let sockets: usize = match system.topology.valid() && system.topology.sockets > 1 {
+        true => system.topology.sockets as usize,
+        false => 1,
+    };
+    for cache in caches.0.iter_mut() {
+        cache.instances_per_socket = cache.instances / sockets;
+    }
+}
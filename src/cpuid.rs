@@ -1,18 +1,52 @@
 #![allow(dead_code)]
 
 use bitflags::*;
+use core::fmt;
+use core::hash::{Hash, Hasher};
 use log::*;
 use modular_bitfield::prelude::*;
+#[cfg(feature = "std")]
 use scan_fmt::*;
-use std::fmt;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
 use std::fs::File;
+#[cfg(feature = "std")]
 use std::io::{prelude::*, BufReader};
 
-use crate::cache::{describe_caches, CacheVec};
-use crate::feature::{describe_features, FeatureVec};
-use crate::topology::{describe_topology, TopologyID, TopologyInferred, TopologyProps};
-
-#[derive(Debug, Clone, PartialEq)]
+use crate::address::{describe_address_sizes, describe_physical_core_count, AddressSizes};
+use crate::cache::{
+    describe_cache_domains, describe_caches, describe_caches_with, describe_prefetch_stride,
+    fill_instances_per_socket, size_str, CacheDescriptorTable, CacheDomain, CacheLevel, CacheVec,
+};
+use crate::diff::{diff_systems, SystemDiff};
+use crate::error::CpuidError;
+use crate::feature::{
+    describe_all_features, describe_features, describe_features_and_unknown, describe_features_with, Feature,
+    FeatureLeaf, FeatureVec,
+};
+use crate::internal::microarchitectures::MICROARCHITECTURES;
+use crate::internal::prelude::*;
+use crate::frequency::{describe_frequency, describe_tsc, FrequencyInfo, TscInfo};
+use crate::security::{describe_pconfig, describe_sev, PconfigInfo, SevInfo};
+use crate::rdt::{describe_amd_qos, describe_rdt, AmdQosInfo, RdtInfo};
+use crate::trace::{describe_processor_trace, ProcessorTraceInfo};
+use crate::virtualization::{
+    describe_hyperv, describe_hypervisor_timing, describe_kvm, describe_svm, describe_vmware, HyperVInfo,
+    HypervisorTiming, KvmFeatures, SvmInfo, VmwareInfo,
+};
+use crate::validate::{validate_system, ValidationWarning};
+use crate::xsave::{describe_xsave, XsaveInfo};
+use crate::topology::{
+    count_sockets, describe_core_type, describe_hybrid_info, describe_smt_capable, describe_topology,
+    describe_topology_props, render_topology_dot, render_topology_tree, CoreType, HybridInfo, TopologyID, TopologyInferred, TopologyProps,
+};
+
+/// Magic bytes at the start of a gzip stream.
+#[cfg(feature = "std")]
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// Input `eax` and `ecx` values for a single CPUID invocation.
 pub struct LeafID {
     /// Input `eax` value
@@ -28,7 +62,26 @@ impl LeafID {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// Describes whether a leaf is available in the raw CPUID data of a processor,
+/// as returned by [Processor::leaf_status](struct.Processor.html#method.leaf_status).
+pub enum LeafStatus {
+    /// The leaf was collected. Its registers may still all be zero.
+    Present,
+
+    /// The leaf wasn't collected, and its base doesn't advertise it: either
+    /// the base is unsupported or the leaf is above its maximum leaf. Also
+    /// used for subleaves beyond the ones collected for an otherwise present
+    /// leaf. Anything the leaf would report can be treated as absent.
+    AbsentAboveMax,
+
+    /// The leaf is within the range advertised by its base, but wasn't
+    /// collected, e.g. because the dump was truncated. Nothing is known about
+    /// what it would report.
+    NotCollected,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// Output registers for a single CPUID invocation.
 pub struct Registers {
     pub eax: u32,
@@ -47,10 +100,62 @@ pub enum RegisterName {
     Unknown,
 }
 
+impl RegisterName {
+    /// Returns the four CPUID output registers, in `[EAX, EBX, ECX, EDX]`
+    /// order. [Unknown](#variant.Unknown) is not included.
+    pub fn all() -> [RegisterName; 4] {
+        [RegisterName::EAX, RegisterName::EBX, RegisterName::ECX, RegisterName::EDX]
+    }
+}
+
+impl fmt::Display for RegisterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            RegisterName::EAX => "EAX",
+            RegisterName::EBX => "EBX",
+            RegisterName::ECX => "ECX",
+            RegisterName::EDX => "EDX",
+            RegisterName::Unknown => "unknown",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Error returned when parsing a [RegisterName](enum.RegisterName.html) from a
+/// string fails.
+pub struct ParseRegisterNameError {
+    /// The register name which was not recognized.
+    pub name: String,
+}
+
+impl fmt::Display for ParseRegisterNameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown register name {:?}", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseRegisterNameError {}
+
+impl core::str::FromStr for RegisterName {
+    type Err = ParseRegisterNameError;
+
+    /// Parses one of the register names produced by the `Display`
+    /// implementation, ignoring case. `unknown` is not accepted.
+    fn from_str(s: &str) -> Result<RegisterName, ParseRegisterNameError> {
+        RegisterName::all()
+            .iter()
+            .find(|register| register.to_string().eq_ignore_ascii_case(s.trim()))
+            .copied()
+            .ok_or_else(|| ParseRegisterNameError { name: s.to_string() })
+    }
+}
+
 bitflags! {
     /// Bitmask for Vendor IDs, used to identify both physical CPU vendors and
     /// hypervisor vendors.
-    #[derive(Copy, Clone)]
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
     pub struct VendorMask: u32 {
         /// This mask contains no vendor flags.
         const UNKNOWN = 0x0000_0000;
@@ -97,6 +202,18 @@ bitflags! {
         /// Vendor flag for `Virtual CPU` CPUs
         const VIRTUAL_CPU = 0x0000_0100;
 
+        /// Vendor flag for `  Shanghai  ` (Zhaoxin) CPUs
+        const ZHAOXIN = 0x0000_0200;
+
+        /// Vendor flag for `NexGenDriven` CPUs
+        const NEXGEN = 0x0000_0400;
+
+        /// Vendor flag for `UMC UMC UMC ` CPUs
+        const UMC = 0x0000_0800;
+
+        /// Vendor flag for `Vortex86 SoC` CPUs
+        const VORTEX86 = 0x0000_1000;
+
         //
         // Common vendor masks
         //
@@ -104,6 +221,9 @@ bitflags! {
         /// Mask covering both Intel and AMD CPUs.
         const INTELAMD = 0x1000_0003;
 
+        /// Mask covering both Centaur (VIA) and Zhaoxin CPUs.
+        const CENTAURZHAOXIN = 0x2000_0204;
+
         //
         // One-hot identifiers for hypervisor vendors
         //
@@ -137,7 +257,124 @@ impl fmt::Debug for VendorMask {
     }
 }
 
+/// Display names for each vendor, in the order they are printed. Vendors whose
+/// masks overlap others (e.g. `HYGON` includes `AMD`) must come first. Names
+/// must be unique so that they parse back to the same mask.
+const VENDOR_NAMES: [(VendorMask, &str); 22] = [
+    (VendorMask::HYGON, "HygonGenuine"),
+    (VendorMask::INTELAMD, "GenuineIntel/AuthenticAMD"),
+    (VendorMask::CENTAURZHAOXIN, "CentaurHauls/Shanghai"),
+    (VendorMask::VIRTUAL_CPU, "Virtual CPU"),
+    (VendorMask::INTEL, "GenuineIntel"),
+    (VendorMask::AMD, "AuthenticAMD"),
+    (VendorMask::CENTAUR, "CentaurHauls"),
+    (VendorMask::CYRIX, "CyrixInstead"),
+    (VendorMask::TRANSMETA, "GenuineTMx86"),
+    (VendorMask::RISE, "RiseRiseRise"),
+    (VendorMask::SIS, "SiS SiS SiS"),
+    (VendorMask::ZHAOXIN, "Shanghai"),
+    (VendorMask::NEXGEN, "NexGenDriven"),
+    (VendorMask::UMC, "UMC UMC UMC"),
+    (VendorMask::VORTEX86, "Vortex86 SoC"),
+    (VendorMask::HYPERV, "Microsoft Hv"),
+    (VendorMask::KVM, "KVMKVMKVM"),
+    (VendorMask::TCG, "TCGTCGTCGTCG"),
+    (VendorMask::XEN, "XenVMMXenVMM"),
+    (VendorMask::PARALLELS, "Parallels"),
+    (VendorMask::VMWARE, "VMwareVMware"),
+    (VendorMask::BHYVE, "bhyve bhyve"),
+];
+
+impl fmt::Display for VendorMask {
+    /// Bits not covered by any vendor name are printed as a hexadecimal mask,
+    /// so that parsing the output always yields the same mask.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut remaining = *self;
+        let mut first = true;
+        for (mask, name) in VENDOR_NAMES.iter() {
+            if !mask.is_empty() && remaining.contains(*mask) {
+                remaining.remove(*mask);
+                if !first {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", name)?;
+                first = false;
+            }
+        }
+        if !remaining.is_empty() {
+            if !first {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:#x}", remaining.bits())?;
+            first = false;
+        }
+        if first {
+            write!(f, "unknown")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Error returned when parsing a [VendorMask](struct.VendorMask.html) from a
+/// string fails.
+pub struct ParseVendorMaskError {
+    /// The vendor name which was not recognized.
+    pub name: String,
+}
+
+impl fmt::Display for ParseVendorMaskError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown vendor name {:?}", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseVendorMaskError {}
+
+impl core::str::FromStr for VendorMask {
+    type Err = ParseVendorMaskError;
+
+    /// Parses a comma-separated list of vendor names and hexadecimal masks, as
+    /// produced by the `Display` implementation.
+    fn from_str(s: &str) -> Result<VendorMask, ParseVendorMaskError> {
+        let mut vendor = VendorMask::UNKNOWN;
+        for part in s.split(',') {
+            let part = part.trim();
+            if part.is_empty() || part == "unknown" {
+                continue;
+            }
+            if let Some(hex) = part.strip_prefix("0x") {
+                let bits = u32::from_str_radix(hex, 16).map_err(|_| ParseVendorMaskError {
+                    name: part.to_string(),
+                })?;
+                vendor |= VendorMask::from_bits_retain(bits);
+                continue;
+            }
+            match VENDOR_NAMES.iter().find(|(_, name)| *name == part) {
+                Some((mask, _)) => vendor |= *mask,
+                None => {
+                    return Err(ParseVendorMaskError {
+                        name: part.to_string(),
+                    })
+                }
+            }
+        }
+        Ok(vendor)
+    }
+}
+
 impl VendorMask {
+    /// Returns only the physical CPU vendor flags in this mask.
+    pub fn cpu_vendor(&self) -> VendorMask {
+        self.intersection(VendorMask::ANY_CPU)
+    }
+
+    /// Returns only the hypervisor vendor flags in this mask.
+    pub fn hypervisor_vendor(&self) -> VendorMask {
+        self.intersection(VendorMask::ANY_HYPERVISOR)
+    }
+
     fn from_string(input: String) -> VendorMask {
         debug!("attempting to match vendor string {:?}", input);
         match input.as_str() {
@@ -151,6 +388,11 @@ impl VendorMask {
             "SiS SiS SiS " => VendorMask::SIS,
             "RiseRiseRise" => VendorMask::RISE,
             "Virtual CPU " => VendorMask::VIRTUAL_CPU | VendorMask::INTEL,
+            "  Shanghai  " => VendorMask::ZHAOXIN,
+            " Shanghai " => VendorMask::ZHAOXIN,
+            "NexGenDriven" => VendorMask::NEXGEN,
+            "UMC UMC UMC " => VendorMask::UMC,
+            "Vortex86 SoC" => VendorMask::VORTEX86,
 
             "Microsoft Hv" => VendorMask::HYPERV,
             "KVMKVMKVM" => VendorMask::KVM,
@@ -164,16 +406,25 @@ impl VendorMask {
     }
 }
 
-fn bytes_to_ascii_dump(bytes: Vec<u8>) -> String {
-    let mut string = String::with_capacity(bytes.len());
-    for byte in bytes.iter() {
-        if *byte > 31 && *byte < 127 {
-            string.push(*byte as char)
-        } else {
-            string.push('.')
+/// Renders the bytes in a set of registers, ordered as `[eax, ebx, ecx, edx]`,
+/// using `.` as a placeholder for bytes that aren't printable ASCII. Writes
+/// straight to the formatter, so dumping leaves doesn't allocate.
+struct AsciiDump<'a>(&'a Registers);
+
+impl<'a> fmt::Display for AsciiDump<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use core::fmt::Write;
+        for register in [self.0.eax, self.0.ebx, self.0.ecx, self.0.edx].iter() {
+            for byte in register.to_le_bytes().iter() {
+                if *byte > 31 && *byte < 127 {
+                    f.write_char(*byte as char)?;
+                } else {
+                    f.write_char('.')?;
+                }
+            }
         }
+        Ok(())
     }
-    string
 }
 
 fn bytes_to_ascii(bytes: Vec<u8>) -> String {
@@ -226,28 +477,71 @@ impl Registers {
         }
     }
 
-    /// Read a specific register by name.
-    pub fn register(&self, name: RegisterName) -> u32 {
+    /// Read a specific register by name. Fails with
+    /// [InvalidRegister](../error/enum.CpuidError.html#variant.InvalidRegister)
+    /// for [RegisterName::Unknown](enum.RegisterName.html#variant.Unknown).
+    pub fn register(&self, name: RegisterName) -> Result<u32, CpuidError> {
         match name {
-            RegisterName::EAX => self.eax,
-            RegisterName::EBX => self.ebx,
-            RegisterName::ECX => self.ecx,
-            RegisterName::EDX => self.edx,
-            _ => panic!("Invalid register"),
+            RegisterName::EAX => Ok(self.eax),
+            RegisterName::EBX => Ok(self.ebx),
+            RegisterName::ECX => Ok(self.ecx),
+            RegisterName::EDX => Ok(self.edx),
+            RegisterName::Unknown => Err(CpuidError::InvalidRegister(name)),
+        }
+    }
+
+    /// Extract the inclusive bit range `lo..=hi` from a register, shifted down
+    /// so that bit `lo` becomes bit 0. Fails with
+    /// [InvalidBitRange](../error/enum.CpuidError.html#variant.InvalidBitRange)
+    /// unless `lo <= hi <= 31`.
+    ///
+    /// ```
+    /// use cpuid::cpuid::{RegisterName, Registers};
+    ///
+    /// // Leaf 1 on a Tiger Lake processor
+    /// let leaf1 = Registers::new(0x000806c1, 0x00100800, 0x7ffafbbf, 0xbfebfbff);
+    /// let family = leaf1.bits(RegisterName::EAX, 8, 11).unwrap();
+    /// let model = leaf1.bits(RegisterName::EAX, 4, 7).unwrap();
+    /// let extended_model = leaf1.bits(RegisterName::EAX, 16, 19).unwrap();
+    /// assert_eq!(family, 0x6);
+    /// assert_eq!((extended_model << 4) | model, 0x8c);
+    /// assert!(leaf1.bits(RegisterName::EAX, 19, 16).is_err());
+    /// ```
+    pub fn bits(&self, name: RegisterName, lo: u8, hi: u8) -> Result<u32, CpuidError> {
+        if lo > hi || hi > 31 {
+            return Err(CpuidError::InvalidBitRange { lo, hi });
         }
+        let value = self.register(name)? >> lo;
+        let width = hi - lo + 1;
+        if width == 32 {
+            Ok(value)
+        } else {
+            Ok(value & ((1u32 << width) - 1))
+        }
+    }
+
+    /// Test a single bit in a register. Fails with
+    /// [InvalidBitRange](../error/enum.CpuidError.html#variant.InvalidBitRange)
+    /// if `n` is greater than 31.
+    ///
+    /// ```
+    /// use cpuid::cpuid::{RegisterName, Registers};
+    ///
+    /// let leaf1 = Registers::new(0x000806c1, 0x00100800, 0x7ffafbbf, 0xbfebfbff);
+    /// // SSE2 is bit 26 of EDX
+    /// assert_eq!(leaf1.bit(RegisterName::EDX, 26), Ok(true));
+    /// // Bit 31 of ECX is reserved for hypervisors
+    /// assert_eq!(leaf1.bit(RegisterName::ECX, 31), Ok(false));
+    /// ```
+    pub fn bit(&self, name: RegisterName, n: u8) -> Result<bool, CpuidError> {
+        Ok(self.bits(name, n, n)? != 0)
     }
 
     /// Try to create an ASCII representation of the bytes in the registers,
     /// ordered as `[eax, ebx, ecx, edx]`. Uses `.` as a placeholder for bytes
     /// that cannot be represented as ASCII values.
     pub fn ascii(&self) -> String {
-        let mut bytes: Vec<u8> = vec![];
-        for register in [self.eax, self.ebx, self.ecx, self.edx].iter() {
-            for byte in register.to_le_bytes().iter() {
-                bytes.push(*byte);
-            }
-        }
-        bytes_to_ascii_dump(bytes)
+        AsciiDump(self).to_string()
     }
 }
 
@@ -272,9 +566,12 @@ pub fn cpuid(input: &LeafID, output: &mut Registers) {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// Structure containing a CPUID leaf ID and the output register values for a
 /// single CPUID invocation.
+///
+/// Displays as a dump line, `CPUID eax:ecx = eax ebx ecx edx | ascii`. The
+/// alternate form (`{:#}`) leaves out the ASCII column.
 pub struct RawCPUIDResponse {
     /// Input leaf ID
     pub input: LeafID,
@@ -335,8 +632,9 @@ struct SignatureRaw {
     __: B4,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-/// Describes the processor signature (family, model, stepping).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Describes the processor signature (family, model, stepping). Signatures are
+/// ordered by family, then model, then stepping.
 pub struct Signature {
     /// Family ID, including extended family.
     pub family: u16,
@@ -365,6 +663,29 @@ impl Signature {
             stepping: 0,
         }
     }
+
+    /// Returns a best-guess microarchitecture (or core) codename for this
+    /// signature, such as `"Coppermine"` or `"Zen 2"`, or `None` if the
+    /// vendor/family/model combination is not known.
+    ///
+    /// ```
+    /// use cpuid::cpuid::{Signature, VendorMask};
+    ///
+    /// let rome = Signature { family: 0x17, model: 0x31, stepping: 0 };
+    /// assert_eq!(rome.microarchitecture(VendorMask::AMD), Some("Zen 2"));
+    /// assert_eq!(rome.microarchitecture(VendorMask::INTEL), None);
+    /// ```
+    pub fn microarchitecture(&self, vendor: VendorMask) -> Option<&'static str> {
+        MICROARCHITECTURES
+            .iter()
+            .find(|(mask, family, models, steppings, _)| {
+                vendor.intersects(*mask)
+                    && *family == self.family
+                    && models.contains(&self.model)
+                    && steppings.contains(&self.stepping)
+            })
+            .map(|(_, _, _, _, name)| *name)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -375,14 +696,25 @@ impl Signature {
 ///
 /// More of the decoded data is available in the [System](struct.System.html)
 /// structure.
+///
+/// Two processors compare equal if their index, raw leaves, vendor mask and
+/// signature match. Topology information derived from the leaves is not
+/// compared.
 pub struct Processor {
     /// Logical index of this CPU on the system.
     pub index: u32,
 
     /// Vector of all the raw [responses](struct.RawCPUIDResponse.html) for known
-    /// CPUID leaves.
+    /// CPUID leaves. For processors which share their leaves with others (see
+    /// [System::from_file_deduplicated](struct.System.html#method.from_file_deduplicated)),
+    /// only holds the leaves reporting APIC IDs, and the other leaves are
+    /// stored once for all of them. Use [iter_leaves](#method.iter_leaves) to
+    /// read every leaf either way.
     pub leaves: Vec<RawCPUIDResponse>,
 
+    /// Leaves shared with identical processors, if any
+    shared: Option<Arc<Vec<RawCPUIDResponse>>>,
+
     /// Matching vendor IDs discovered in the various CPUID leaves. May contain
     /// more than one vendor, e.g. if a hypervisor is present.
     pub vendor: VendorMask,
@@ -397,6 +729,34 @@ pub struct Processor {
 
     /// Used for decoding `x2apic_id`
     topology_props: TopologyProps,
+
+    /// Cleared when collection may have run on a different CPU
+    reliable: bool,
+
+    /// Set when the leaves were collected from the local machine
+    local: bool,
+}
+
+impl PartialEq for Processor {
+    fn eq(&self, other: &Processor) -> bool {
+        self.index == other.index
+            && self.iter_leaves().eq(other.iter_leaves())
+            && self.vendor == other.vendor
+            && self.signature == other.signature
+    }
+}
+
+impl Eq for Processor {}
+
+impl Hash for Processor {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+        for leaf in self.iter_leaves() {
+            leaf.hash(state);
+        }
+        self.vendor.hash(state);
+        self.signature.hash(state);
+    }
 }
 
 impl Processor {
@@ -405,11 +765,14 @@ impl Processor {
         Processor {
             index: 0,
             leaves: vec![],
+            shared: None,
             vendor: VendorMask::UNKNOWN,
             signature: Signature::new(),
             topology_decoded: None,
             x2apic_id: 0,
             topology_props: TopologyProps::new(),
+            reliable: true,
+            local: false,
         }
     }
 
@@ -417,8 +780,47 @@ impl Processor {
     /// set your process or thread affinity to prevent the OS from moving the
     /// process/thread around causing you to query other CPUs inadvertently.
     pub fn from_local() -> Processor {
+        Processor::from_local_filtered(&LeafFilter::new())
+    }
+
+    /// Walk the CPUID leaves allowed by `filter` on the current processor. See
+    /// [LeafFilter](struct.LeafFilter.html) for which leaves are always
+    /// collected. The same affinity caveat as
+    /// [from_local](#method.from_local) applies.
+    pub fn from_local_filtered(filter: &LeafFilter) -> Processor {
         let mut processor: Processor = Processor::new();
-        walk_bases(&mut processor.leaves);
+        processor.local = true;
+        let apic_id_before = local_initial_apic_id();
+        walk_bases(&mut processor.leaves, filter);
+        let apic_id_after = local_initial_apic_id();
+        if apic_id_before != apic_id_after {
+            warn!(
+                "initial APIC ID changed from {:#x} to {:#x} while collecting leaves, the thread was moved to \
+                 another CPU",
+                apic_id_before, apic_id_after
+            );
+            processor.reliable = false;
+        }
+        processor
+    }
+
+    /// Returns `false` if this processor's leaves were collected by
+    /// [from_local](struct.System.html#method.from_local) but may have come
+    /// from a different logical CPU than intended, e.g. because the thread
+    /// could not be pinned to the CPU, moved between CPUs during collection,
+    /// reports a different APIC ID than the OS expects for the CPU, or shares
+    /// its APIC ID with another processor. Always `true` for processors loaded
+    /// from dumps.
+    pub fn reliable(&self) -> bool {
+        self.reliable
+    }
+
+    /// Creates a `Processor` from raw CPUID responses collected elsewhere or
+    /// crafted by hand, and decodes its vendor and signature.
+    pub fn from_leaves(leaves: Vec<RawCPUIDResponse>) -> Processor {
+        let mut processor: Processor = Processor::new();
+        processor.leaves = leaves;
+        processor.decode();
         processor
     }
 
@@ -426,18 +828,134 @@ impl Processor {
     /// matching the specified input `eax` and `ecx` values. Returns None if no
     /// match was found for this processor.
     pub fn get_subleaf(&self, eax: u32, ecx: u32) -> Option<&RawCPUIDResponse> {
-        for result in self.leaves.iter() {
-            if result.input.eax == eax && result.input.ecx == ecx {
-                return Some(&result);
+        let leaves = match &self.shared {
+            Some(shared) if !reports_apic_id(eax) => &shared[..],
+            _ => &self.leaves[..],
+        };
+        leaves
+            .iter()
+            .find(|result| result.input.eax == eax && result.input.ecx == ecx)
+    }
+
+    /// Iterates over all the raw [responses](struct.RawCPUIDResponse.html) of
+    /// this processor in collection order, including any leaves shared with
+    /// identical processors.
+    pub fn iter_leaves(&self) -> impl Iterator<Item = &RawCPUIDResponse> {
+        let (leaves, mut own) = match &self.shared {
+            Some(shared) => (&shared[..], self.leaves.iter()),
+            None => (&self.leaves[..], [].iter()),
+        };
+        let shared = self.shared.is_some();
+        leaves.iter().map(move |leaf| {
+            if shared && reports_apic_id(leaf.input.eax) {
+                own.next().unwrap_or(leaf)
+            } else {
+                leaf
             }
+        })
+    }
+
+    /// Tells whether this processor shares its leaves with `other`, apart from
+    /// the leaves reporting APIC IDs. See
+    /// [System::from_file_deduplicated](struct.System.html#method.from_file_deduplicated).
+    pub fn shares_leaves_with(&self, other: &Processor) -> bool {
+        match (&self.shared, &other.shared) {
+            (Some(shared), Some(other_shared)) => Arc::ptr_eq(shared, other_shared),
+            _ => false,
+        }
+    }
+
+    /// Stores the leaves which don't report APIC IDs in `shared`, which must
+    /// hold the same leaves in the same order apart from those reporting APIC
+    /// IDs.
+    fn share_leaves(&mut self, shared: Arc<Vec<RawCPUIDResponse>>) {
+        self.leaves.retain(|leaf| reports_apic_id(leaf.input.eax));
+        self.shared = Some(shared);
+    }
+
+    /// Copies the shared leaves back into [leaves](#structfield.leaves) so
+    /// they can be modified.
+    fn unshare_leaves(&mut self) {
+        if self.shared.is_some() {
+            let leaves: Vec<RawCPUIDResponse> = self.iter_leaves().cloned().collect();
+            self.leaves = leaves;
+            self.shared = None;
+        }
+    }
+
+    /// Gets a mutable reference to a single
+    /// [RawCPUIDResponse](struct.RawCPUIDResponse.html) object matching the
+    /// specified input `eax` and `ecx` values, e.g. to mask off feature bits.
+    /// Call [re_fill](#method.re_fill) afterwards to keep the vendor and
+    /// signature consistent with the modified leaves.
+    pub fn get_subleaf_mut(&mut self, eax: u32, ecx: u32) -> Option<&mut RawCPUIDResponse> {
+        self.unshare_leaves();
+        self.leaves
+            .iter_mut()
+            .find(|result| result.input.eax == eax && result.input.ecx == ecx)
+    }
+
+    /// Inserts `leaf`, replacing any existing leaf with the same input `eax`
+    /// and `ecx` values. Call [re_fill](#method.re_fill) afterwards to keep the
+    /// vendor and signature consistent with the modified leaves.
+    pub fn set_leaf(&mut self, leaf: RawCPUIDResponse) {
+        match self.get_subleaf_mut(leaf.input.eax, leaf.input.ecx) {
+            Some(existing) => *existing = leaf,
+            None => self.leaves.push(leaf),
+        }
+    }
+
+    /// Discards the decoded vendor and signature and decodes them again from
+    /// the current [leaves](#structfield.leaves). Needed after modifying the
+    /// leaves of an already decoded processor. Decode the owning
+    /// [System](struct.System.html) again to refresh its decoded fields too.
+    pub fn re_fill(&mut self) {
+        self.vendor = VendorMask::UNKNOWN;
+        self.signature = Signature::new();
+        self.decode();
+    }
+
+    /// Gets a single leaf like [get_subleaf](#method.get_subleaf), but if it
+    /// hasn't been collected yet on a processor built by
+    /// [from_local](#method.from_local) or
+    /// [from_local_filtered](#method.from_local_filtered), executes the CPUID
+    /// instruction on whichever CPU the current thread is running on and
+    /// caches the result in [leaves](#structfield.leaves). Useful when only a
+    /// few leaves are needed and walking every leaf is wasteful.
+    ///
+    /// Processors imported from dumps or built from leaves may describe
+    /// another machine, so the CPUID instruction is never executed for them,
+    /// and a missing leaf is returned with all output registers zeroed. Use
+    /// [try_query](#method.try_query) to tell missing leaves apart.
+    pub fn query(&mut self, eax: u32, ecx: u32) -> RawCPUIDResponse {
+        match self.try_query(eax, ecx) {
+            Some(leaf) => leaf,
+            None => RawCPUIDResponse {
+                input: LeafID::new(eax, ecx),
+                output: Registers::new(0, 0, 0, 0),
+            },
         }
-        None
+    }
+
+    /// Like [query](#method.query), but returns `None` if the leaf is missing
+    /// and can't be queried because the processor wasn't collected locally.
+    pub fn try_query(&mut self, eax: u32, ecx: u32) -> Option<RawCPUIDResponse> {
+        if let Some(leaf) = self.get_subleaf(eax, ecx) {
+            return Some(leaf.clone());
+        }
+        if !self.local {
+            return None;
+        }
+        let leaf = RawCPUIDResponse::invoke(eax, ecx);
+        self.unshare_leaves();
+        self.leaves.push(leaf.clone());
+        Some(leaf)
     }
 
     /// Gets all [RawCPUIDResponse](struct.RawCPUIDResponse.html) objects with matching input `eax` values.
     pub fn get(&self, eax: u32) -> Vec<&RawCPUIDResponse> {
         let mut out: Vec<&RawCPUIDResponse> = vec![];
-        for result in self.leaves.iter() {
+        for result in self.iter_leaves() {
             if result.input.eax == eax {
                 out.push(&result);
             }
@@ -445,6 +963,42 @@ impl Processor {
         out
     }
 
+    /// Returns the maximum supported leaf reported by the specified CPUID
+    /// `base` (e.g. `0x8000_0000`), or `None` if the base is not supported.
+    pub fn max_leaf(&self, base: u32) -> Option<u32> {
+        match self.get_subleaf(base, 0) {
+            Some(leaf) if valid_max_leaf(base, leaf.output.eax) => Some(leaf.output.eax),
+            _ => None,
+        }
+    }
+
+    /// Tells whether the leaf matching the input `eax` and `ecx` values was
+    /// collected, and if not, whether the processor advertises it. Use this to
+    /// tell a leaf which reads as all zeros apart from one that is missing.
+    pub fn leaf_status(&self, eax: u32, ecx: u32) -> LeafStatus {
+        if self.get_subleaf(eax, ecx).is_some() {
+            return LeafStatus::Present;
+        }
+        if self.iter_leaves().any(|leaf| leaf.input.eax == eax) {
+            // Subleaves are only collected up to the last valid one.
+            return LeafStatus::AbsentAboveMax;
+        }
+        match base_of(eax).and_then(|base| self.max_leaf(base)) {
+            Some(max_leaf) if eax <= max_leaf => LeafStatus::NotCollected,
+            _ => LeafStatus::AbsentAboveMax,
+        }
+    }
+
+    /// Returns the list of CPUID bases (from [CPUID_BASES](constant.CPUID_BASES.html))
+    /// which report a valid maximum leaf on this processor.
+    pub fn supported_bases(&self) -> Vec<u32> {
+        CPUID_BASES
+            .iter()
+            .filter(|base| self.max_leaf(**base).is_some())
+            .cloned()
+            .collect()
+    }
+
     /// Finds the matching hardware vendor as a
     /// [VendorMask](struct.VendorMask.html) for the current processor, based on
     /// the contents of leaf `0x0000_0000`.
@@ -463,23 +1017,120 @@ impl Processor {
     }
 
     /// Tests if the specified `bit` is set in the specified `register` from a
-    /// particular leaf/subleaf.
+    /// particular leaf/subleaf. Returns `false` if the leaf is missing or
+    /// `register` is [RegisterName::Unknown](enum.RegisterName.html#variant.Unknown).
     pub fn has_feature_bit(&self, leaf: u32, subleaf: u32, register: RegisterName, bit: u32) -> bool {
         match self.get_subleaf(leaf, subleaf) {
             None => false,
-            Some(leafdata) => {
-                let bits = match register {
-                    RegisterName::EAX => leafdata.output.eax,
-                    RegisterName::EBX => leafdata.output.ebx,
-                    RegisterName::ECX => leafdata.output.ecx,
-                    RegisterName::EDX => leafdata.output.edx,
-                    _ => panic!("Invalid register"),
-                };
-                bits & (1 << bit) != 0
+            Some(leafdata) => match leafdata.output.register(register) {
+                Ok(bits) => bit < 32 && bits & (1 << bit) != 0,
+                Err(_) => false,
+            },
+        }
+    }
+
+    /// Lists every known feature applicable to `vendor`, each paired with
+    /// whether it is present on this processor. Unlike the decoded
+    /// [features](struct.System.html#structfield.features), which only contain
+    /// present features, this makes it easy to compare feature sets between
+    /// processors.
+    pub fn all_features(&self, vendor: VendorMask) -> Vec<(Feature, bool)> {
+        describe_all_features(self, vendor)
+    }
+
+    /// Decodes the features present on this processor, as
+    /// [System::decode](struct.System.html#method.decode) does for the first
+    /// processor, without needing a [System](struct.System.html).
+    pub fn features(&self, vendor: VendorMask) -> FeatureVec {
+        describe_features(self, vendor)
+    }
+
+    /// Decodes the caches and TLBs of this processor. The `system` supplies
+    /// the vendor, and the logical CPU count and topology used to count cache
+    /// instances, so it need not contain this processor.
+    pub fn describe_caches(&self, system: &System) -> CacheVec {
+        describe_caches(system, self)
+    }
+
+    /// Decodes the masks and shifts which split this processor's x2APIC ID
+    /// into socket, die, module, core and thread IDs from leaf `0x0000_001F`
+    /// or `0x0000_000B`. Returns `None`
+    /// if the leaf is missing or incomplete. Unlike
+    /// [System::topology_props](struct.System.html#structfield.topology_props),
+    /// this does not require decoding a whole system.
+    pub fn topology_props(&self) -> Option<TopologyProps> {
+        describe_topology_props(self)
+    }
+
+    /// Tests the hypervisor present bit (leaf `0x0000_0001`, `ecx` bit 31).
+    /// This is set by most hypervisors even when the hypervisor vendor leaf at
+    /// `0x4000_0000` is missing or unrecognized.
+    pub fn hypervisor_present(&self) -> bool {
+        self.has_feature_bit(0x0000_0001, 0, RegisterName::ECX, 31)
+    }
+
+    /// Returns the maximum hypervisor leaf reported in `eax` of leaf
+    /// `0x4000_0000`, or `None` if no hypervisor leaves are reported. Leaves
+    /// from `0x4000_0000` up to this value are safe to query.
+    pub fn hypervisor_max_leaf(&self) -> Option<u32> {
+        self.max_leaf(0x4000_0000)
+    }
+
+    /// Tests the invariant TSC bit (leaf `0x8000_0007`, `edx` bit 8). When set,
+    /// the TSC runs at a constant rate across P-, C- and T-state transitions,
+    /// so it can be used as a wall-clock time source.
+    pub fn invariant_tsc(&self) -> bool {
+        self.has_feature_bit(0x8000_0007, 0, RegisterName::EDX, 8)
+    }
+
+    /// The 96-bit processor serial number of Pentium III processors, as
+    /// `[upper, middle, lower]` 32-bit words. The upper word is the processor
+    /// signature from leaf `0x0000_0001` `eax`, and the middle and lower words
+    /// come from leaf `0x0000_0003` `edx` and `ecx`. Returns `None` unless the
+    /// PSN feature (leaf `0x0000_0001`, `edx` bit 18) is enabled.
+    pub fn serial_number(&self) -> Option<[u32; 3]> {
+        if !self.has_feature_bit(0x0000_0001, 0, RegisterName::EDX, 18) {
+            return None;
+        }
+        match (self.get_subleaf(0x0000_0001, 0), self.get_subleaf(0x0000_0003, 0)) {
+            (Some(leaf1), Some(leaf3)) => Some([leaf1.output.eax, leaf3.output.edx, leaf3.output.ecx]),
+            _ => None,
+        }
+    }
+
+    /// Type of core this logical CPU belongs to on hybrid processors, from leaf
+    /// `0x0000_001A`. Returns `None` if the processor does not report a core
+    /// type.
+    pub fn core_type(&self) -> Option<CoreType> {
+        describe_core_type(self)
+    }
+
+    /// Core type and native model ID of this logical CPU on hybrid processors,
+    /// from leaf `0x0000_001A` `eax`. Returns `None` if the leaf is missing or
+    /// reads as zero.
+    pub fn hybrid_info(&self) -> Option<HybridInfo> {
+        describe_hybrid_info(self)
+    }
+
+    /// APIC ID of this logical CPU. Uses the 32-bit x2APIC ID from leaf
+    /// `0x0000_001F` or `0x0000_000B` `edx` when either is supported, and
+    /// falls back to the 8-bit initial APIC ID in leaf `0x0000_0001` `ebx`
+    /// bits 31:24 otherwise. Returns `None` if none of these leaves are
+    /// present.
+    pub fn apic_id(&self) -> Option<u32> {
+        for leaf_id in [0x0000_001F, 0x0000_000B].iter() {
+            match self.get_subleaf(*leaf_id, 0) {
+                Some(leaf) if leaf.output.ebx != 0 => return Some(leaf.output.edx),
+                _ => {}
             }
         }
+        self.get_subleaf(0x0000_0001, 0).map(|leaf| leaf.output.ebx >> 24)
     }
 
+    /// Socket, core and thread IDs of this logical CPU, decoded from its x2APIC
+    /// ID using the masks in [System::topology_props](struct.System.html#structfield.topology_props).
+    /// Only available once the owning [System](struct.System.html) has been
+    /// decoded.
     pub fn topology(&self) -> &Option<TopologyID> {
         &self.topology_decoded
     }
@@ -530,8 +1181,16 @@ impl Processor {
     }
 
     fn fill_x2apic_topology(&mut self, props: &TopologyProps) {
-        if let Some(leaf) = self.get_subleaf(0x0000_000B, 0x0) {
-            self.x2apic_id = leaf.output.edx;
+        // Leaf 0x0000_001F supersedes leaf 0x0000_000B, but both report the
+        // same x2APIC ID in edx. Leaf 0x0000_001F reads as all zeroes when it
+        // is within range but unsupported.
+        match self.get_subleaf(0x0000_001F, 0x0) {
+            Some(leaf) if leaf.output.ebx != 0 => self.x2apic_id = leaf.output.edx,
+            _ => {
+                if let Some(leaf) = self.get_subleaf(0x0000_000B, 0x0) {
+                    self.x2apic_id = leaf.output.edx;
+                }
+            }
         }
         self.topology_props = props.clone();
         self.topology_decoded = Some(TopologyID {
@@ -552,6 +1211,12 @@ impl Processor {
 ///
 /// Aside from the raw CPUID data, this structure also contains the decoded
 /// vendor IDs, name string, cache descriptions, feature descriptions, etc.
+///
+/// Two systems compare equal if their processors (see
+/// [Processor](struct.Processor.html)), CPU count, vendor mask and name string
+/// match. The remaining fields are decoded from the processors' leaves and are
+/// not compared, so a decoded system never equals an undecoded one, since the
+/// undecoded vendor mask is empty.
 pub struct System {
     /// Vector of processors in the system. May only contain one instance if the
     /// platform does not support thread affinity APIs (*COUGH, COUGH* macOS
@@ -572,14 +1237,87 @@ pub struct System {
     /// Vector of all the discovered caches and TLBs in the first processor.
     pub caches: CacheVec,
 
+    /// Hardware prefetch stride of the first processor in bytes, from the
+    /// legacy descriptors of leaf `0x0000_0002`, if reported.
+    pub prefetch_stride: Option<u16>,
+
     /// Vector of all the discovered features in the first processor.
     pub features: FeatureVec,
 
+    /// Feature bits which are set in the first processor but not described by
+    /// any known feature, as `(leaf, register, bit)`. Useful for discovering
+    /// features of new processors.
+    pub unknown_features: Vec<(LeafID, RegisterName, u8)>,
+
     /// Inferred CPU topology (cores, threads, sockets), if available.
     pub topology: TopologyInferred,
 
     /// Discovered CPU topology metadata, if available.
     pub topology_props: TopologyProps,
+
+    /// Physical and linear address sizes, if available.
+    pub address_sizes: Option<AddressSizes>,
+
+    /// AMD SME/SEV capabilities, if available.
+    pub sev: Option<SevInfo>,
+
+    /// Intel PCONFIG target types, such as MKTME, if available.
+    pub pconfig: Option<PconfigInfo>,
+
+    /// XSAVE area size and supported state components, if available.
+    pub xsave: Option<XsaveInfo>,
+
+    /// Intel Processor Trace capabilities, if available.
+    pub processor_trace: Option<ProcessorTraceInfo>,
+
+    /// Resource Director Technology monitoring and allocation capabilities, if
+    /// available.
+    pub rdt: Option<RdtInfo>,
+
+    /// AMD Platform QoS Extended enforcement capabilities, if available.
+    pub amd_qos: Option<AmdQosInfo>,
+
+    /// Base, maximum, and bus frequencies, if available.
+    pub frequency: Option<FrequencyInfo>,
+
+    /// TSC / core crystal clock ratio, if available.
+    pub tsc: Option<TscInfo>,
+
+    /// AMD SVM capabilities, if available.
+    pub svm: Option<SvmInfo>,
+
+    /// Hyper-V hypervisor interface details, if running under Hyper-V.
+    pub hyperv: Option<HyperVInfo>,
+
+    /// KVM paravirtualization features, if running under KVM.
+    pub kvm: Option<KvmFeatures>,
+
+    /// Virtual TSC and APIC bus frequencies reported by the hypervisor, if any.
+    pub hypervisor_timing: Option<HypervisorTiming>,
+
+    /// VMware hypervisor details, if running under VMware.
+    pub vmware: Option<VmwareInfo>,
+
+}
+
+impl PartialEq for System {
+    fn eq(&self, other: &System) -> bool {
+        self.cpus == other.cpus
+            && self.cpu_count == other.cpu_count
+            && self.vendor == other.vendor
+            && self.name_string == other.name_string
+    }
+}
+
+impl Eq for System {}
+
+impl Hash for System {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.cpus.hash(state);
+        self.cpu_count.hash(state);
+        self.vendor.hash(state);
+        self.name_string.hash(state);
+    }
 }
 
 impl System {
@@ -590,51 +1328,146 @@ impl System {
             vendor: VendorMask::UNKNOWN,
             name_string: String::new(),
             caches: CacheVec::new(),
+            prefetch_stride: None,
             features: FeatureVec::new(),
+            unknown_features: vec![],
             topology: TopologyInferred::new(),
             topology_props: TopologyProps::new(),
+            address_sizes: None,
+            sev: None,
+            pconfig: None,
+            xsave: None,
+            processor_trace: None,
+            rdt: None,
+            amd_qos: None,
+            frequency: None,
+            tsc: None,
+            svm: None,
+            hyperv: None,
+            kvm: None,
+            hypervisor_timing: None,
+            vmware: None,
         }
     }
 
     /// Walk all known CPUID leaves for each CPU on the local system and store
     /// the results in a new [System](struct.System.html) object.
+    #[cfg(feature = "std")]
     pub fn from_local() -> System {
-        System::from_local_impl()
+        System::from_local_impl(&LeafFilter::new())
+    }
+
+    /// Like [from_local](#method.from_local), but only collects the leaves
+    /// allowed by `filter`, which saves a lot of CPUID instructions on large
+    /// systems when only some of the decoded information is needed.
+    ///
+    /// ```no_run
+    /// use cpuid::cpuid::{LeafFilter, System};
+    ///
+    /// // Enough for the features in leaves 0x0000_0001 and 0x0000_0007 and the
+    /// // extended features and address sizes.
+    /// let filter = LeafFilter::new()
+    ///     .allow(0x0000_0001, 0x0000_0001)
+    ///     .allow(0x0000_0007, 0x0000_0007)
+    ///     .allow(0x8000_0000, 0x8000_0008);
+    /// let system = System::from_local_filtered(&filter).with_decoded();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_local_filtered(filter: &LeafFilter) -> System {
+        System::from_local_impl(filter)
     }
 
-    #[cfg(not(target_os = "macos"))]
-    fn from_local_impl() -> System {
+    #[cfg(all(feature = "std", not(target_os = "macos")))]
+    fn from_local_impl(filter: &LeafFilter) -> System {
         let mut system: System = System::new();
         let cpu_start: u32 = 0;
         let cpu_end: u32 = num_cpus::get() as u32 - 1;
+        let os_apic_ids = os_apic_ids();
 
-        let old_affinity = affinity::get_thread_affinity().unwrap();
+        let old_affinity = match affinity::get_thread_affinity() {
+            Ok(mask) => Some(mask),
+            Err(e) => {
+                warn!("failed to read the thread affinity, not restoring it: {}", e);
+                None
+            }
+        };
 
         for cpu in cpu_start..(cpu_end + 1) {
             debug!("collecting leaves for CPU {:?}", cpu);
-            let mask = vec![cpu as usize];
-
-            // TODO: This can fail, and we should be noisy about it when it does.
-            // Though if we're on macOS we can't do anything about it since there
-            // isn't any thread affinity API there.
-            affinity::set_thread_affinity(mask).unwrap();
+            let pinned = match affinity::set_thread_affinity(vec![cpu as usize]) {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!(
+                        "failed to pin the thread to CPU {}, its leaves may be from another CPU: {}",
+                        cpu, e
+                    );
+                    false
+                }
+            };
 
-            let mut processor = Processor::from_local();
+            let mut processor = Processor::from_local_filtered(filter);
             processor.index = cpu;
+            if !pinned {
+                processor.reliable = false;
+            } else if let Some((_, expected)) = os_apic_ids.iter().find(|(index, _)| *index == cpu) {
+                // Without leaf 0x0000_000B only the low 8 bits of the APIC ID
+                // are reported.
+                match processor.apic_id() {
+                    Some(apic_id) if apic_id == *expected || apic_id == *expected & 0xFF => {}
+                    Some(apic_id) => {
+                        warn!(
+                            "CPU {} reports APIC ID {:#x} but the OS expects {:#x}, its leaves may be from another CPU",
+                            cpu, apic_id, expected
+                        );
+                        processor.reliable = false;
+                    }
+                    None => {}
+                }
+            }
             system.cpus.push(processor);
         }
 
-        affinity::set_thread_affinity(old_affinity).unwrap();
+        if let Some(mask) = old_affinity {
+            if let Err(e) = affinity::set_thread_affinity(mask) {
+                warn!("failed to restore the thread affinity: {}", e);
+            }
+        }
 
         system.cpu_count = num_cpus::get();
+        System::check_apic_ids(&mut system.cpus);
 
         system
     }
 
-    #[cfg(target_os = "macos")]
-    fn from_local_impl() -> System {
+    /// Marks processors which report the same APIC ID as another processor as
+    /// unreliable, since at least one of them was collected on the wrong CPU.
+    /// Processors which predate APIC IDs in leaf `0x0000_0001` report zero on
+    /// every CPU, so nothing is checked if all the APIC IDs are zero.
+    #[cfg(all(feature = "std", not(target_os = "macos")))]
+    fn check_apic_ids(cpus: &mut [Processor]) {
+        let apic_ids: Vec<Option<u32>> = cpus.iter().map(|cpu| cpu.apic_id()).collect();
+        if apic_ids.iter().all(|apic_id| apic_id.unwrap_or(0) == 0) {
+            return;
+        }
+        for (position, cpu) in cpus.iter_mut().enumerate() {
+            let apic_id = match apic_ids[position] {
+                Some(apic_id) => apic_id,
+                None => continue,
+            };
+            if apic_ids.iter().filter(|other| **other == Some(apic_id)).count() > 1 {
+                warn!(
+                    "CPU {} reports APIC ID {:#x} like another CPU, its leaves may be from another CPU",
+                    cpu.index, apic_id
+                );
+                cpu.reliable = false;
+            }
+        }
+    }
+
+    #[cfg(all(feature = "std", target_os = "macos"))]
+    fn from_local_impl(filter: &LeafFilter) -> System {
         let mut system: System = System::new();
-        let mut processor = Processor::from_local();
+        let mut processor = Processor::from_local_filtered(filter);
         processor.index = 0;
         debug!("collecting leaves for one CPU");
         system.cpus.push(processor);
@@ -642,45 +1475,242 @@ impl System {
         system
     }
 
+    /// Build a single-processor [System](struct.System.html) from raw CPUID
+    /// responses that were collected elsewhere. This is the main entry point
+    /// when built without the `std` feature.
+    pub fn from_slice(leaves: &[RawCPUIDResponse]) -> System {
+        let mut system: System = System::new();
+        let mut processor: Processor = Processor::new();
+        processor.index = 0;
+        processor.leaves = leaves.to_vec();
+        system.cpus.push(processor);
+        system.cpu_count = 1;
+        system
+    }
+
+    /// Build a decoded [System](struct.System.html) from `processors`, e.g.
+    /// ones created with [Processor::from_leaves](struct.Processor.html#method.from_leaves).
+    /// The processors keep their [index](struct.Processor.html#structfield.index)
+    /// values, and [cpu_count](#structfield.cpu_count) is the number of
+    /// processors given.
+    ///
+    /// Fails with [NoProcessors](../error/enum.CpuidError.html#variant.NoProcessors)
+    /// if `processors` is empty, or with
+    /// [MismatchedSignature](../error/enum.CpuidError.html#variant.MismatchedSignature)
+    /// if the processors don't all share the same signature.
+    pub fn from_processors(processors: Vec<Processor>) -> Result<System, CpuidError> {
+        let mut system: System = System::new();
+        system.cpu_count = processors.len();
+        system.cpus = processors;
+        system.check_signatures()?;
+        Ok(system.with_decoded())
+    }
+
+    /// Decodes the signature of every processor and checks that they all match
+    /// the first one, which [decode](#method.decode) relies on.
+    fn check_signatures(&mut self) -> Result<(), CpuidError> {
+        for processor in self.cpus.iter_mut() {
+            processor.decode();
+        }
+        let expected = match self.cpus.first() {
+            Some(first) => first.signature.clone(),
+            None => return Err(CpuidError::NoProcessors),
+        };
+        match self.cpus.iter().find(|cpu| cpu.signature != expected) {
+            Some(cpu) => Err(CpuidError::MismatchedSignature {
+                index: cpu.index,
+                expected,
+                found: cpu.signature.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+
     /// Import a CPUID dump file instead of querying processors on the local
-    /// machine.
+    /// machine. Blank lines, `#` comments and any other unrecognized lines are
+    /// skipped. Gzip-compressed dumps are decompressed transparently when the
+    /// `gzip` feature is enabled.
+    #[cfg(feature = "std")]
     pub fn from_file(filename: &str) -> std::io::Result<System> {
+        System::from_file_impl(filename, false, false)
+    }
+
+    /// Import a CPUID dump file like [from_file](#method.from_file), but fail
+    /// with an [InvalidData](std::io::ErrorKind::InvalidData) error naming the
+    /// offending line number if any line other than a blank line or a `#`
+    /// comment can't be parsed, or if the file contains no processors. Useful
+    /// for catching truncated or corrupted dumps.
+    #[cfg(feature = "std")]
+    pub fn from_file_strict(filename: &str) -> std::io::Result<System> {
+        System::from_file_impl(filename, true, false)
+    }
+
+    /// Import a CPUID dump file like [from_file](#method.from_file), but store
+    /// the leaves of processors which only differ in their APIC IDs once,
+    /// which saves memory on large homogeneous dumps. Every processor is still
+    /// listed in [cpus](#structfield.cpus) with its own index and the leaves
+    /// reporting its APIC IDs (leaves `0x0000_0001`, `0x0000_000B`,
+    /// `0x0000_001F` and `0x8000_001E`), so topology decoding is unaffected.
+    /// See [Processor::iter_leaves](struct.Processor.html#method.iter_leaves)
+    /// and [Processor::shares_leaves_with](struct.Processor.html#method.shares_leaves_with).
+    #[cfg(feature = "std")]
+    pub fn from_file_deduplicated(filename: &str) -> std::io::Result<System> {
+        System::from_file_impl(filename, false, true)
+    }
+
+    /// Import several CPUID dump files, such as one per socket or NUMA node, and
+    /// merge their processors into a single decoded [System](struct.System.html).
+    /// Processors keep their original CPU index unless an earlier file already
+    /// used it, in which case they are renumbered sequentially after the
+    /// highest index seen so far.
+    ///
+    /// All processors must share the same signature, so merging dumps of
+    /// different processor models or steppings fails with an
+    /// [InvalidData](std::io::ErrorKind::InvalidData) error naming the
+    /// offending file.
+    #[cfg(feature = "std")]
+    pub fn from_files(filenames: &[&str]) -> std::io::Result<System> {
+        let mut processors: Vec<Processor> = vec![];
+        let mut signature: Option<Signature> = None;
+        for filename in filenames.iter() {
+            let import = System::from_file(filename)?;
+            for mut processor in import.cpus.into_iter() {
+                processor.decode();
+                let expected = signature.get_or_insert_with(|| processor.signature.clone());
+                if *expected != processor.signature {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!(
+                            "{}: CPU {} has signature {}, but the first CPU has {}",
+                            filename, processor.index, processor.signature, expected
+                        ),
+                    ));
+                }
+                if processors.iter().any(|cpu| cpu.index == processor.index) {
+                    processor.index = processors.iter().map(|cpu| cpu.index).max().unwrap_or(0) + 1;
+                }
+                processors.push(processor);
+            }
+        }
+        System::from_processors(processors)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))
+    }
+
+    /// Import a CPUID dump from any buffered reader, using the same format and
+    /// lenient parsing as [from_file](#method.from_file). The reader is not
+    /// checked for gzip compression.
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: BufRead>(reader: R) -> std::io::Result<System> {
+        System::from_reader_impl(reader, "<reader>", false, false)
+    }
+
+    /// Import a CPUID dump from any buffered reader like
+    /// [from_reader](#method.from_reader), deduplicating identical processors
+    /// like [from_file_deduplicated](#method.from_file_deduplicated).
+    #[cfg(feature = "std")]
+    pub fn from_reader_deduplicated<R: BufRead>(reader: R) -> std::io::Result<System> {
+        System::from_reader_impl(reader, "<reader>", false, true)
+    }
+
+    /// Writes the raw leaves of every processor in the dump format read by
+    /// [from_reader](#method.from_reader), as printed by the `dump` binary.
+    /// Re-importing the output yields the same leaves, and a dump written by
+    /// this crate is reproduced byte for byte. See
+    /// [write_dump_with](#method.write_dump_with) to leave out the ASCII
+    /// column.
+    #[cfg(feature = "std")]
+    pub fn write_dump<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.write_dump_with(writer, true)
+    }
+
+    /// Like [write_dump](#method.write_dump), but if `ascii` is `false` each
+    /// line ends after the `edx` value, without the ASCII rendering of the
+    /// registers. The output can still be imported, since the ASCII column is
+    /// ignored when parsing.
+    #[cfg(feature = "std")]
+    pub fn write_dump_with<W: Write>(&self, writer: &mut W, ascii: bool) -> std::io::Result<()> {
+        for processor in self.cpus.iter() {
+            writeln!(writer, "CPU {}:", processor.index)?;
+            for leaf in processor.iter_leaves() {
+                if ascii {
+                    writeln!(writer, "{}", leaf)?;
+                } else {
+                    writeln!(writer, "{:#}", leaf)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    fn from_file_impl(filename: &str, strict: bool, dedup: bool) -> std::io::Result<System> {
         let file = File::open(filename)?;
-        let reader = BufReader::new(file);
+        let mut reader = BufReader::new(file);
+
+        let is_gzip = reader.fill_buf()?.starts_with(&GZIP_MAGIC);
+        if is_gzip {
+            #[cfg(feature = "gzip")]
+            {
+                let decoder = BufReader::new(flate2::bufread::GzDecoder::new(reader));
+                return System::from_reader_impl(decoder, filename, strict, dedup);
+            }
+            #[cfg(not(feature = "gzip"))]
+            {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("{}: gzip-compressed dumps require the \"gzip\" feature", filename),
+                ));
+            }
+        }
+
+        System::from_reader_impl(reader, filename, strict, dedup)
+    }
 
+    #[cfg(feature = "std")]
+    fn from_reader_impl<R: BufRead>(
+        reader: R,
+        filename: &str,
+        strict: bool,
+        dedup: bool,
+    ) -> std::io::Result<System> {
         let mut system: System = System::new();
         let mut processor: Processor = Processor::new();
         let mut cpu_index: i32 = -1;
+        let mut shared: Option<SharedLeaves> = if dedup { Some(HashMap::new()) } else { None };
 
-        for line in reader.lines() {
+        for (line_number, line) in reader.lines().enumerate() {
             let line = line?;
-            if let Ok((in_eax, in_ecx, out_eax, out_ebx, out_ecx, out_edx)) = scan_fmt!(&line, "CPUID {x}:{x} = {x} {x} {x} {x}", [hex u32], [hex u32], [hex u32], [hex u32], [hex u32], [hex u32])
-            {
-                processor.leaves.push(RawCPUIDResponse {
-                    input: LeafID {
-                        eax: in_eax,
-                        ecx: in_ecx,
-                    },
-                    output: Registers {
-                        eax: out_eax,
-                        ebx: out_ebx,
-                        ecx: out_ecx,
-                        edx: out_edx,
-                    },
-                })
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            if let Ok(leaf) = trimmed.parse::<RawCPUIDResponse>() {
+                processor.leaves.push(leaf)
             } else if let Ok(sc_index) = scan_fmt!(&line, "CPU {}:", i32) {
                 if cpu_index >= 0 {
                     processor.index = cpu_index as u32;
-                    system.cpus.push(processor);
+                    system.push_processor(processor, shared.as_mut());
                     processor = Processor::new();
                 }
                 cpu_index = sc_index;
+            } else if strict {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("{}:{}: malformed line: {:?}", filename, line_number + 1, trimmed),
+                ));
+            } else {
+                debug!("skipping malformed line {}: {:?}", line_number + 1, trimmed);
             }
         }
 
         if cpu_index >= 0 {
             processor.index = cpu_index as u32;
-            system.cpus.push(processor);
+            system.push_processor(processor, shared.as_mut());
+        } else if strict {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("{}: no processors found", filename),
+            ));
         }
 
         system.cpu_count = system.cpus.len();
@@ -688,36 +1718,473 @@ impl System {
         Ok(system)
     }
 
+    /// Appends `processor` to [cpus](#structfield.cpus). If `shared` is given,
+    /// the leaves which don't report APIC IDs are shared with any earlier
+    /// processor whose leaves only differ in those reporting APIC IDs.
+    #[cfg(feature = "std")]
+    fn push_processor(&mut self, mut processor: Processor, shared: Option<&mut SharedLeaves>) {
+        if let Some(shared) = shared {
+            let key: Vec<RawCPUIDResponse> = processor
+                .leaves
+                .iter()
+                .map(|leaf| {
+                    let mut leaf = leaf.clone();
+                    if reports_apic_id(leaf.input.eax) {
+                        leaf.output = Registers::new(0, 0, 0, 0);
+                    }
+                    leaf
+                })
+                .collect();
+            let leaves = shared
+                .entry(key)
+                .or_insert_with(|| Arc::new(processor.leaves.clone()))
+                .clone();
+            processor.share_leaves(leaves);
+        }
+        self.cpus.push(processor);
+    }
+
+    /// Orders the processors by [index](struct.Processor.html#structfield.index)
+    /// and the leaves of each processor by input `eax` and `ecx`, so that dumps
+    /// of the same machine compare cleanly regardless of collection order.
+    /// Leaves with the same input keep their relative order. Dumps are written
+    /// in collection or file order unless sorted, which keeps imported dumps
+    /// byte-identical when written back out.
+    pub fn sorted(mut self) -> Self {
+        let key = |leaf: &RawCPUIDResponse| (leaf.input.eax, leaf.input.ecx);
+        self.cpus.sort_by_key(|cpu| cpu.index);
+        // Sort each set of shared leaves once, and keep sharing the result.
+        let mut sorted_shared: Vec<(Arc<Vec<RawCPUIDResponse>>, Arc<Vec<RawCPUIDResponse>>)> = vec![];
+        for cpu in self.cpus.iter_mut() {
+            cpu.leaves.sort_by_key(key);
+            if let Some(shared) = cpu.shared.take() {
+                let existing = sorted_shared
+                    .iter()
+                    .find(|(original, _)| Arc::ptr_eq(original, &shared));
+                let sorted = match existing {
+                    Some((_, sorted)) => sorted.clone(),
+                    None => {
+                        let mut leaves: Vec<RawCPUIDResponse> = shared.to_vec();
+                        leaves.sort_by_key(key);
+                        let sorted = Arc::new(leaves);
+                        sorted_shared.push((shared, sorted.clone()));
+                        sorted
+                    }
+                };
+                cpu.shared = Some(sorted);
+            }
+        }
+        self
+    }
+
+    /// Decodes the raw CPUID data and returns the decoded
+    /// [System](struct.System.html). See [decode](#method.decode).
     pub fn with_decoded(mut self) -> Self {
         self.decode();
         self
     }
 
+    /// Replaces the detected [vendor](#structfield.vendor) with `vendor` and
+    /// decodes the [caches](#structfield.caches) and
+    /// [features](#structfield.features) again as if the processor had
+    /// reported it, e.g. to see how an emulator with an unusual vendor string
+    /// would be decoded as Intel. Call this on a decoded system; the other
+    /// decoded fields still reflect the detected vendor, and decoding again
+    /// restores it.
+    ///
+    /// This deliberately bypasses vendor detection. Vendors interpret many
+    /// leaves differently, so forcing a vendor which doesn't match the dump
+    /// may produce nonsensical caches and features.
+    pub fn with_vendor_override(mut self, vendor: VendorMask) -> Self {
+        self.vendor = vendor;
+        self.fill_caches();
+        self.fill_features();
+        self.fill_cache_instances_per_socket();
+        self
+    }
+
+    /// Decodes the raw CPUID data of every processor and fills in the vendor,
+    /// name string, caches, features, topology and the other decoded fields.
+    /// None of these are populated by the constructors, so importing a dump
+    /// without decoding it is cheap.
+    ///
+    /// Most fields are decoded from the first processor only. If the
+    /// processors' signatures differ, a warning is logged and they are decoded
+    /// as if they all matched the first one. A system without processors is
+    /// left undecoded.
     pub fn decode(&mut self) {
         // Order is important. Feature/cache decoding depends a lot on the vendor string.
-        for processor in self.cpus.iter_mut() {
-            processor.decode();
-        }
-
         // We make an assumption that all the processors have identical features and other
-        // properties, so we should ensure that each logical CPU has the same signature value.
-        for processor in self.cpus.iter() {
-            assert_eq!(processor.signature, self.cpus[0].signature);
+        // properties, so we check that each logical CPU has the same signature value.
+        match self.check_signatures() {
+            Ok(()) => {}
+            Err(CpuidError::NoProcessors) => {
+                warn!("no processors to decode");
+                return;
+            }
+            Err(e) => warn!("{}, decoding it like the first CPU", e),
         }
 
         self.fill_vendor();
         self.fill_processor_name();
         self.fill_caches();
+        self.fill_prefetch_stride();
         self.fill_features();
         self.fill_x2apic();
+        self.fill_cache_instances_per_socket();
+        self.fill_address_sizes();
+        self.fill_sev();
+        self.fill_pconfig();
+        self.fill_xsave();
+        self.fill_processor_trace();
+        self.fill_rdt();
+        self.fill_amd_qos();
+        self.fill_frequency();
+        self.fill_svm();
+        self.fill_hyperv();
+        self.fill_kvm();
+        self.fill_hypervisor_timing();
+        self.fill_vmware();
+    }
+
+    /// Returns an undecoded copy of this system with identifying data scrubbed
+    /// from the raw leaves of every processor, for sharing dumps:
+    ///
+    /// * Leaf `0x0000_0003` (processor serial number): all registers are zeroed.
+    /// * Leaf `0x0000_0001`: the initial APIC ID in `ebx` bits 31:24 is zeroed.
+    ///
+    /// Everything else is copied unchanged. See
+    /// [anonymized_with](#method.anonymized_with) to also blank the brand string.
+    pub fn anonymized(&self) -> System {
+        self.anonymized_with(false)
+    }
+
+    /// Like [anonymized](#method.anonymized), but if `blank_brand_string` is
+    /// `true` all registers of the brand string leaves `0x8000_0002` through
+    /// `0x8000_0004` are zeroed as well.
+    pub fn anonymized_with(&self, blank_brand_string: bool) -> System {
+        let mut system: System = System::new();
+        for cpu in self.cpus.iter() {
+            let mut processor: Processor = Processor::new();
+            processor.index = cpu.index;
+            processor.leaves = cpu.iter_leaves().cloned().collect();
+            for leaf in processor.leaves.iter_mut() {
+                match leaf.input.eax {
+                    0x0000_0001 => leaf.output.ebx &= 0x00FF_FFFF,
+                    0x0000_0003 => leaf.output = Registers::new(0, 0, 0, 0),
+                    0x8000_0002..=0x8000_0004 if blank_brand_string => {
+                        leaf.output = Registers::new(0, 0, 0, 0)
+                    }
+                    _ => {}
+                }
+            }
+            system.cpus.push(processor);
+        }
+        system.cpu_count = self.cpu_count;
+        system
+    }
+
+    /// Gets a single leaf of the first processor. On systems built by
+    /// [from_local](#method.from_local) or
+    /// [from_local_filtered](#method.from_local_filtered), a leaf which
+    /// hasn't been collected yet is queried on the current CPU and cached. See
+    /// [Processor::query](struct.Processor.html#method.query), including for
+    /// what is returned for missing leaves of systems imported from dumps.
+    pub fn query(&mut self, eax: u32, ecx: u32) -> RawCPUIDResponse {
+        match self.cpus.first_mut() {
+            Some(cpu) => cpu.query(eax, ecx),
+            None => RawCPUIDResponse {
+                input: LeafID::new(eax, ecx),
+                output: Registers::new(0, 0, 0, 0),
+            },
+        }
+    }
+
+    /// Like [query](#method.query), but returns `None` if the system has no
+    /// processors or the leaf is missing and can't be queried, see
+    /// [Processor::try_query](struct.Processor.html#method.try_query).
+    pub fn try_query(&mut self, eax: u32, ecx: u32) -> Option<RawCPUIDResponse> {
+        self.cpus.first_mut()?.try_query(eax, ecx)
+    }
+
+    /// Selects processors by index using the syntax described in
+    /// [parse_cpu_selection](fn.parse_cpu_selection.html), e.g. `"0-3,8"` or
+    /// `"all"`.
+    pub fn select_cpus(&self, spec: &str) -> Result<Vec<u32>, CpuidError> {
+        let available: Vec<u32> = self.cpus.iter().map(|cpu| cpu.index).collect();
+        parse_cpu_selection(spec, &available)
+    }
+
+    /// Maps the logical index of each processor to its APIC ID, as
+    /// `(index, apic_id)` pairs. Processors without an APIC ID are skipped.
+    /// See [Processor::apic_id](struct.Processor.html#method.apic_id).
+    pub fn apic_id_table(&self) -> Vec<(u32, u32)> {
+        self.cpus
+            .iter()
+            .filter_map(|cpu| cpu.apic_id().map(|apic_id| (cpu.index, apic_id)))
+            .collect()
+    }
+
+    /// Lists the logical CPUs sharing each instance of each cache, e.g. which
+    /// CPUs share each L3 cache on processors with several per socket.
+    /// Computed from the APIC IDs and the number of threads sharing each
+    /// cache, so processors without an APIC ID are left out. Every logical
+    /// CPU in [cpus](#structfield.cpus) is covered, including those imported
+    /// by [from_file_deduplicated](#method.from_file_deduplicated), but on
+    /// macOS only one CPU is collected locally. Requires decoding first.
+    pub fn cache_domains(&self) -> Vec<CacheDomain> {
+        describe_cache_domains(self)
+    }
+
+    /// Renders the topology IDs of every processor as an indented tree of
+    /// sockets, cores and threads, with the core type of each core on hybrid
+    /// processors. Returns an empty string if no processor has topology IDs,
+    /// e.g. before decoding or on processors without leaf `0x0000_000B`.
+    ///
+    /// ```text
+    /// Socket 0
+    ///   Core 0
+    ///     Thread 0: CPU 0
+    ///     Thread 1: CPU 1
+    /// ```
+    pub fn topology_tree(&self) -> String {
+        render_topology_tree(self)
+    }
+
+    /// Renders the same topology as [topology_tree](#method.topology_tree) as
+    /// an undirected GraphViz DOT graph. The graph has no nodes if no processor
+    /// has topology IDs.
+    pub fn topology_dot(&self) -> String {
+        render_topology_dot(self)
+    }
+
+    /// Returns `true` if the first processor reports that it is running under a
+    /// hypervisor. See
+    /// [Processor::hypervisor_present](struct.Processor.html#method.hypervisor_present).
+    pub fn hypervisor_present(&self) -> bool {
+        match self.cpus.first() {
+            Some(cpu) => cpu.hypervisor_present(),
+            None => false,
+        }
+    }
+
+    /// Returns the maximum hypervisor leaf of the first processor. See
+    /// [Processor::hypervisor_max_leaf](struct.Processor.html#method.hypervisor_max_leaf).
+    pub fn hypervisor_max_leaf(&self) -> Option<u32> {
+        self.cpus.first().and_then(|cpu| cpu.hypervisor_max_leaf())
+    }
+
+    /// Returns the hypervisor interface signature from `eax` of leaf
+    /// `0x4000_0001` when running under Hyper-V, e.g. `"Hv#1"`. Other
+    /// hypervisors use that register for their own purposes, so `None` is
+    /// returned for them. Requires decoding first.
+    pub fn hypervisor_interface_signature(&self) -> Option<&str> {
+        self.hyperv.as_ref().map(|hyperv| hyperv.interface_signature.as_str())
+    }
+
+    /// Number of logical CPUs in the system. Uses the product of the inferred
+    /// [topology](#structfield.topology) levels when it is valid, and falls
+    /// back to [cpu_count](#structfield.cpu_count) otherwise. Requires decoding
+    /// first.
+    pub fn total_logical_cpus(&self) -> usize {
+        if self.topology.valid() {
+            self.topology.total() as usize
+        } else {
+            self.cpu_count
+        }
+    }
+
+    /// Returns `false` if the inferred [topology](#structfield.topology) is
+    /// valid but accounts for a different number of logical CPUs than
+    /// [cpu_count](#structfield.cpu_count), e.g. on virtual machines which only
+    /// expose some of the host's CPUs, or on partial dumps.
+    pub fn topology_consistent(&self) -> bool {
+        !self.topology.valid() || self.total_logical_cpus() == self.cpu_count
+    }
+
+    /// Returns `true` if the processors support more than one thread per core,
+    /// even if SMT was disabled by firmware or the operating system. Compare
+    /// with [smt_enabled](#method.smt_enabled) to confirm that SMT is actually
+    /// off. Requires decoding first.
+    pub fn smt_capable(&self) -> bool {
+        describe_smt_capable(self)
+    }
+
+    /// Returns `true` if the inferred [topology](#structfield.topology) has
+    /// more than one thread per core. Requires decoding first.
+    pub fn smt_enabled(&self) -> bool {
+        self.topology.valid() && self.topology.threads_per_core > 1
+    }
+
+    /// Returns `true` if more than one distinct
+    /// [CoreType](../topology/enum.CoreType.html) was found across all the
+    /// processors, e.g. on processors mixing P-cores and E-cores.
+    pub fn is_hybrid(&self) -> bool {
+        let mut core_types: Vec<CoreType> = self.cpus.iter().filter_map(|cpu| cpu.core_type()).collect();
+        core_types.sort();
+        core_types.dedup();
+        core_types.len() > 1
+    }
+
+    /// Compares this system against `other`, e.g. dumps captured before and
+    /// after a microcode or BIOS update. Reports raw leaves that were added,
+    /// removed or changed on the first processor, along with added and
+    /// removed features and caches. Both systems should be decoded first.
+    pub fn diff(&self, other: &System) -> SystemDiff {
+        diff_systems(self, other)
+    }
+
+    /// Checks the raw leaves of every processor for signs of a broken CPU,
+    /// hypervisor or capture: leaves outside the maximum leaf advertised by
+    /// their base, missing or duplicated leaves, reserved bits which are set,
+    /// and bases which advertise leaves that all read as zero. Returns an
+    /// empty vector if nothing suspicious was found.
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        validate_system(self)
+    }
+
+    /// Returns the detected features that have a stable rustc `target_feature`
+    /// equivalent, formatted for `-C target-feature`. Requires decoding first.
+    ///
+    /// ```no_run
+    /// use cpuid::cpuid::System;
+    ///
+    /// let system = System::from_local().with_decoded();
+    /// // e.g. "+sse4.2,+avx2,+fma,+bmi2,+aes"
+    /// println!("RUSTFLAGS=-C target-feature={}", system.rustc_target_features());
+    /// ```
+    pub fn rustc_target_features(&self) -> String {
+        self.features.rustc_target_features()
+    }
+
+    /// Returns the names of the AVX-512 extensions supported by the first
+    /// processor, without the `AVX512` prefix. See
+    /// [FeatureVec::avx512_subsets](../feature/struct.FeatureVec.html#method.avx512_subsets).
+    pub fn avx512_subsets(&self) -> Vec<&'static str> {
+        self.features.avx512_subsets()
+    }
+
+    /// Returns the width in bits of the widest SIMD vectors supported by the
+    /// first processor. See
+    /// [FeatureVec::max_vector_bits](../feature/struct.FeatureVec.html#method.max_vector_bits).
+    pub fn max_vector_bits(&self) -> u16 {
+        self.features.max_vector_bits()
+    }
+
+    /// Returns a one-line summary of the system for log lines, composed of the
+    /// vendor, processor name, signature, core and thread counts, last level
+    /// cache and the newest SIMD level, e.g. `"GenuineIntel 11th Gen Intel(R)
+    /// Core(TM) i7-1165G7 @ 2.80GHz (Family 6h, Model 8Ch, Stepping 1h) — 4C/8T,
+    /// L3 12MB, AVX-512"`. Parts which weren't decoded are left out, and the
+    /// core count is only given when the topology is valid. Requires decoding
+    /// first.
+    pub fn summary(&self) -> String {
+        let mut summary = self.vendor.to_string();
+        if !self.name_string.trim().is_empty() {
+            summary.push_str(&format!(" {}", self.name_string.trim()));
+        }
+        if let Some(cpu) = self.cpus.first() {
+            summary.push_str(&format!(" ({})", cpu.signature));
+        }
+        let mut details: Vec<String> = vec![];
+        if self.topology.valid() {
+            let threads = self.topology.total();
+            details.push(format!(
+                "{}C/{}T",
+                threads / self.topology.threads_per_core as u64,
+                threads
+            ));
+        } else {
+            details.push(format!("{}T", self.cpu_count));
+        }
+        let last_level = self
+            .caches
+            .iter()
+            .filter(|cache| !cache.cachetype.is_tlb() && cache.level != CacheLevel::Unknown)
+            .max_by_key(|cache| cache.level);
+        if let Some(cache) = last_level {
+            let size = size_str(cache.size, cache.cachetype);
+            details.push(format!("{} {}", cache.level, size));
+        }
+        if let Some(level) = self.features.simd_level() {
+            details.push(level.to_string());
+        }
+        format!("{} — {}", summary, details.join(", "))
+    }
+
+    /// Number of physical cores per socket, as reported by leaf `0x8000_0008`.
+    /// Only available on AMD processors.
+    pub fn physical_core_count(&self) -> Option<u32> {
+        match self.cpus.first() {
+            Some(cpu) => describe_physical_core_count(cpu, self.vendor),
+            None => None,
+        }
+    }
+
+    /// Gets the [Processor](struct.Processor.html) with the specified logical
+    /// CPU index, if it was enumerated.
+    pub fn cpu(&self, index: u32) -> Option<&Processor> {
+        self.cpus.iter().find(|cpu| cpu.index == index)
+    }
+
+    /// Decodes the caches and TLBs of the processor with the specified logical
+    /// CPU index, rather than of the first processor. Useful on systems where
+    /// not all processors are identical.
+    pub fn caches_for(&self, index: u32) -> Option<CacheVec> {
+        self.cpu(index).map(|cpu| describe_caches(self, cpu))
+    }
+
+    /// Decodes the features of the processor with the specified logical CPU
+    /// index, rather than of the first processor. Useful on systems where not
+    /// all processors are identical.
+    pub fn features_for(&self, index: u32) -> Option<FeatureVec> {
+        self.cpu(index).map(|cpu| describe_features(cpu, self.vendor))
+    }
+
+    /// Decodes the caches and TLBs of the first processor like
+    /// [caches](#structfield.caches), but consults `table` for the legacy
+    /// descriptors in leaf `0x0000_0002`. See
+    /// [CacheDescriptorTable](../cache/struct.CacheDescriptorTable.html).
+    /// Empty if there are no processors.
+    pub fn caches_with(&self, table: &CacheDescriptorTable) -> CacheVec {
+        match self.cpus.first() {
+            Some(cpu) => describe_caches_with(self, cpu, table),
+            None => CacheVec::new(),
+        }
+    }
+
+    /// Decodes the features of the first processor like
+    /// [features](#structfield.features), but also decodes the feature bits
+    /// described in the user-supplied `extra` tables. See
+    /// [describe_features_with](../feature/fn.describe_features_with.html).
+    /// Empty if there are no processors.
+    pub fn features_with(&self, extra: &[FeatureLeaf]) -> FeatureVec {
+        match self.cpus.first() {
+            Some(cpu) => describe_features_with(cpu, self.vendor, extra),
+            None => FeatureVec::new(),
+        }
     }
 
     fn fill_caches(&mut self) {
         self.caches = describe_caches(self, &self.cpus[0])
     }
 
+    fn fill_prefetch_stride(&mut self) {
+        self.prefetch_stride = describe_prefetch_stride(&self.cpus[0]);
+    }
+
+    fn fill_cache_instances_per_socket(&mut self) {
+        // Caches are decoded before the topology, so revisit them now that the
+        // socket count is known.
+        let mut caches = CacheVec(core::mem::take(&mut self.caches.0));
+        fill_instances_per_socket(self, &mut caches);
+        self.caches = caches;
+    }
+
     fn fill_features(&mut self) {
-        self.features = describe_features(&self.cpus[0], self.vendor);
+        let (features, unknown_features) = describe_features_and_unknown(&self.cpus[0], self.vendor);
+        self.features = features;
+        self.unknown_features = unknown_features;
     }
 
     fn fill_vendor(&mut self) {
@@ -735,33 +2202,202 @@ impl System {
                 }
             }
         }
-        if bytes.len() == 3 * 4 * 4 {
+        // Some virtual CPUs only expose part of the brand string, so use
+        // whatever is available.
+        if !bytes.is_empty() {
             self.name_string = squeeze_str(bytes_to_ascii(bytes));
             debug!("decoded name string: {:#?}", self.name_string);
         }
     }
 
+    fn fill_address_sizes(&mut self) {
+        self.address_sizes = describe_address_sizes(&self.cpus[0]);
+    }
+
+    fn fill_sev(&mut self) {
+        self.sev = describe_sev(&self.cpus[0], self.vendor);
+    }
+
+    fn fill_pconfig(&mut self) {
+        self.pconfig = describe_pconfig(&self.cpus[0], self.vendor);
+    }
+
+    fn fill_xsave(&mut self) {
+        self.xsave = describe_xsave(&self.cpus[0]);
+    }
+
+    fn fill_processor_trace(&mut self) {
+        self.processor_trace = describe_processor_trace(&self.cpus[0], self.vendor);
+    }
+
+    fn fill_rdt(&mut self) {
+        self.rdt = describe_rdt(&self.cpus[0], self.vendor);
+    }
+
+    fn fill_amd_qos(&mut self) {
+        self.amd_qos = describe_amd_qos(&self.cpus[0], self.vendor);
+    }
+
+    fn fill_frequency(&mut self) {
+        self.frequency = describe_frequency(&self.cpus[0]);
+        self.tsc = describe_tsc(&self.cpus[0]);
+    }
+
+    fn fill_svm(&mut self) {
+        self.svm = describe_svm(&self.cpus[0], self.vendor);
+    }
+
+    fn fill_hyperv(&mut self) {
+        self.hyperv = describe_hyperv(&self.cpus[0], self.vendor);
+    }
+
+    fn fill_kvm(&mut self) {
+        self.kvm = describe_kvm(&self.cpus[0], self.vendor);
+    }
+
+    fn fill_hypervisor_timing(&mut self) {
+        self.hypervisor_timing = describe_hypervisor_timing(&self.cpus[0], self.vendor);
+    }
+
+    fn fill_vmware(&mut self) {
+        self.vmware = describe_vmware(&self.cpus[0], self.vendor);
+    }
+
     fn fill_x2apic(&mut self) {
         describe_topology(self);
         for cpu in self.cpus.iter_mut() {
             cpu.fill_x2apic_topology(&self.topology_props)
         }
+
+        // The socket count inferred from the first processor alone divides the
+        // OS-reported CPU count, which is wrong on asymmetric systems. If every
+        // processor was enumerated, count the distinct package IDs instead.
+        if self.topology.valid() && self.topology_props.socket.reported {
+            if let Some(sockets) = count_sockets(self) {
+                self.topology.sockets = sockets;
+            }
+        }
+        if !self.topology_consistent() {
+            warn!(
+                "topology implies {} logical CPUs, but {} were reported",
+                self.total_logical_cpus(),
+                self.cpu_count
+            );
+        }
     }
 }
 
+/// Parses a selection of CPU indices and checks each against `available`.
+/// Accepts a single index (`21`), an inclusive range (`21-35`), a
+/// comma-separated list of either (`21,22,30-35`), or `all` (also spelled
+/// `-1`) for every available CPU. Returns the selected indices in ascending
+/// order without duplicates.
+pub fn parse_cpu_selection(spec: &str, available: &[u32]) -> Result<Vec<u32>, CpuidError> {
+    let spec = spec.trim();
+    let mut available: Vec<u32> = available.to_vec();
+    available.sort_unstable();
+    available.dedup();
+    if spec == "all" || spec == "-1" {
+        return Ok(available);
+    }
+
+    let mut selected: Vec<u32> = vec![];
+    for part in spec.split(',') {
+        let invalid = || CpuidError::InvalidCpuSelection(part.trim().to_string());
+        let parse_index = |text: &str| text.trim().parse::<u32>().map_err(|_| invalid());
+        let (start, end) = match part.find('-') {
+            Some(dash) => (parse_index(&part[..dash])?, parse_index(&part[dash + 1..])?),
+            None => {
+                let index = parse_index(part)?;
+                (index, index)
+            }
+        };
+        if start > end {
+            return Err(invalid());
+        }
+        for index in start..=end {
+            if available.binary_search(&index).is_err() {
+                return Err(CpuidError::CpuOutOfRange {
+                    index: index,
+                    first: available.first().copied().unwrap_or(0),
+                    last: available.last().copied().unwrap_or(0),
+                });
+            }
+            selected.push(index);
+        }
+    }
+    selected.sort_unstable();
+    selected.dedup();
+    Ok(selected)
+}
+
 impl fmt::Display for RawCPUIDResponse {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "CPUID {:08x}:{:02x} = {:08x} {:08x} {:08x} {:08x} | {}",
+            "CPUID {:08x}:{:02x} = {:08x} {:08x} {:08x} {:08x}",
             self.input.eax,
             self.input.ecx,
             self.output.eax,
             self.output.ebx,
             self.output.ecx,
-            self.output.edx,
-            self.output.ascii()
-        )
+            self.output.edx
+        )?;
+        if !f.alternate() {
+            write!(f, " | {}", AsciiDump(&self.output))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Error returned when parsing a [RawCPUIDResponse](struct.RawCPUIDResponse.html)
+/// from a dump line fails.
+pub struct ParseRawCPUIDResponseError {
+    /// The line which could not be parsed.
+    pub line: String,
+}
+
+impl fmt::Display for ParseRawCPUIDResponseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "malformed CPUID line {:?}", self.line)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseRawCPUIDResponseError {}
+
+impl core::str::FromStr for RawCPUIDResponse {
+    type Err = ParseRawCPUIDResponseError;
+
+    /// Parses a single `CPUID eax:ecx = eax ebx ecx edx` dump line, as produced
+    /// by the `Display` implementation. Anything after a `|` separator (the
+    /// ASCII representation of the registers) is ignored.
+    fn from_str(s: &str) -> Result<RawCPUIDResponse, ParseRawCPUIDResponseError> {
+        let line = s.trim();
+        let error = || ParseRawCPUIDResponseError { line: line.to_string() };
+        let hex = |value: &str| u32::from_str_radix(value, 16).map_err(|_| error());
+
+        let fields = line.split('|').next().unwrap_or("");
+        let fields = fields.strip_prefix("CPUID").ok_or_else(error)?;
+        let (input, output) = fields.split_once('=').ok_or_else(error)?;
+        let (in_eax, in_ecx) = input.trim().split_once(':').ok_or_else(error)?;
+        let output: Vec<&str> = output.split_whitespace().collect();
+        if output.len() != 4 {
+            return Err(error());
+        }
+        Ok(RawCPUIDResponse {
+            input: LeafID {
+                eax: hex(in_eax)?,
+                ecx: hex(in_ecx)?,
+            },
+            output: Registers {
+                eax: hex(output[0])?,
+                ebx: hex(output[1])?,
+                ecx: hex(output[2])?,
+                edx: hex(output[3])?,
+            },
+        })
     }
 }
 
@@ -818,9 +2454,13 @@ fn call_leaf_0f(out: &mut Vec<RawCPUIDResponse>, state: &mut RawCPUIDResponse) {
 }
 
 fn call_leaf_10(out: &mut Vec<RawCPUIDResponse>, state: &mut RawCPUIDResponse) {
+    // EBX bits 1 to 3 indicate which of the L3 CAT, L2 CAT and MBA subleaves
+    // are valid.
     let mut max_ecx = 0;
-    if (state.output.ebx & 0x2) != 0 {
-        max_ecx = 1
+    for resource in 1..=3 {
+        if (state.output.ebx & (1 << resource)) != 0 {
+            max_ecx = resource;
+        }
     }
     loop {
         out.push(state.clone());
@@ -897,23 +2537,139 @@ fn call_leaf_indexed(out: &mut Vec<RawCPUIDResponse>, state: &mut RawCPUIDRespon
     }
 }
 
-fn walk_leaves(out: &mut Vec<RawCPUIDResponse>, base: u32) {
-    let mut state = RawCPUIDResponse::invoke(base, 0);
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Restricts which leaves are collected by
+/// [System::from_local_filtered](struct.System.html#method.from_local_filtered).
+/// A new filter allows every leaf; once any range is allowed, only leaves in
+/// allowed ranges are collected. Denied ranges take precedence over allowed
+/// ones. The base leaf of each walked base is always collected, since it
+/// reports the maximum leaf of the base, and bases without any allowed leaves
+/// are skipped entirely.
+///
+/// The decoders need these leaves, along with leaf `0x0000_0000` for the
+/// vendor and `0x0000_0001` for the signature:
+///
+/// | Decoded information | Leaves |
+/// |---------------------|--------|
+/// | Features | `0x0000_0001`, `0x0000_0006`, `0x0000_0007`, `0x0000_0014`, `0x4000_0001`, `0x8000_0001`, `0x8000_0007`, `0x8000_0008`, `0x8000_000A`, `0x8000_001A`, `0x8000_001B`, `0x8000_001F`, `0xC000_0001` |
+/// | Caches and TLBs | `0x0000_0002`, `0x0000_0004`, `0x0000_0018`, `0x8000_0001`, `0x8000_0005`, `0x8000_0006`, `0x8000_0019`, `0x8000_001D` |
+/// | Topology | `0x0000_0001`, `0x0000_0004`, `0x0000_000B`, `0x0000_001A`, `0x0000_001F`, `0x8000_0001`, `0x8000_001D` |
+/// | Physical core count | `0x8000_0008`, `0x8000_001E` |
+/// | Processor name | `0x8000_0002` to `0x8000_0004` |
+/// | Address sizes | `0x8000_0008` |
+/// | Frequency and TSC | `0x0000_0015`, `0x0000_0016` |
+/// | XSAVE | `0x0000_000D` |
+/// | RDT and AMD QoS | `0x0000_0007`, `0x0000_000F`, `0x0000_0010`, `0x8000_0020` |
+/// | Processor trace | `0x0000_0007`, `0x0000_0014` |
+/// | SEV and PCONFIG | `0x0000_0007`, `0x0000_001B`, `0x8000_001F` |
+/// | SVM | `0x8000_0001`, `0x8000_000A` |
+/// | Hypervisors | `0x4000_0000` to `0x4000_0010` |
+///
+/// On AMD processors without leaf `0x0000_000B`, the topology is estimated
+/// from the cache sharing reported by leaf `0x8000_001D`, which is only read
+/// when leaf `0x8000_0001` reports topology extensions. The cache instance
+/// counts per socket depend on the topology, so need its leaves too.
+pub struct LeafFilter {
+    allowed: Vec<(u32, u32)>,
+    denied: Vec<(u32, u32)>,
+}
+
+impl LeafFilter {
+    /// Creates a filter which allows every leaf.
+    pub fn new() -> LeafFilter {
+        LeafFilter {
+            allowed: vec![],
+            denied: vec![],
+        }
+    }
+
+    /// Allows the leaves from `first` to `last`, inclusive.
+    pub fn allow(mut self, first: u32, last: u32) -> Self {
+        self.allowed.push((first, last));
+        self
+    }
+
+    /// Allows every leaf in `base`, e.g. `0x8000_0000` for the extended leaves.
+    pub fn allow_base(self, base: u32) -> Self {
+        self.allow(base, base.saturating_add(0xFFFF))
+    }
+
+    /// Denies the leaves from `first` to `last`, inclusive, even if they are
+    /// also allowed.
+    pub fn deny(mut self, first: u32, last: u32) -> Self {
+        self.denied.push((first, last));
+        self
+    }
+
+    /// Tests if `leaf` should be collected.
+    pub fn contains(&self, leaf: u32) -> bool {
+        let within = |(first, last): &(u32, u32)| leaf >= *first && leaf <= *last;
+        (self.allowed.is_empty() || self.allowed.iter().any(within)) && !self.denied.iter().any(within)
+    }
+
+    /// Tests if any leaf in `base` should be collected.
+    fn walks_base(&self, base: u32) -> bool {
+        let last = base.saturating_add(0xFFFF);
+        let covers = |(first, end): &(u32, u32)| *first <= base && *end >= last;
+        let overlaps = |(first, end): &(u32, u32)| *first <= last && *end >= base;
+        (self.allowed.is_empty() || self.allowed.iter().any(overlaps)) && !self.denied.iter().any(covers)
+    }
+}
+
+/// Maximum number of leaves walked within a single CPUID base. Real processors
+/// and hypervisors report far fewer leaves than this, so a larger range means
+/// the maximum leaf is bogus.
+pub const MAX_LEAVES_PER_BASE: u32 = 0x400;
+
+/// Returns the range of leaves to walk for the base leaf response `base_leaf`,
+/// from the base leaf itself up to the maximum leaf reported in its `eax`. The
+/// range is empty if `eax` is not a valid maximum leaf for the base, and is
+/// truncated to [MAX_LEAVES_PER_BASE](constant.MAX_LEAVES_PER_BASE.html)
+/// leaves.
+///
+/// ```
+/// use cpuid::cpuid::{walk_range, RawCPUIDResponse};
+///
+/// let base: RawCPUIDResponse = "CPUID 80000000:00 = 80000008 00000000 00000000 00000000"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(walk_range(&base), 0x8000_0000..0x8000_0009);
+/// ```
+pub fn walk_range(base_leaf: &RawCPUIDResponse) -> core::ops::Range<u32> {
+    let begin = base_leaf.input.eax;
 
     // All valid bases use eax to indicate the maximum supported leaf within that range.
-    if state.output.eax < base || state.output.eax > base + 0xFFFF {
+    if !valid_max_leaf(begin, base_leaf.output.eax) {
+        return begin..begin;
+    }
+
+    let count = base_leaf.output.eax.saturating_sub(begin).saturating_add(1);
+    if count > MAX_LEAVES_PER_BASE {
+        warn!(
+            "base {:08x} reports maximum leaf {:08x}, only walking the first {} leaves",
+            begin, base_leaf.output.eax, MAX_LEAVES_PER_BASE
+        );
+    }
+    begin..begin.saturating_add(count.min(MAX_LEAVES_PER_BASE))
+}
+
+fn walk_leaves(out: &mut Vec<RawCPUIDResponse>, base: u32, filter: &LeafFilter) {
+    let mut state = RawCPUIDResponse::invoke(base, 0);
+
+    let range = walk_range(&state);
+    if range.is_empty() {
         // Even if this base isn't valid, print it so that our dump is comprehensive.
         out.push(state);
         return;
     }
 
-    let begin: usize = state.input.eax as usize;
-    let end: usize = state.output.eax as usize + 1;
+    out.reserve(range.len());
 
-    out.reserve(end - begin);
-
-    for leaf in begin..end {
-        state.input.eax = leaf as u32;
+    for leaf in range {
+        if leaf != base && !filter.contains(leaf) {
+            continue;
+        }
+        state.input.eax = leaf;
         state.input.ecx = 0;
         state.call();
 
@@ -942,24 +2698,92 @@ fn walk_leaves(out: &mut Vec<RawCPUIDResponse>, base: u32) {
     }
 }
 
-fn walk_bases(out: &mut Vec<RawCPUIDResponse>) {
-    let bases = vec![
-        // Standard base.
-        0x0000_0000,
-        // Hypervisor base.
-        0x4000_0000,
-        // Extended base (mostly AMD things here)
-        0x8000_0000,
-        // Transmeta base
-        0x8086_0000,
-        // Centaur base
-        0xc000_0000,
-        // Mystery leaves, found as easter eggs on some CPUs
-        0x8FFF_FFFE,
-        0x8FFF_FFFF,
-    ];
+/// Finds the base from [CPUID_BASES](constant.CPUID_BASES.html) whose range
+/// contains `leaf`, if any.
+pub(crate) fn base_of(leaf: u32) -> Option<u32> {
+    CPUID_BASES
+        .iter()
+        .filter(|base| leaf >= **base && leaf - **base <= 0xFFFF)
+        .max()
+        .copied()
+}
+
+/// All the known CPUID base leaves.
+pub const CPUID_BASES: [u32; 7] = [
+    // Standard base.
+    0x0000_0000,
+    // Hypervisor base.
+    0x4000_0000,
+    // Extended base (mostly AMD things here)
+    0x8000_0000,
+    // Transmeta base
+    0x8086_0000,
+    // Centaur base
+    0xc000_0000,
+    // Mystery leaves, found as easter eggs on some CPUs
+    0x8FFF_FFFE,
+    0x8FFF_FFFF,
+];
+
+/// Tests if the `eax` value returned by a base leaf indicates a valid maximum
+/// leaf within that base.
+fn valid_max_leaf(base: u32, max_leaf: u32) -> bool {
+    max_leaf >= base && max_leaf <= base.saturating_add(0xFFFF)
+}
+
+/// Shared leaves of deduplicated processors, keyed on the leaves with the
+/// outputs of those reporting APIC IDs zeroed.
+#[cfg(feature = "std")]
+type SharedLeaves = HashMap<Vec<RawCPUIDResponse>, Arc<Vec<RawCPUIDResponse>>>;
+
+/// Tells whether leaf `eax` reports the APIC ID or other IDs which differ
+/// between the logical CPUs of otherwise identical processors.
+fn reports_apic_id(eax: u32) -> bool {
+    match eax {
+        0x0000_0001 | 0x0000_000B | 0x0000_001F | 0x8000_001E => true,
+        _ => false,
+    }
+}
+
+/// Reads the APIC ID the OS reports for each logical CPU from the `apicid`
+/// lines of `/proc/cpuinfo`, as `(index, apic_id)` pairs. Empty on systems
+/// without it.
+#[cfg(all(feature = "std", not(target_os = "macos")))]
+fn os_apic_ids() -> Vec<(u32, u32)> {
+    let cpuinfo = match std::fs::read_to_string("/proc/cpuinfo") {
+        Ok(cpuinfo) => cpuinfo,
+        Err(e) => {
+            debug!("not checking APIC IDs, failed to read /proc/cpuinfo: {}", e);
+            return vec![];
+        }
+    };
+    let mut apic_ids: Vec<(u32, u32)> = vec![];
+    let mut index: Option<u32> = None;
+    for line in cpuinfo.lines() {
+        let mut parts = line.splitn(2, ':');
+        let key = parts.next().unwrap_or("").trim();
+        let value = parts.next().unwrap_or("").trim();
+        match key {
+            "processor" => index = value.parse().ok(),
+            "apicid" => {
+                if let (Some(index), Ok(apic_id)) = (index, value.parse()) {
+                    apic_ids.push((index, apic_id));
+                }
+            }
+            _ => {}
+        }
+    }
+    apic_ids
+}
+
+/// Reads the initial APIC ID of the CPU the current thread is running on from
+/// leaf `0x0000_0001` `ebx` bits 31:24.
+fn local_initial_apic_id() -> u32 {
+    RawCPUIDResponse::invoke(0x0000_0001, 0).output.ebx >> 24
+}
 
-    for base in bases.iter() {
-        walk_leaves(out, *base);
+fn walk_bases(out: &mut Vec<RawCPUIDResponse>, filter: &LeafFilter) {
+    for base in CPUID_BASES.iter().filter(|base| filter.walks_base(**base)) {
+        walk_leaves(out, *base, filter);
     }
 }
@@ -0,0 +1,155 @@
+use core::fmt;
+
+use crate::cache::CacheDescription;
+use crate::cpuid::{RawCPUIDResponse, System};
+use crate::feature::Feature;
+use crate::internal::prelude::*;
+use crate::internal::util::indent;
+
+#[derive(Debug, Clone, Default)]
+/// Describes the differences between two [Systems](../cpuid/struct.System.html),
+/// as returned by [System::diff](../cpuid/struct.System.html#method.diff).
+/// Leaves are compared on the first processor of each system.
+pub struct SystemDiff {
+    /// Leaves only present in the other system.
+    pub added_leaves: Vec<RawCPUIDResponse>,
+
+    /// Leaves only present in this system.
+    pub removed_leaves: Vec<RawCPUIDResponse>,
+
+    /// Leaves present in both systems with different output registers, as
+    /// pairs of this system's leaf and the other system's leaf.
+    pub changed_leaves: Vec<(RawCPUIDResponse, RawCPUIDResponse)>,
+
+    /// Features only detected in the other system.
+    pub added_features: Vec<Feature>,
+
+    /// Features only detected in this system.
+    pub removed_features: Vec<Feature>,
+
+    /// Caches and TLBs only described in the other system.
+    pub added_caches: Vec<CacheDescription>,
+
+    /// Caches and TLBs only described in this system.
+    pub removed_caches: Vec<CacheDescription>,
+}
+
+impl SystemDiff {
+    /// Returns `true` if no differences were found.
+    pub fn is_empty(&self) -> bool {
+        self.added_leaves.is_empty()
+            && self.removed_leaves.is_empty()
+            && self.changed_leaves.is_empty()
+            && self.added_features.is_empty()
+            && self.removed_features.is_empty()
+            && self.added_caches.is_empty()
+            && self.removed_caches.is_empty()
+    }
+}
+
+impl fmt::Display for SystemDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "No differences\n");
+        }
+        if !self.added_leaves.is_empty()
+            || !self.removed_leaves.is_empty()
+            || !self.changed_leaves.is_empty()
+        {
+            write!(f, "Leaves:\n")?;
+            for leaf in self.removed_leaves.iter() {
+                write!(f, "- {}\n", leaf)?;
+            }
+            for (before, after) in self.changed_leaves.iter() {
+                write!(f, "- {}\n+ {}\n", before, after)?;
+            }
+            for leaf in self.added_leaves.iter() {
+                write!(f, "+ {}\n", leaf)?;
+            }
+        }
+        if !self.added_features.is_empty() || !self.removed_features.is_empty() {
+            write!(f, "Features:\n")?;
+            for feature in self.removed_features.iter() {
+                write!(f, "- {}\n", fmt_feature(feature))?;
+            }
+            for feature in self.added_features.iter() {
+                write!(f, "+ {}\n", fmt_feature(feature))?;
+            }
+        }
+        if !self.added_caches.is_empty() || !self.removed_caches.is_empty() {
+            write!(f, "Caches:\n")?;
+            for cache in self.removed_caches.iter() {
+                write!(f, "{}", indent(&format!("{}\n", cache), "- "))?;
+            }
+            for cache in self.added_caches.iter() {
+                write!(f, "{}", indent(&format!("{}\n", cache), "+ "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn fmt_feature(feature: &Feature) -> String {
+    format!(
+        "Leaf {:08x}:{:02x}, register {}, bit {}: {}",
+        feature.leaf.eax, feature.leaf.ecx, feature.register, feature.bit, feature
+    )
+}
+
+fn same_feature(a: &Feature, b: &Feature) -> bool {
+    a.leaf == b.leaf && a.register == b.register && a.bit == b.bit
+}
+
+/// Returns the entries of `items` that have no counterpart in `others`. Each
+/// entry in `others` only matches once, so repeated entries are accounted for.
+fn unmatched<T: Clone, F: Fn(&T, &T) -> bool>(items: &[T], others: &[T], eq: F) -> Vec<T> {
+    let mut used: Vec<bool> = vec![false; others.len()];
+    let mut output: Vec<T> = vec![];
+    for item in items.iter() {
+        match others
+            .iter()
+            .enumerate()
+            .position(|(index, other)| !used[index] && eq(item, other))
+        {
+            Some(index) => used[index] = true,
+            None => output.push(item.clone()),
+        }
+    }
+    output
+}
+
+pub(crate) fn diff_systems(before: &System, after: &System) -> SystemDiff {
+    let mut diff = SystemDiff::default();
+
+    let leaves = |system: &System| -> Vec<RawCPUIDResponse> {
+        match system.cpus.first() {
+            Some(cpu) => cpu.iter_leaves().cloned().collect(),
+            None => vec![],
+        }
+    };
+    let before_leaves = leaves(before);
+    let after_leaves = leaves(after);
+    for leaf in before_leaves.iter() {
+        match after_leaves.iter().find(|other| other.input == leaf.input) {
+            Some(other) => {
+                if other.output != leaf.output {
+                    diff.changed_leaves.push((leaf.clone(), other.clone()));
+                }
+            }
+            None => diff.removed_leaves.push(leaf.clone()),
+        }
+    }
+    for leaf in after_leaves.iter() {
+        if !before_leaves.iter().any(|other| other.input == leaf.input) {
+            diff.added_leaves.push(leaf.clone());
+        }
+    }
+
+    diff.removed_features = unmatched(&before.features.0, &after.features.0, same_feature);
+    diff.added_features = unmatched(&after.features.0, &before.features.0, same_feature);
+
+    diff.removed_caches = unmatched(&before.caches.0, &after.caches.0, |a, b| a == b);
+    diff.added_caches = unmatched(&after.caches.0, &before.caches.0, |a, b| a == b);
+
+    diff
+}
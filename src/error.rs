@@ -0,0 +1,83 @@
+use core::fmt;
+
+use crate::cpuid::{RegisterName, Signature};
+use crate::internal::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Errors returned by this crate.
+pub enum CpuidError {
+    /// A register name other than `eax`, `ebx`, `ecx` or `edx` was used to
+    /// read register contents.
+    InvalidRegister(RegisterName),
+
+    /// A bit range was out of bounds or reversed. Bit ranges must satisfy
+    /// `lo <= hi <= 31`.
+    InvalidBitRange {
+        /// Lowest bit of the requested range.
+        lo: u8,
+
+        /// Highest bit of the requested range.
+        hi: u8,
+    },
+
+    /// A CPU selection could not be parsed. Contains the offending part of the
+    /// selection.
+    InvalidCpuSelection(String),
+
+    /// A CPU selection named a CPU that does not exist.
+    CpuOutOfRange {
+        /// The CPU index which does not exist.
+        index: u32,
+
+        /// Lowest valid CPU index.
+        first: u32,
+
+        /// Highest valid CPU index.
+        last: u32,
+    },
+
+    /// A system was built from an empty list of processors.
+    NoProcessors,
+
+    /// A processor's signature differs from that of the first processor. All
+    /// processors of a system are decoded as if they were identical, so
+    /// mixing signatures is not supported.
+    MismatchedSignature {
+        /// Index of the processor whose signature differs.
+        index: u32,
+
+        /// Signature of the first processor.
+        expected: Signature,
+
+        /// Signature of the offending processor.
+        found: Signature,
+    },
+}
+
+impl fmt::Display for CpuidError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CpuidError::InvalidRegister(name) => write!(f, "invalid register {:?}", name),
+            CpuidError::InvalidBitRange { lo, hi } => write!(f, "invalid bit range {}..={}", lo, hi),
+            CpuidError::InvalidCpuSelection(part) => write!(f, "invalid CPU selection {:?}", part),
+            CpuidError::CpuOutOfRange { index, first, last } => write!(
+                f,
+                "CPU {} does not exist (valid range: {} to {})",
+                index, first, last
+            ),
+            CpuidError::NoProcessors => write!(f, "no processors"),
+            CpuidError::MismatchedSignature {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "CPU {} has signature {}, but the first CPU has {}",
+                index, found, expected
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CpuidError {}
@@ -1,9 +1,137 @@
+use core::fmt;
 use log::*;
-use std::fmt;
-use textwrap::indent;
 
-use crate::cpuid::{LeafID, Processor, RegisterName, VendorMask};
-use crate::internal::feature_flags::{FeatureLeaf, FeatureSpec, FEATURE_LEAVES};
+use crate::cpuid::{LeafID, LeafStatus, Processor, RegisterName, VendorMask};
+use crate::internal::feature_flags::{
+    AVX512_SUBSETS, FEATURE_LEAVES, RUSTC_TARGET_FEATURES, SIMD_LEVELS, SIMD_VECTOR_WIDTHS,
+};
+use crate::internal::prelude::*;
+use crate::internal::util::indent;
+
+pub use crate::internal::feature_flags::{FeatureLeaf, FeatureSpec};
+
+/// Declares a [FeatureLeaf](feature/struct.FeatureLeaf.html) for
+/// [describe_features_with](feature/fn.describe_features_with.html) in the
+/// same terse form as the built-in feature tables. Each bit is given as
+/// `(bit, vendor_mask, category, shortname, name)`, where `category` names a
+/// [FeatureCategory](feature/enum.FeatureCategory.html) variant. The register
+/// names a [RegisterName](cpuid/enum.RegisterName.html) variant. All values
+/// must be constants, so the table can be stored in a `static`.
+///
+/// ```
+/// use cpuid::cpuid::{System, VendorMask};
+/// use cpuid::feature::FeatureLeaf;
+/// use cpuid::feature_leaf;
+///
+/// static EXTRA_FEATURES: [FeatureLeaf; 1] = [feature_leaf! {
+///     leaf: 0x0000_0001, 0;
+///     vendor_mask: VendorMask::INTEL;
+///     register: EBX;
+///     bits: [
+///         (4, VendorMask::INTEL, Misc, "CUSTOM", "Custom feature bit"),
+///     ]
+/// }];
+///
+/// let system = System::from_local().with_decoded();
+/// for feature in system.features_with(&EXTRA_FEATURES).iter() {
+///     println!("{}", feature);
+/// }
+/// ```
+#[macro_export]
+macro_rules! feature_leaf {
+    (
+        leaf: $eax:expr, $ecx:expr;
+        vendor_mask: $vendor_mask:expr;
+        register: $register:ident;
+        bits: [$(($bit:expr, $bit_vendor_mask:expr, $category:ident, $shortname:expr, $name:expr)),* $(,)?]
+    ) => {
+        $crate::feature::FeatureLeaf {
+            leaf: $crate::cpuid::LeafID { eax: $eax, ecx: $ecx },
+            vendor_mask: $vendor_mask,
+            register: $crate::cpuid::RegisterName::$register,
+            bits: &[$($crate::feature::FeatureSpec {
+                bit: $bit,
+                vendor_mask: $bit_vendor_mask,
+                category: $crate::feature::FeatureCategory::$category,
+                shortname: $shortname,
+                name: $name,
+            }),*],
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Broad category of a CPU feature, used to filter long feature lists.
+pub enum FeatureCategory {
+    /// Vector and floating-point instruction set extensions.
+    Simd,
+    /// Cryptographic instructions and random number generators.
+    Crypto,
+    /// Protection mechanisms and speculative execution mitigations.
+    Security,
+    /// Hardware virtualization and paravirtualization.
+    Virtualization,
+    /// Power, thermal and frequency management.
+    Power,
+    /// Paging, caching and memory ordering.
+    Memory,
+    /// Debugging, tracing, profiling and error reporting.
+    Debug,
+    /// Anything that does not fit another category.
+    Misc,
+}
+
+/// Names accepted and produced for each [FeatureCategory](enum.FeatureCategory.html).
+static CATEGORY_NAMES: [(FeatureCategory, &str); 8] = [
+    (FeatureCategory::Simd, "SIMD"),
+    (FeatureCategory::Crypto, "Crypto"),
+    (FeatureCategory::Security, "Security"),
+    (FeatureCategory::Virtualization, "Virtualization"),
+    (FeatureCategory::Power, "Power"),
+    (FeatureCategory::Memory, "Memory"),
+    (FeatureCategory::Debug, "Debug"),
+    (FeatureCategory::Misc, "Misc"),
+];
+
+impl fmt::Display for FeatureCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = CATEGORY_NAMES
+            .iter()
+            .find(|(category, _)| category == self)
+            .map_or("", |(_, name)| name);
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Error returned when parsing a [FeatureCategory](enum.FeatureCategory.html)
+/// from a string fails.
+pub struct ParseFeatureCategoryError {
+    /// The category name which was not recognized.
+    pub name: String,
+}
+
+impl fmt::Display for ParseFeatureCategoryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown feature category {:?}", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFeatureCategoryError {}
+
+impl core::str::FromStr for FeatureCategory {
+    type Err = ParseFeatureCategoryError;
+
+    /// Parses a category name, ignoring case.
+    fn from_str(s: &str) -> Result<FeatureCategory, ParseFeatureCategoryError> {
+        let name = s.trim();
+        match CATEGORY_NAMES.iter().find(|(_, other)| other.eq_ignore_ascii_case(name)) {
+            Some((category, _)) => Ok(*category),
+            None => Err(ParseFeatureCategoryError { name: name.to_string() }),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 /// Describes a discovered CPU feature.
@@ -20,6 +148,9 @@ pub struct Feature {
     /// Mask of valid vendors this feature can be detected in.
     pub vendor_mask: VendorMask,
 
+    /// Broad category of the feature.
+    pub category: FeatureCategory,
+
     /// Short name of the feature. May be blank if the feature doesn't have/need
     /// a shorter name or initialism.
     pub shortname: &'static str,
@@ -35,6 +166,7 @@ impl Feature {
             register: leaf.register,
             bit: bit,
             vendor_mask: spec.vendor_mask,
+            category: spec.category,
             shortname: spec.shortname,
             name: spec.name,
         }
@@ -55,6 +187,26 @@ impl fmt::Display for Feature {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+/// Flat description of a feature for rendering as a table or CSV row, see
+/// [FeatureVec::rows](struct.FeatureVec.html#method.rows).
+pub struct FeatureRow {
+    /// Longer, more descriptive name of the feature.
+    pub name: &'static str,
+
+    /// Short name of the feature. May be blank.
+    pub shortname: &'static str,
+
+    /// Leaf and subleaf the feature was discovered in.
+    pub leaf: LeafID,
+
+    /// Register the feature was discovered in.
+    pub register: RegisterName,
+
+    /// Bit index of the feature within the register.
+    pub bit: u8,
+}
+
 #[derive(Debug)]
 /// Vector of [Feature](struct.Feature.html) objects.
 pub struct FeatureVec(pub Vec<Feature>);
@@ -63,6 +215,118 @@ impl FeatureVec {
     pub fn new() -> FeatureVec {
         FeatureVec(vec![])
     }
+
+    /// Returns an iterator over the contained [Features](struct.Feature.html).
+    pub fn iter(&self) -> core::slice::Iter<'_, Feature> {
+        self.0.iter()
+    }
+
+    /// Returns the number of contained [Features](struct.Feature.html).
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no [Features](struct.Feature.html).
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the contained features belonging to `category`.
+    pub fn by_category(&self, category: FeatureCategory) -> FeatureVec {
+        FeatureVec(self.iter().filter(|feature| feature.category == category).cloned().collect())
+    }
+
+    /// Returns one [FeatureRow](struct.FeatureRow.html) per contained
+    /// feature, in the same order.
+    pub fn rows(&self) -> Vec<FeatureRow> {
+        self.iter()
+            .map(|feature| FeatureRow {
+                name: feature.name,
+                shortname: feature.shortname,
+                leaf: feature.leaf.clone(),
+                register: feature.register,
+                bit: feature.bit,
+            })
+            .collect()
+    }
+
+    /// Returns the contained features that have a stable rustc
+    /// `target_feature` equivalent, formatted for `-C target-feature`, e.g.
+    /// `"+sse4.2,+avx2,+fma"`.
+    pub fn rustc_target_features(&self) -> String {
+        RUSTC_TARGET_FEATURES
+            .iter()
+            .filter(|(eax, ecx, register, bit, _)| self.contains_bit(*eax, *ecx, *register, *bit))
+            .map(|(.., name)| format!("+{}", name))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    /// Returns the names of the contained AVX-512 extensions, without the
+    /// `AVX512` prefix, e.g. `["F", "DQ", "CD", "BW", "VL"]`.
+    pub fn avx512_subsets(&self) -> Vec<&'static str> {
+        AVX512_SUBSETS
+            .iter()
+            .filter(|(eax, ecx, register, bit, _)| self.contains_bit(*eax, *ecx, *register, *bit))
+            .map(|(.., name)| *name)
+            .collect()
+    }
+
+    /// Returns the width in bits of the widest SIMD vectors supported by the
+    /// contained features: 512 for AVX-512, 256 for AVX, 128 for SSE, 64 for
+    /// MMX, or 0 if none of these are present. Note that this only reflects
+    /// CPUID, not whether the operating system has enabled the register state.
+    pub fn max_vector_bits(&self) -> u16 {
+        SIMD_VECTOR_WIDTHS
+            .iter()
+            .find(|(eax, ecx, register, bit, _)| self.contains_bit(*eax, *ecx, *register, *bit))
+            .map_or(0, |(.., width)| *width)
+    }
+
+    /// Returns the name of the newest SIMD instruction set level among the
+    /// contained features, one of `"AVX-512"`, `"AVX2"`, `"AVX"`, `"SSE4.2"` or
+    /// `"SSE2"`, or `None` if none of these are present.
+    pub fn simd_level(&self) -> Option<&'static str> {
+        SIMD_LEVELS
+            .iter()
+            .find(|(eax, ecx, register, bit, _)| self.contains_bit(*eax, *ecx, *register, *bit))
+            .map(|(.., name)| *name)
+    }
+
+    fn contains_bit(&self, eax: u32, ecx: u32, register: RegisterName, bit: u8) -> bool {
+        self.iter().any(|feature| {
+            feature.leaf.eax == eax
+                && feature.leaf.ecx == ecx
+                && feature.register == register
+                && feature.bit == bit
+        })
+    }
+}
+
+impl core::ops::Index<usize> for FeatureVec {
+    type Output = Feature;
+
+    fn index(&self, index: usize) -> &Feature {
+        &self.0[index]
+    }
+}
+
+impl IntoIterator for FeatureVec {
+    type Item = Feature;
+    type IntoIter = alloc::vec::IntoIter<Feature>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a FeatureVec {
+    type Item = &'a Feature;
+    type IntoIter = core::slice::Iter<'a, Feature>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
 }
 
 fn leaf_name(leaf: &LeafID, register: RegisterName) -> &'static str {
@@ -71,6 +335,7 @@ fn leaf_name(leaf: &LeafID, register: RegisterName) -> &'static str {
         0x0000_0006 => "Thermal and Power Management",
         0x0000_0007 => "Structured Extended Feature Identifiers",
         0x0000_0014 => "Intel Processor Trace Enumeration",
+        0x4000_0001 => "KVM Paravirtualization",
         0x8000_0007 => match register {
             RegisterName::EBX => "RAS Capabilities",
             RegisterName::EDX => "Advanced Power Management Information",
@@ -80,6 +345,7 @@ fn leaf_name(leaf: &LeafID, register: RegisterName) -> &'static str {
         0x8000_000A => "SVM Feature Identifiers",
         0x8000_001A => "Performance Optimization Identifiers",
         0x8000_001B => "Instruction Based Sampling Identifiers",
+        0x8000_001F => "Encrypted Memory Capabilities",
         0xC000_0001 => "Centaur Feature Identifiers",
         _ => "",
     }
@@ -104,7 +370,7 @@ impl fmt::Display for FeatureVec {
                 }
                 write!(
                     f,
-                    "  Leaf {:08x}:{:02x}{}, register {:?}\n",
+                    "  Leaf {:08x}:{:02x}{}, register {}\n",
                     v.leaf.eax, v.leaf.ecx, name, v.register
                 )?;
                 lastleaf = v.leaf.clone();
@@ -117,53 +383,198 @@ impl fmt::Display for FeatureVec {
     }
 }
 
+/// Bits of leaf `0x8000_0001` EDX which mirror leaf `0x0000_0001` EDX.
+const AMD_DUPLICATE_EDX_BITS: u32 = 0x0183_ffff;
+
 pub(crate) fn describe_features(cpu: &Processor, vendor_mask: VendorMask) -> FeatureVec {
+    describe_features_with(cpu, vendor_mask, &[])
+}
+
+/// Decodes the features of `cpu`, along with the bits that are set in the
+/// decoded registers but not described by any applicable feature table.
+pub(crate) fn describe_features_and_unknown(
+    cpu: &Processor,
+    vendor_mask: VendorMask,
+) -> (FeatureVec, Vec<(LeafID, RegisterName, u8)>) {
+    let mut unknown: Vec<(LeafID, RegisterName, u8)> = vec![];
+    let features = describe_features_impl(cpu, vendor_mask, &[], &mut unknown);
+    (features, unknown)
+}
+
+/// Decodes the features of `cpu` like the built-in feature decoding, but also
+/// decodes the feature bits described in `extra`. Entries in `extra` for a
+/// leaf and register that the built-in table already covers are merged with
+/// it, and bits already reported by the built-in table are not repeated.
+pub fn describe_features_with(cpu: &Processor, vendor_mask: VendorMask, extra: &[FeatureLeaf]) -> FeatureVec {
+    describe_features_impl(cpu, vendor_mask, extra, &mut vec![])
+}
+
+fn describe_features_impl(
+    cpu: &Processor,
+    vendor_mask: VendorMask,
+    extra: &[FeatureLeaf],
+    unknown: &mut Vec<(LeafID, RegisterName, u8)>,
+) -> FeatureVec {
     let mut output: FeatureVec = FeatureVec::new();
+    for feature_leaf in FEATURE_LEAVES.iter() {
+        let mut tables: Vec<&FeatureLeaf> = vec![feature_leaf];
+        tables.extend(extra.iter().filter(|other| same_register(feature_leaf, other)));
+        describe_feature_tables(cpu, vendor_mask, &tables, &mut output, unknown);
+    }
+    for (index, feature_leaf) in extra.iter().enumerate() {
+        // Skip anything already merged above, or merged into an earlier entry.
+        if FEATURE_LEAVES.iter().any(|other| same_register(feature_leaf, other))
+            || extra[..index].iter().any(|other| same_register(feature_leaf, other))
+        {
+            continue;
+        }
+        let tables: Vec<&FeatureLeaf> = extra[index..]
+            .iter()
+            .filter(|other| same_register(feature_leaf, other))
+            .collect();
+        describe_feature_tables(cpu, vendor_mask, &tables, &mut output, unknown);
+    }
+    // Keep the output stable regardless of how the tables are ordered.
+    output.0.sort_by_key(|feature| {
+        (
+            feature.leaf.eax,
+            feature.leaf.ecx,
+            feature.register as u8,
+            feature.bit,
+        )
+    });
+    unknown.sort_by_key(|(leaf, register, bit)| (leaf.eax, leaf.ecx, *register as u8, *bit));
+    output
+}
+
+/// Lists every known feature applicable to `vendor_mask`, paired with whether
+/// it is present on `cpu`. Features in leaves that `cpu` doesn't advertise are
+/// listed as absent, but features in advertised leaves which weren't collected
+/// are left out, since nothing is known about them. The present features match
+/// [describe_features](fn.describe_features.html).
+pub(crate) fn describe_all_features(cpu: &Processor, vendor_mask: VendorMask) -> Vec<(Feature, bool)> {
+    let mut output: Vec<(Feature, bool)> = vec![];
     for feature_leaf in FEATURE_LEAVES.iter() {
         if !vendor_mask.intersects(feature_leaf.vendor_mask) {
             continue;
         }
-        if let Some(raw) = cpu.get_subleaf(feature_leaf.leaf.eax, feature_leaf.leaf.ecx) {
-            debug!(
-                "Leaf {:08x}:{:02x}:{:?} beginning decode",
-                feature_leaf.leaf.eax, feature_leaf.leaf.ecx, feature_leaf.register
-            );
-            let mut register: u32 = raw.output.register(feature_leaf.register);
-            if feature_leaf.leaf.eax == 0x8000_0001 && feature_leaf.register == RegisterName::EDX {
-                // These are features covered in leaf 0x0000_0001, and we don't
-                // want to repeat them here.
-                register &= !0x0183ffff;
+        let leaf = &feature_leaf.leaf;
+        if cpu.leaf_status(leaf.eax, leaf.ecx) == LeafStatus::NotCollected {
+            continue;
+        }
+        for bit in 0..32 {
+            let feature_spec = match select_feature_spec(feature_leaf, bit, vendor_mask) {
+                Some(feature_spec) => feature_spec,
+                None => continue,
+            };
+            if leaf.eax == 0x8000_0001
+                && feature_leaf.register == RegisterName::EDX
+                && AMD_DUPLICATE_EDX_BITS & (1 << bit) != 0
+            {
+                continue;
             }
-            for feature_spec in feature_leaf.bits.iter() {
-                let bit = feature_spec.bit;
-                if vendor_mask.intersects(feature_spec.vendor_mask) {
-                    let mask = 1 << bit;
-                    if (register & mask) != 0 {
-                        // Mark that we've seen and accounted for this feature
-                        // bit. We can report on unaccounted for bits afterward
-                        // (in debug)
-                        register &= !mask;
-                        let feature = Feature::from_detection(feature_leaf, feature_spec, bit as u8);
-                        debug!(
-                            "Leaf {:08x}:{:02x}:{:?} bit {} detected {}",
-                            feature_leaf.leaf.eax, feature_leaf.leaf.ecx, feature_leaf.register, bit, feature
-                        );
-                        output.0.push(feature);
-                    }
+            let present = cpu.has_feature_bit(leaf.eax, leaf.ecx, feature_leaf.register, bit);
+            output.push((Feature::from_detection(feature_leaf, feature_spec, bit), present));
+        }
+    }
+    output.sort_by_key(|(feature, _)| {
+        (
+            feature.leaf.eax,
+            feature.leaf.ecx,
+            feature.register as u8,
+            feature.bit,
+        )
+    });
+    output
+}
+
+fn same_register(a: &FeatureLeaf, b: &FeatureLeaf) -> bool {
+    a.leaf == b.leaf && a.register == b.register
+}
+
+/// Picks the spec in `feature_leaf` describing `bit` for `vendor_mask`. When
+/// several specs for the bit apply, the one with the narrowest vendor mask
+/// wins, so that e.g. an AMD-specific name overrides a generic one.
+fn select_feature_spec(
+    feature_leaf: &FeatureLeaf,
+    bit: u8,
+    vendor_mask: VendorMask,
+) -> Option<&'static FeatureSpec> {
+    feature_leaf
+        .bits
+        .iter()
+        .filter(|spec| spec.bit == bit && vendor_mask.intersects(spec.vendor_mask))
+        .min_by_key(|spec| spec.vendor_mask.bits().count_ones())
+}
+
+fn describe_feature_tables(
+    cpu: &Processor,
+    vendor_mask: VendorMask,
+    tables: &[&FeatureLeaf],
+    output: &mut FeatureVec,
+    unknown: &mut Vec<(LeafID, RegisterName, u8)>,
+) {
+    let leaf = &tables[0].leaf;
+    let register_name = tables[0].register;
+    if let Some(raw) = cpu.get_subleaf(leaf.eax, leaf.ecx) {
+        debug!(
+            "Leaf {:08x}:{:02x}:{:?} beginning decode",
+            leaf.eax, leaf.ecx, register_name
+        );
+        let mut register: u32 = match raw.output.register(register_name) {
+            Ok(register) => register,
+            Err(e) => {
+                debug!("Leaf {:08x}:{:02x} skipped: {}", leaf.eax, leaf.ecx, e);
+                return;
+            }
+        };
+        if leaf.eax == 0x8000_0001 && register_name == RegisterName::EDX {
+            // These are features covered in leaf 0x0000_0001, and we don't
+            // want to repeat them here.
+            register &= !AMD_DUPLICATE_EDX_BITS;
+        }
+        let mut applicable = false;
+        for feature_leaf in tables.iter() {
+            if !vendor_mask.intersects(feature_leaf.vendor_mask) {
+                continue;
+            }
+            applicable = true;
+            for bit in 0..32 {
+                let mask = 1 << bit;
+                if (register & mask) == 0 {
+                    continue;
+                }
+                if let Some(feature_spec) = select_feature_spec(feature_leaf, bit, vendor_mask) {
+                    // Mark that we've seen and accounted for this feature
+                    // bit. We can report on unaccounted for bits afterward.
+                    register &= !mask;
+                    let feature = Feature::from_detection(feature_leaf, feature_spec, bit);
+                    debug!(
+                        "Leaf {:08x}:{:02x}:{:?} bit {} detected {}",
+                        leaf.eax, leaf.ecx, register_name, bit, feature
+                    );
+                    output.0.push(feature);
                 }
             }
-            if register != 0 {
-                for bit in 0..31 {
-                    let mask = 1 << bit;
-                    if (register & mask) != 0 {
-                        debug!(
-                            "Leaf {:08x}:{:02x}:{:?} unaccounted for bit {}",
-                            feature_leaf.leaf.eax, feature_leaf.leaf.ecx, feature_leaf.register, bit
-                        );
-                    }
+        }
+        // Registers with no table for this vendor aren't decoded at all, so
+        // their bits aren't reported as unknown either.
+        if applicable && register != 0 {
+            for bit in 0..32 {
+                let mask = 1 << bit;
+                if (register & mask) != 0 {
+                    debug!(
+                        "Leaf {:08x}:{:02x}:{:?} unaccounted for bit {}",
+                        leaf.eax, leaf.ecx, register_name, bit
+                    );
+                    unknown.push((leaf.clone(), register_name, bit as u8));
                 }
             }
         }
+    } else if cpu.leaf_status(leaf.eax, leaf.ecx) == LeafStatus::NotCollected {
+        debug!(
+            "Leaf {:08x}:{:02x} is advertised but wasn't collected, its features are unknown",
+            leaf.eax, leaf.ecx
+        );
     }
-    output
 }
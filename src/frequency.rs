@@ -0,0 +1,114 @@
+#![allow(dead_code)]
+
+use core::fmt;
+use log::*;
+
+use crate::cpuid::Processor;
+use crate::internal::prelude::*;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes the processor frequencies reported by leaf `0x0000_0016`. A value
+/// of zero means the frequency was not reported.
+pub struct FrequencyInfo {
+    /// Processor base frequency, in MHz.
+    pub base_mhz: u16,
+
+    /// Maximum processor frequency, in MHz.
+    pub max_mhz: u16,
+
+    /// Bus (reference) frequency, in MHz.
+    pub bus_mhz: u16,
+}
+
+fn mhz_str(mhz: u16) -> String {
+    match mhz {
+        0 => "unknown".to_string(),
+        _ => format!("{} MHz", mhz),
+    }
+}
+
+impl fmt::Display for FrequencyInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Base {}, Max {}, Bus {}",
+            mhz_str(self.base_mhz),
+            mhz_str(self.max_mhz),
+            mhz_str(self.bus_mhz)
+        )
+    }
+}
+
+pub(crate) fn describe_frequency(cpu: &Processor) -> Option<FrequencyInfo> {
+    if let Some(raw) = cpu.get_subleaf(0x0000_0016, 0) {
+        let info = FrequencyInfo {
+            base_mhz: (raw.output.eax & 0xFFFF) as u16,
+            max_mhz: (raw.output.ebx & 0xFFFF) as u16,
+            bus_mhz: (raw.output.ecx & 0xFFFF) as u16,
+        };
+        if info.base_mhz == 0 && info.max_mhz == 0 && info.bus_mhz == 0 {
+            return None;
+        }
+        debug!("describe_frequency() found {:?}", info);
+        Some(info)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes the relationship between the TSC and the core crystal clock,
+/// from leaf `0x0000_0015`.
+pub struct TscInfo {
+    /// Denominator of the TSC / core crystal clock ratio.
+    pub denominator: u32,
+
+    /// Numerator of the TSC / core crystal clock ratio.
+    pub numerator: u32,
+
+    /// Nominal frequency of the core crystal clock, in Hz. Zero means the
+    /// crystal frequency was not reported.
+    pub crystal_hz: u32,
+}
+
+impl TscInfo {
+    /// Computes the nominal TSC frequency in Hz, if the crystal frequency was
+    /// reported.
+    pub fn tsc_hz(&self) -> Option<u64> {
+        if self.denominator == 0 || self.numerator == 0 || self.crystal_hz == 0 {
+            return None;
+        }
+        Some(self.crystal_hz as u64 * self.numerator as u64 / self.denominator as u64)
+    }
+}
+
+impl fmt::Display for TscInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(tsc_hz) = self.tsc_hz() {
+            write!(f, "{:.2} MHz, ", tsc_hz as f64 / 1_000_000.0)?;
+        }
+        write!(f, "ratio {}/{}, crystal ", self.numerator, self.denominator)?;
+        match self.crystal_hz {
+            0 => write!(f, "unknown"),
+            _ => write!(f, "{:.2} MHz", self.crystal_hz as f64 / 1_000_000.0),
+        }
+    }
+}
+
+pub(crate) fn describe_tsc(cpu: &Processor) -> Option<TscInfo> {
+    if let Some(raw) = cpu.get_subleaf(0x0000_0015, 0) {
+        // Both parts of the ratio must be present for this leaf to be valid.
+        if raw.output.eax == 0 || raw.output.ebx == 0 {
+            return None;
+        }
+        let info = TscInfo {
+            denominator: raw.output.eax,
+            numerator: raw.output.ebx,
+            crystal_hz: raw.output.ecx,
+        };
+        debug!("describe_tsc() found {:?}", info);
+        Some(info)
+    } else {
+        None
+    }
+}
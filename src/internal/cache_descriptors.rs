@@ -1,745 +1,778 @@
 use log::*;
 
-use crate::cache::{CacheAssociativity, CacheDescription, CacheFlags, CacheLevel, CacheType, CacheVec};
+use crate::cache::{
+    CacheAssociativity, CacheDescription, CacheDescriptorTable, CacheFlags, CacheLevel, CacheType, CacheVec,
+};
+use crate::internal::prelude::*;
 
 #[cfg(feature = "legacy-cache-descriptors")]
 fn lookup_cache_internal(descriptor: u8) -> Option<CacheDescription> {
     match descriptor {
-        0x06 => Some(CacheDescription {
-            cachetype: CacheType::Code,
-            level: CacheLevel::L1,
-            size: 8,
-            linesize: 32,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x08 => Some(CacheDescription {
-            cachetype: CacheType::Code,
-            level: CacheLevel::L1,
-            size: 16,
-            linesize: 32,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x09 => Some(CacheDescription {
-            cachetype: CacheType::Code,
-            level: CacheLevel::L1,
-            size: 32,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x0A => Some(CacheDescription {
-            cachetype: CacheType::Data,
-            level: CacheLevel::L1,
-            size: 8,
-            linesize: 32,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x02),
-            ..Default::default()
-        }),
-        0x0C => Some(CacheDescription {
-            cachetype: CacheType::Data,
-            level: CacheLevel::L1,
-            size: 16,
-            linesize: 32,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x0D => Some(CacheDescription {
-            cachetype: CacheType::Data,
-            level: CacheLevel::L1,
-            size: 16,
-            linesize: 64,
-            flags: CacheFlags::new().with_ecc(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x0E => Some(CacheDescription {
-            cachetype: CacheType::Data,
-            level: CacheLevel::L1,
-            size: 24,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x06),
-            ..Default::default()
-        }),
-        0x10 => Some(CacheDescription {
-            cachetype: CacheType::Data,
-            level: CacheLevel::L1,
-            size: 16,
-            linesize: 32,
-            flags: CacheFlags::new().with_ia64(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x15 => Some(CacheDescription {
-            cachetype: CacheType::Code,
-            level: CacheLevel::L1,
-            size: 16,
-            linesize: 32,
-            flags: CacheFlags::new().with_ia64(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x1A => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 96,
-            linesize: 64,
-            flags: CacheFlags::new().with_ia64(true),
-            associativity: CacheAssociativity::from_identifier(0x06),
-            ..Default::default()
-        }),
-        0x1D => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 128,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x02),
-            ..Default::default()
-        }),
-        0x21 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 256,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x22 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 512,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x23 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 1024,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x24 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 1024,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x10),
-            ..Default::default()
-        }),
-        0x25 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 2048,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x29 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 4096,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x2C => Some(CacheDescription {
-            cachetype: CacheType::Data,
-            level: CacheLevel::L1,
-            size: 32,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x30 => Some(CacheDescription {
-            cachetype: CacheType::Code,
-            level: CacheLevel::L1,
-            size: 32,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x39 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 128,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x3A => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 192,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x06),
-            ..Default::default()
-        }),
-        0x3B => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 128,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x02),
-            ..Default::default()
-        }),
-        0x3C => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 256,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x3D => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 384,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x06),
-            ..Default::default()
-        }),
-        0x3E => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 512,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
+        0x06 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Code)
+                .level(CacheLevel::L1)
+                .size(8)
+                .linesize(32)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x08 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Code)
+                .level(CacheLevel::L1)
+                .size(16)
+                .linesize(32)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x09 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Code)
+                .level(CacheLevel::L1)
+                .size(32)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x0A => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Data)
+                .level(CacheLevel::L1)
+                .size(8)
+                .linesize(32)
+                .associativity(CacheAssociativity::from_identifier(0x02))
+                .build(),
+        ),
+        0x0C => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Data)
+                .level(CacheLevel::L1)
+                .size(16)
+                .linesize(32)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x0D => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Data)
+                .level(CacheLevel::L1)
+                .size(16)
+                .linesize(64)
+                .flags(CacheFlags::new().with_ecc(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x0E => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Data)
+                .level(CacheLevel::L1)
+                .size(24)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x06))
+                .build(),
+        ),
+        0x10 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Data)
+                .level(CacheLevel::L1)
+                .size(16)
+                .linesize(32)
+                .flags(CacheFlags::new().with_ia64(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x15 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Code)
+                .level(CacheLevel::L1)
+                .size(16)
+                .linesize(32)
+                .flags(CacheFlags::new().with_ia64(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x1A => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(96)
+                .linesize(64)
+                .flags(CacheFlags::new().with_ia64(true))
+                .associativity(CacheAssociativity::from_identifier(0x06))
+                .build(),
+        ),
+        0x1D => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(128)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x02))
+                .build(),
+        ),
+        0x21 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(256)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x22 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(512)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x23 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(1024)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x24 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(1024)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x10))
+                .build(),
+        ),
+        0x25 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(2048)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x29 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(4096)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x2C => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Data)
+                .level(CacheLevel::L1)
+                .size(32)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x30 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Code)
+                .level(CacheLevel::L1)
+                .size(32)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x39 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(128)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x3A => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(192)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x06))
+                .build(),
+        ),
+        0x3B => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(128)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x02))
+                .build(),
+        ),
+        0x3C => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(256)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x3D => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(384)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x06))
+                .build(),
+        ),
+        0x3E => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(512)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
         // 0x40 -> handled in parent function, special case
-        0x41 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 128,
-            linesize: 32,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x42 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 256,
-            linesize: 32,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x43 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 512,
-            linesize: 32,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x44 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 1024,
-            linesize: 32,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x45 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 2048,
-            linesize: 32,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x46 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 4096,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x47 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 8192,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x48 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 3072,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x0C),
-            ..Default::default()
-        }),
-        0x4A => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 6144,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x0C),
-            ..Default::default()
-        }),
-        0x4B => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 8192,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x10),
-            ..Default::default()
-        }),
-        0x4C => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 12288,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x0C),
-            ..Default::default()
-        }),
-        0x4D => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 16384,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x10),
-            ..Default::default()
-        }),
-        0x4E => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 6144,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x18),
-            ..Default::default()
-        }),
-        0x60 => Some(CacheDescription {
-            cachetype: CacheType::Data,
-            level: CacheLevel::L1,
-            size: 16,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x66 => Some(CacheDescription {
-            cachetype: CacheType::Data,
-            level: CacheLevel::L1,
-            size: 8,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x67 => Some(CacheDescription {
-            cachetype: CacheType::Data,
-            level: CacheLevel::L1,
-            size: 16,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x68 => Some(CacheDescription {
-            cachetype: CacheType::Data,
-            level: CacheLevel::L1,
-            size: 32,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x70 => Some(CacheDescription {
-            cachetype: CacheType::Trace,
-            level: CacheLevel::L1,
-            size: 12,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x71 => Some(CacheDescription {
-            cachetype: CacheType::Trace,
-            level: CacheLevel::L1,
-            size: 16,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x72 => Some(CacheDescription {
-            cachetype: CacheType::Trace,
-            level: CacheLevel::L1,
-            size: 32,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x73 => Some(CacheDescription {
-            cachetype: CacheType::Trace,
-            level: CacheLevel::L1,
-            size: 64,
-            flags: CacheFlags::new().with_undocumented(true),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x77 => Some(CacheDescription {
-            cachetype: CacheType::Code,
-            level: CacheLevel::L1,
-            size: 16,
-            linesize: 64,
-            flags: CacheFlags::new().with_ia64(true).with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x78 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 1024,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x79 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 128,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x7A => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 256,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x7B => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 512,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x7C => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 1024,
-            linesize: 64,
-            flags: CacheFlags::new().with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x7D => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 2048,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x7E => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 256,
-            linesize: 128,
-            flags: CacheFlags::new().with_ia64(true).with_sectored(true),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x7F => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 512,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x02),
-            ..Default::default()
-        }),
-        0x80 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 512,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x81 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 128,
-            linesize: 32,
-            flags: CacheFlags::new().with_undocumented(true),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x82 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 256,
-            linesize: 32,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x83 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 512,
-            linesize: 32,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x84 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 1024,
-            linesize: 32,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x85 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 2048,
-            linesize: 32,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x86 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 512,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x87 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L2,
-            size: 1024,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x88 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 2048,
-            linesize: 64,
-            flags: CacheFlags::new().with_ia64(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x89 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 4096,
-            linesize: 64,
-            flags: CacheFlags::new().with_ia64(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x8A => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 8192,
-            linesize: 64,
-            flags: CacheFlags::new().with_ia64(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x8D => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 3072,
-            linesize: 128,
-            flags: CacheFlags::new().with_ia64(true),
-            associativity: CacheAssociativity::from_identifier(0x0C),
-            ..Default::default()
-        }),
-        0xD0 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 512,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0xD1 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 1024,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0xD2 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 2048,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0xD6 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 1024,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0xD7 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 2048,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0xD8 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 4096,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0xDC => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 1536,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x0C),
-            ..Default::default()
-        }),
-        0xDD => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 3072,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x0C),
-            ..Default::default()
-        }),
-        0xDE => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 6144,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x0C),
-            ..Default::default()
-        }),
-        0xE2 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 2048,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x10),
-            ..Default::default()
-        }),
-        0xE3 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 4096,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x10),
-            ..Default::default()
-        }),
-        0xE4 => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 8192,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x10),
-            ..Default::default()
-        }),
-        0xEA => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 12288,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x18),
-            ..Default::default()
-        }),
-        0xEB => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 18432,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x18),
-            ..Default::default()
-        }),
-        0xEC => Some(CacheDescription {
-            cachetype: CacheType::Unified,
-            level: CacheLevel::L3,
-            size: 24576,
-            linesize: 64,
-            flags: CacheFlags::new(),
-            associativity: CacheAssociativity::from_identifier(0x18),
-            ..Default::default()
-        }),
+        0x41 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(128)
+                .linesize(32)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x42 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(256)
+                .linesize(32)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x43 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(512)
+                .linesize(32)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x44 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(1024)
+                .linesize(32)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x45 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(2048)
+                .linesize(32)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x46 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(4096)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x47 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(8192)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x48 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(3072)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x0C))
+                .build(),
+        ),
+        0x4A => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(6144)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x0C))
+                .build(),
+        ),
+        0x4B => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(8192)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x10))
+                .build(),
+        ),
+        0x4C => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(12288)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x0C))
+                .build(),
+        ),
+        0x4D => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(16384)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x10))
+                .build(),
+        ),
+        0x4E => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(6144)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x18))
+                .build(),
+        ),
+        0x60 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Data)
+                .level(CacheLevel::L1)
+                .size(16)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x66 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Data)
+                .level(CacheLevel::L1)
+                .size(8)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x67 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Data)
+                .level(CacheLevel::L1)
+                .size(16)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x68 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Data)
+                .level(CacheLevel::L1)
+                .size(32)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x70 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Trace)
+                .level(CacheLevel::L1)
+                .size(12)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x71 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Trace)
+                .level(CacheLevel::L1)
+                .size(16)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x72 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Trace)
+                .level(CacheLevel::L1)
+                .size(32)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x73 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Trace)
+                .level(CacheLevel::L1)
+                .size(64)
+                .flags(CacheFlags::new().with_undocumented(true))
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x77 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Code)
+                .level(CacheLevel::L1)
+                .size(16)
+                .linesize(64)
+                .flags(CacheFlags::new().with_ia64(true).with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x78 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(1024)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x79 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(128)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x7A => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(256)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x7B => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(512)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x7C => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(1024)
+                .linesize(64)
+                .flags(CacheFlags::new().with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x7D => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(2048)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x7E => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(256)
+                .linesize(128)
+                .flags(CacheFlags::new().with_ia64(true).with_sectored(true))
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x7F => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(512)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x02))
+                .build(),
+        ),
+        0x80 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(512)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x81 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(128)
+                .linesize(32)
+                .flags(CacheFlags::new().with_undocumented(true))
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x82 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(256)
+                .linesize(32)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x83 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(512)
+                .linesize(32)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x84 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(1024)
+                .linesize(32)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x85 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(2048)
+                .linesize(32)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x86 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(512)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x87 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L2)
+                .size(1024)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x88 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(2048)
+                .linesize(64)
+                .flags(CacheFlags::new().with_ia64(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x89 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(4096)
+                .linesize(64)
+                .flags(CacheFlags::new().with_ia64(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x8A => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(8192)
+                .linesize(64)
+                .flags(CacheFlags::new().with_ia64(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x8D => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(3072)
+                .linesize(128)
+                .flags(CacheFlags::new().with_ia64(true))
+                .associativity(CacheAssociativity::from_identifier(0x0C))
+                .build(),
+        ),
+        0xD0 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(512)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0xD1 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(1024)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0xD2 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(2048)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0xD6 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(1024)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0xD7 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(2048)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0xD8 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(4096)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0xDC => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(1536)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x0C))
+                .build(),
+        ),
+        0xDD => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(3072)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x0C))
+                .build(),
+        ),
+        0xDE => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(6144)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x0C))
+                .build(),
+        ),
+        0xE2 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(2048)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x10))
+                .build(),
+        ),
+        0xE3 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(4096)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x10))
+                .build(),
+        ),
+        0xE4 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(8192)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x10))
+                .build(),
+        ),
+        0xEA => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(12288)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x18))
+                .build(),
+        ),
+        0xEB => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(18432)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x18))
+                .build(),
+        ),
+        0xEC => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::Unified)
+                .level(CacheLevel::L3)
+                .size(24576)
+                .linesize(64)
+                .associativity(CacheAssociativity::from_identifier(0x18))
+                .build(),
+        ),
         _ => None
     }
 }
@@ -747,284 +780,322 @@ fn lookup_cache_internal(descriptor: u8) -> Option<CacheDescription> {
 #[cfg(feature = "legacy-tlb-descriptors")]
 fn lookup_tlb_internal(descriptor: u8) -> Option<CacheDescription> {
     match descriptor {
-        0x01 => Some(CacheDescription {
-            cachetype: CacheType::CodeTLB,
-            size: 32,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x02 => Some(CacheDescription {
-            cachetype: CacheType::CodeTLB,
-            size: 2,
-            flags: CacheFlags::new().with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0xFF),
-            ..Default::default()
-        }),
-        0x03 => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 64,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x04 => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 8,
-            flags: CacheFlags::new().with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x05 => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 32,
-            flags: CacheFlags::new().with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x0B => Some(CacheDescription {
-            cachetype: CacheType::CodeTLB,
-            level: CacheLevel::L1,
-            size: 4,
-            flags: CacheFlags::new().with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x4F => Some(CacheDescription {
-            cachetype: CacheType::CodeTLB,
-            size: 32,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x00),
-            ..Default::default()
-        }),
-        0x50 => Some(CacheDescription {
-            cachetype: CacheType::CodeTLB,
-            size: 64,
-            flags: CacheFlags::new().with_pages_4k(true).with_pages_2m(true).with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0x00),
-            ..Default::default()
-        }),
-        0x51 => Some(CacheDescription {
-            cachetype: CacheType::CodeTLB,
-            size: 128,
-            flags: CacheFlags::new().with_pages_4k(true).with_pages_2m(true).with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0x00),
-            ..Default::default()
-        }),
-        0x52 => Some(CacheDescription {
-            cachetype: CacheType::CodeTLB,
-            size: 256,
-            flags: CacheFlags::new().with_pages_4k(true).with_pages_2m(true).with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0x00),
-            ..Default::default()
-        }),
-        0x55 => Some(CacheDescription {
-            cachetype: CacheType::CodeTLB,
-            size: 256,
-            flags: CacheFlags::new().with_pages_2m(true).with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0xFF),
-            ..Default::default()
-        }),
-        0x56 => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            level: CacheLevel::L0,
-            size: 16,
-            flags: CacheFlags::new().with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x57 => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            level: CacheLevel::L0,
-            size: 16,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x59 => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            level: CacheLevel::L0,
-            size: 16,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0xFF),
-            ..Default::default()
-        }),
-        0x5A => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 32,
-            flags: CacheFlags::new().with_pages_2m(true).with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x5B => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 64,
-            flags: CacheFlags::new().with_pages_4k(true).with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0xFF),
-            ..Default::default()
-        }),
-        0x5C => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 128,
-            flags: CacheFlags::new().with_pages_4k(true).with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0xFF),
-            ..Default::default()
-        }),
-        0x5D => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 256,
-            flags: CacheFlags::new().with_pages_4k(true).with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0xFF),
-            ..Default::default()
-        }),
-        0x61 => Some(CacheDescription {
-            cachetype: CacheType::CodeTLB,
-            size: 48,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0xFF),
-            ..Default::default()
-        }),
+        0x01 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::CodeTLB)
+                .size(32)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x02 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::CodeTLB)
+                .size(2)
+                .flags(CacheFlags::new().with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0xFF))
+                .build(),
+        ),
+        0x03 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(64)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x04 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(8)
+                .flags(CacheFlags::new().with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x05 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(32)
+                .flags(CacheFlags::new().with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x0B => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::CodeTLB)
+                .level(CacheLevel::L1)
+                .size(4)
+                .flags(CacheFlags::new().with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x4F => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::CodeTLB)
+                .size(32)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x00))
+                .build(),
+        ),
+        0x50 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::CodeTLB)
+                .size(64)
+                .flags(CacheFlags::new().with_pages_4k(true).with_pages_2m(true).with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0x00))
+                .build(),
+        ),
+        0x51 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::CodeTLB)
+                .size(128)
+                .flags(CacheFlags::new().with_pages_4k(true).with_pages_2m(true).with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0x00))
+                .build(),
+        ),
+        0x52 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::CodeTLB)
+                .size(256)
+                .flags(CacheFlags::new().with_pages_4k(true).with_pages_2m(true).with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0x00))
+                .build(),
+        ),
+        0x55 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::CodeTLB)
+                .size(256)
+                .flags(CacheFlags::new().with_pages_2m(true).with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0xFF))
+                .build(),
+        ),
+        0x56 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .level(CacheLevel::L0)
+                .size(16)
+                .flags(CacheFlags::new().with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x57 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .level(CacheLevel::L0)
+                .size(16)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x59 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .level(CacheLevel::L0)
+                .size(16)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0xFF))
+                .build(),
+        ),
+        0x5A => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(32)
+                .flags(CacheFlags::new().with_pages_2m(true).with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x5B => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(64)
+                .flags(CacheFlags::new().with_pages_4k(true).with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0xFF))
+                .build(),
+        ),
+        0x5C => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(128)
+                .flags(CacheFlags::new().with_pages_4k(true).with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0xFF))
+                .build(),
+        ),
+        0x5D => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(256)
+                .flags(CacheFlags::new().with_pages_4k(true).with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0xFF))
+                .build(),
+        ),
+        0x61 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::CodeTLB)
+                .size(48)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0xFF))
+                .build(),
+        ),
         // 0x63 -> two different entries simultaneously, handled in parent function
-        0x64 => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 512,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0x6A => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            level: CacheLevel::L0,
-            size: 64,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x6B => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 256,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x6C => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 128,
-            flags: CacheFlags::new().with_pages_2m(true).with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0x6D => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 16,
-            flags: CacheFlags::new().with_pages_1g(true),
-            associativity: CacheAssociativity::from_identifier(0xFF),
-            ..Default::default()
-        }),
-        0x76 => Some(CacheDescription {
-            cachetype: CacheType::CodeTLB,
-            size: 8,
-            flags: CacheFlags::new().with_pages_2m(true).with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0xFF),
-            ..Default::default()
-        }),
-        0xA0 => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 32,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0xFF),
-            ..Default::default()
-        }),
-        0xB0 => Some(CacheDescription {
-            cachetype: CacheType::CodeTLB,
-            size: 128,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
+        0x64 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(512)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0x6A => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .level(CacheLevel::L0)
+                .size(64)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x6B => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(256)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x6C => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(128)
+                .flags(CacheFlags::new().with_pages_2m(true).with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0x6D => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(16)
+                .flags(CacheFlags::new().with_pages_1g(true))
+                .associativity(CacheAssociativity::from_identifier(0xFF))
+                .build(),
+        ),
+        0x76 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::CodeTLB)
+                .size(8)
+                .flags(CacheFlags::new().with_pages_2m(true).with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0xFF))
+                .build(),
+        ),
+        0xA0 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(32)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0xFF))
+                .build(),
+        ),
+        0xB0 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::CodeTLB)
+                .size(128)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
         // 0xB1 -> two entries, special case. handled in parent function
-        0xB2 => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 64,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0xB3 => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 128,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0xB4 => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            level: CacheLevel::L1,
-            size: 256,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0xB5 => Some(CacheDescription {
-            cachetype: CacheType::CodeTLB,
-            size: 64,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0xB6 => Some(CacheDescription {
-            cachetype: CacheType::CodeTLB,
-            size: 128,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0xBA => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            level: CacheLevel::L1,
-            size: 64,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0xC0 => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 8,
-            flags: CacheFlags::new().with_pages_4k(true).with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0xC1 => Some(CacheDescription {
-            cachetype: CacheType::SharedTLB,
-            level: CacheLevel::L2,
-            size: 1024,
-            flags: CacheFlags::new().with_pages_4k(true).with_pages_2m(true),
-            associativity: CacheAssociativity::from_identifier(0x08),
-            ..Default::default()
-        }),
-        0xC2 => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 16,
-            flags: CacheFlags::new().with_pages_2m(true).with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
+        0xB2 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(64)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0xB3 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(128)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0xB4 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .level(CacheLevel::L1)
+                .size(256)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0xB5 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::CodeTLB)
+                .size(64)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0xB6 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::CodeTLB)
+                .size(128)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0xBA => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .level(CacheLevel::L1)
+                .size(64)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0xC0 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(8)
+                .flags(CacheFlags::new().with_pages_4k(true).with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0xC1 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::SharedTLB)
+                .level(CacheLevel::L2)
+                .size(1024)
+                .flags(CacheFlags::new().with_pages_4k(true).with_pages_2m(true))
+                .associativity(CacheAssociativity::from_identifier(0x08))
+                .build(),
+        ),
+        0xC2 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(16)
+                .flags(CacheFlags::new().with_pages_2m(true).with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
         // 0xC3 -> two entries. special case handled by parent function.
-        0xC4 => Some(CacheDescription {
-            cachetype: CacheType::DataTLB,
-            size: 32,
-            flags: CacheFlags::new().with_pages_2m(true).with_pages_4m(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
-        0xCA => Some(CacheDescription {
-            cachetype: CacheType::SharedTLB,
-            level: CacheLevel::L2,
-            size: 512,
-            flags: CacheFlags::new().with_pages_4k(true),
-            associativity: CacheAssociativity::from_identifier(0x04),
-            ..Default::default()
-        }),
+        0xC4 => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::DataTLB)
+                .size(32)
+                .flags(CacheFlags::new().with_pages_2m(true).with_pages_4m(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
+        0xCA => Some(
+            CacheDescription::builder()
+                .cachetype(CacheType::SharedTLB)
+                .level(CacheLevel::L2)
+                .size(512)
+                .flags(CacheFlags::new().with_pages_4k(true))
+                .associativity(CacheAssociativity::from_identifier(0x04))
+                .build(),
+        ),
         _ => None,
     }
 }
@@ -1037,20 +1108,22 @@ fn lookup_descriptor_fallback(descriptor: u8, out: &mut CacheVec, filter: &Vec<C
         0x63 => {
             if filter.contains(&CacheType::DataTLB) {
                 let mut entries = CacheVec::new();
-                entries.0.push(CacheDescription {
-                    cachetype: CacheType::DataTLB,
-                    size: 32,
-                    flags: CacheFlags::new().with_pages_2m(true).with_pages_4m(true),
-                    associativity: CacheAssociativity::from_identifier(0x04),
-                    ..Default::default()
-                });
-                entries.0.push(CacheDescription {
-                    cachetype: CacheType::DataTLB,
-                    size: 4,
-                    flags: CacheFlags::new().with_pages_1g(true),
-                    associativity: CacheAssociativity::from_identifier(0x04),
-                    ..Default::default()
-                });
+                entries.0.push(
+                    CacheDescription::builder()
+                        .cachetype(CacheType::DataTLB)
+                        .size(32)
+                        .flags(CacheFlags::new().with_pages_2m(true).with_pages_4m(true))
+                        .associativity(CacheAssociativity::from_identifier(0x04))
+                        .build(),
+                );
+                entries.0.push(
+                    CacheDescription::builder()
+                        .cachetype(CacheType::DataTLB)
+                        .size(4)
+                        .flags(CacheFlags::new().with_pages_1g(true))
+                        .associativity(CacheAssociativity::from_identifier(0x04))
+                        .build(),
+                );
                 debug!("lookup_descriptors() descriptor {:0>2x}: {:?}", descriptor, entries);
                 out.0.append(&mut entries.0);
             }
@@ -1058,20 +1131,22 @@ fn lookup_descriptor_fallback(descriptor: u8, out: &mut CacheVec, filter: &Vec<C
         0xB1 => {
             if filter.contains(&CacheType::CodeTLB) {
                 let mut entries = CacheVec::new();
-                entries.0.push(CacheDescription {
-                    cachetype: CacheType::CodeTLB,
-                    size: 8,
-                    flags: CacheFlags::new().with_pages_2m(true),
-                    associativity: CacheAssociativity::from_identifier(0x04),
-                    ..Default::default()
-                });
-                entries.0.push(CacheDescription {
-                    cachetype: CacheType::CodeTLB,
-                    size: 4,
-                    flags: CacheFlags::new().with_pages_4m(true),
-                    associativity: CacheAssociativity::from_identifier(0x04),
-                    ..Default::default()
-                });
+                entries.0.push(
+                    CacheDescription::builder()
+                        .cachetype(CacheType::CodeTLB)
+                        .size(8)
+                        .flags(CacheFlags::new().with_pages_2m(true))
+                        .associativity(CacheAssociativity::from_identifier(0x04))
+                        .build(),
+                );
+                entries.0.push(
+                    CacheDescription::builder()
+                        .cachetype(CacheType::CodeTLB)
+                        .size(4)
+                        .flags(CacheFlags::new().with_pages_4m(true))
+                        .associativity(CacheAssociativity::from_identifier(0x04))
+                        .build(),
+                );
                 debug!("lookup_descriptors() descriptor {:0>2x}: {:?}", descriptor, entries);
                 out.0.append(&mut entries.0);
             }
@@ -1079,22 +1154,24 @@ fn lookup_descriptor_fallback(descriptor: u8, out: &mut CacheVec, filter: &Vec<C
         0xC3 => {
             if filter.contains(&CacheType::SharedTLB) {
                 let mut entries = CacheVec::new();
-                entries.0.push(CacheDescription {
-                    cachetype: CacheType::SharedTLB,
-                    level: CacheLevel::L2,
-                    size: 1536,
-                    flags: CacheFlags::new().with_pages_4k(true).with_pages_2m(true),
-                    associativity: CacheAssociativity::from_identifier(0x06),
-                    ..Default::default()
-                });
-                entries.0.push(CacheDescription {
-                    cachetype: CacheType::SharedTLB,
-                    level: CacheLevel::L2,
-                    size: 16,
-                    flags: CacheFlags::new().with_pages_1g(true),
-                    associativity: CacheAssociativity::from_identifier(0x04),
-                    ..Default::default()
-                });
+                entries.0.push(
+                    CacheDescription::builder()
+                        .cachetype(CacheType::SharedTLB)
+                        .level(CacheLevel::L2)
+                        .size(1536)
+                        .flags(CacheFlags::new().with_pages_4k(true).with_pages_2m(true))
+                        .associativity(CacheAssociativity::from_identifier(0x06))
+                        .build(),
+                );
+                entries.0.push(
+                    CacheDescription::builder()
+                        .cachetype(CacheType::SharedTLB)
+                        .level(CacheLevel::L2)
+                        .size(16)
+                        .flags(CacheFlags::new().with_pages_1g(true))
+                        .associativity(CacheAssociativity::from_identifier(0x04))
+                        .build(),
+                );
                 debug!("lookup_descriptors() descriptor {:0>2x}: {:?}", descriptor, entries);
                 out.0.append(&mut entries.0);
             }
@@ -1103,7 +1180,7 @@ fn lookup_descriptor_fallback(descriptor: u8, out: &mut CacheVec, filter: &Vec<C
     }
 }
 
-fn lookup_descriptor_internal(descriptor: u8) -> Option<CacheDescription> {
+pub fn lookup_descriptor_internal(descriptor: u8) -> Option<CacheDescription> {
     let mut found: Option<CacheDescription> = None;
     #[cfg(feature = "legacy-cache-descriptors")]
     if found.is_none() {
@@ -1116,14 +1193,18 @@ fn lookup_descriptor_internal(descriptor: u8) -> Option<CacheDescription> {
     found
 }
 
-pub fn lookup_descriptors(out: &mut CacheVec, descriptors: Vec<u8>, filter: &Vec<CacheType>)
+pub fn lookup_descriptors(out: &mut CacheVec, descriptors: Vec<u8>, filter: &Vec<CacheType>, table: &CacheDescriptorTable)
 {
     for descriptor in descriptors.iter() {
         if *descriptor == 0x00 {
             // null cache descriptor, not worth logging
             continue;
         }
-        if let Some(desc) = lookup_descriptor_internal(*descriptor) {
+        if *descriptor == 0xF0 || *descriptor == 0xF1 {
+            // prefetch stride, not a cache. See describe_prefetch_stride().
+            continue;
+        }
+        if let Some(desc) = table.lookup(*descriptor) {
             if filter.contains(&desc.cachetype) {
                 debug!("lookup_descriptors() descriptor {:0>2x}: {:?}", descriptor, desc);
                 out.0.push(desc);
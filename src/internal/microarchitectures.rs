@@ -0,0 +1,162 @@
+use core::ops::RangeInclusive;
+
+use crate::cpuid::VendorMask;
+
+/// Microarchitecture (or core) codenames, keyed on vendor, family, model range
+/// and stepping range. Entries are searched in order and the first match wins.
+pub static MICROARCHITECTURES: [(VendorMask, u16, RangeInclusive<u16>, RangeInclusive<u8>, &str); 147] = [
+    // Intel
+    (VendorMask::INTEL,         0x04, 0x00..=0x0F, 0x0..=0xF, "i486"),
+    (VendorMask::INTEL,         0x05, 0x00..=0x01, 0x0..=0xF, "P5"),
+    (VendorMask::INTEL,         0x05, 0x02..=0x02, 0x0..=0xF, "P54C"),
+    (VendorMask::INTEL,         0x05, 0x03..=0x03, 0x0..=0xF, "P24T"),
+    (VendorMask::INTEL,         0x05, 0x04..=0x04, 0x0..=0xF, "P55C"),
+    (VendorMask::INTEL,         0x05, 0x07..=0x07, 0x0..=0xF, "P54C"),
+    (VendorMask::INTEL,         0x05, 0x08..=0x08, 0x0..=0xF, "Tillamook"),
+    (VendorMask::INTEL,         0x05, 0x09..=0x0A, 0x0..=0xF, "Lakemont"),
+    (VendorMask::INTEL,         0x06, 0x00..=0x01, 0x0..=0xF, "P6"),
+    (VendorMask::INTEL,         0x06, 0x03..=0x03, 0x0..=0xF, "Klamath"),
+    (VendorMask::INTEL,         0x06, 0x05..=0x05, 0x0..=0xF, "Deschutes"),
+    (VendorMask::INTEL,         0x06, 0x06..=0x06, 0x0..=0xF, "Mendocino"),
+    (VendorMask::INTEL,         0x06, 0x07..=0x07, 0x0..=0xF, "Katmai"),
+    (VendorMask::INTEL,         0x06, 0x08..=0x08, 0x0..=0xF, "Coppermine"),
+    (VendorMask::INTEL,         0x06, 0x09..=0x09, 0x0..=0xF, "Banias"),
+    (VendorMask::INTEL,         0x06, 0x0A..=0x0A, 0x0..=0xF, "Coppermine"),
+    (VendorMask::INTEL,         0x06, 0x0B..=0x0B, 0x0..=0xF, "Tualatin"),
+    (VendorMask::INTEL,         0x06, 0x0D..=0x0D, 0x0..=0xF, "Dothan"),
+    (VendorMask::INTEL,         0x06, 0x0E..=0x0E, 0x0..=0xF, "Yonah"),
+    (VendorMask::INTEL,         0x06, 0x0F..=0x0F, 0x0..=0xF, "Merom"),
+    (VendorMask::INTEL,         0x06, 0x16..=0x16, 0x0..=0xF, "Merom"),
+    (VendorMask::INTEL,         0x06, 0x17..=0x17, 0x0..=0xF, "Penryn"),
+    (VendorMask::INTEL,         0x06, 0x1A..=0x1A, 0x0..=0xF, "Nehalem"),
+    (VendorMask::INTEL,         0x06, 0x1C..=0x1C, 0x0..=0xF, "Bonnell"),
+    (VendorMask::INTEL,         0x06, 0x1D..=0x1D, 0x0..=0xF, "Penryn"),
+    (VendorMask::INTEL,         0x06, 0x1E..=0x1F, 0x0..=0xF, "Nehalem"),
+    (VendorMask::INTEL,         0x06, 0x25..=0x25, 0x0..=0xF, "Westmere"),
+    (VendorMask::INTEL,         0x06, 0x26..=0x26, 0x0..=0xF, "Bonnell"),
+    (VendorMask::INTEL,         0x06, 0x27..=0x27, 0x0..=0xF, "Saltwell"),
+    (VendorMask::INTEL,         0x06, 0x2A..=0x2A, 0x0..=0xF, "Sandy Bridge"),
+    (VendorMask::INTEL,         0x06, 0x2C..=0x2C, 0x0..=0xF, "Westmere"),
+    (VendorMask::INTEL,         0x06, 0x2D..=0x2D, 0x0..=0xF, "Sandy Bridge"),
+    (VendorMask::INTEL,         0x06, 0x2E..=0x2E, 0x0..=0xF, "Nehalem"),
+    (VendorMask::INTEL,         0x06, 0x2F..=0x2F, 0x0..=0xF, "Westmere"),
+    (VendorMask::INTEL,         0x06, 0x35..=0x36, 0x0..=0xF, "Saltwell"),
+    (VendorMask::INTEL,         0x06, 0x37..=0x37, 0x0..=0xF, "Silvermont"),
+    (VendorMask::INTEL,         0x06, 0x3A..=0x3A, 0x0..=0xF, "Ivy Bridge"),
+    (VendorMask::INTEL,         0x06, 0x3C..=0x3C, 0x0..=0xF, "Haswell"),
+    (VendorMask::INTEL,         0x06, 0x3D..=0x3D, 0x0..=0xF, "Broadwell"),
+    (VendorMask::INTEL,         0x06, 0x3E..=0x3E, 0x0..=0xF, "Ivy Bridge"),
+    (VendorMask::INTEL,         0x06, 0x3F..=0x3F, 0x0..=0xF, "Haswell"),
+    (VendorMask::INTEL,         0x06, 0x45..=0x46, 0x0..=0xF, "Haswell"),
+    (VendorMask::INTEL,         0x06, 0x47..=0x47, 0x0..=0xF, "Broadwell"),
+    (VendorMask::INTEL,         0x06, 0x4A..=0x4A, 0x0..=0xF, "Silvermont"),
+    (VendorMask::INTEL,         0x06, 0x4C..=0x4C, 0x0..=0xF, "Airmont"),
+    (VendorMask::INTEL,         0x06, 0x4D..=0x4D, 0x0..=0xF, "Silvermont"),
+    (VendorMask::INTEL,         0x06, 0x4E..=0x4E, 0x0..=0xF, "Skylake"),
+    (VendorMask::INTEL,         0x06, 0x4F..=0x4F, 0x0..=0xF, "Broadwell"),
+    (VendorMask::INTEL,         0x06, 0x55..=0x55, 0x0..=0x4, "Skylake"),
+    (VendorMask::INTEL,         0x06, 0x55..=0x55, 0x5..=0x9, "Cascade Lake"),
+    (VendorMask::INTEL,         0x06, 0x55..=0x55, 0xA..=0xF, "Cooper Lake"),
+    (VendorMask::INTEL,         0x06, 0x56..=0x56, 0x0..=0xF, "Broadwell"),
+    (VendorMask::INTEL,         0x06, 0x57..=0x57, 0x0..=0xF, "Knights Landing"),
+    (VendorMask::INTEL,         0x06, 0x5A..=0x5A, 0x0..=0xF, "Silvermont"),
+    (VendorMask::INTEL,         0x06, 0x5C..=0x5C, 0x0..=0xF, "Goldmont"),
+    (VendorMask::INTEL,         0x06, 0x5D..=0x5D, 0x0..=0xF, "Silvermont"),
+    (VendorMask::INTEL,         0x06, 0x5E..=0x5E, 0x0..=0xF, "Skylake"),
+    (VendorMask::INTEL,         0x06, 0x5F..=0x5F, 0x0..=0xF, "Goldmont"),
+    (VendorMask::INTEL,         0x06, 0x66..=0x66, 0x0..=0xF, "Cannon Lake"),
+    (VendorMask::INTEL,         0x06, 0x6A..=0x6A, 0x0..=0xF, "Ice Lake"),
+    (VendorMask::INTEL,         0x06, 0x6C..=0x6C, 0x0..=0xF, "Ice Lake"),
+    (VendorMask::INTEL,         0x06, 0x75..=0x75, 0x0..=0xF, "Airmont"),
+    (VendorMask::INTEL,         0x06, 0x7A..=0x7A, 0x0..=0xF, "Goldmont Plus"),
+    (VendorMask::INTEL,         0x06, 0x7D..=0x7E, 0x0..=0xF, "Ice Lake"),
+    (VendorMask::INTEL,         0x06, 0x85..=0x85, 0x0..=0xF, "Knights Mill"),
+    (VendorMask::INTEL,         0x06, 0x86..=0x86, 0x0..=0xF, "Tremont"),
+    (VendorMask::INTEL,         0x06, 0x8A..=0x8A, 0x0..=0xF, "Lakefield"),
+    (VendorMask::INTEL,         0x06, 0x8C..=0x8D, 0x0..=0xF, "Tiger Lake"),
+    (VendorMask::INTEL,         0x06, 0x8E..=0x8E, 0x0..=0x9, "Kaby Lake"),
+    (VendorMask::INTEL,         0x06, 0x8E..=0x8E, 0xA..=0xA, "Coffee Lake"),
+    (VendorMask::INTEL,         0x06, 0x8E..=0x8E, 0xB..=0xB, "Whiskey Lake"),
+    (VendorMask::INTEL,         0x06, 0x8E..=0x8E, 0xC..=0xF, "Comet Lake"),
+    (VendorMask::INTEL,         0x06, 0x8F..=0x8F, 0x0..=0xF, "Sapphire Rapids"),
+    (VendorMask::INTEL,         0x06, 0x96..=0x96, 0x0..=0xF, "Tremont"),
+    (VendorMask::INTEL,         0x06, 0x97..=0x97, 0x0..=0xF, "Alder Lake"),
+    (VendorMask::INTEL,         0x06, 0x9A..=0x9A, 0x0..=0xF, "Alder Lake"),
+    (VendorMask::INTEL,         0x06, 0x9C..=0x9C, 0x0..=0xF, "Tremont"),
+    (VendorMask::INTEL,         0x06, 0x9D..=0x9D, 0x0..=0xF, "Ice Lake"),
+    (VendorMask::INTEL,         0x06, 0x9E..=0x9E, 0x0..=0x9, "Kaby Lake"),
+    (VendorMask::INTEL,         0x06, 0x9E..=0x9E, 0xA..=0xF, "Coffee Lake"),
+    (VendorMask::INTEL,         0x06, 0xA5..=0xA6, 0x0..=0xF, "Comet Lake"),
+    (VendorMask::INTEL,         0x06, 0xA7..=0xA7, 0x0..=0xF, "Rocket Lake"),
+    (VendorMask::INTEL,         0x06, 0xAA..=0xAA, 0x0..=0xF, "Meteor Lake"),
+    (VendorMask::INTEL,         0x06, 0xAC..=0xAC, 0x0..=0xF, "Meteor Lake"),
+    (VendorMask::INTEL,         0x06, 0xAD..=0xAE, 0x0..=0xF, "Granite Rapids"),
+    (VendorMask::INTEL,         0x06, 0xAF..=0xAF, 0x0..=0xF, "Sierra Forest"),
+    (VendorMask::INTEL,         0x06, 0xB7..=0xB7, 0x0..=0xF, "Raptor Lake"),
+    (VendorMask::INTEL,         0x06, 0xBA..=0xBA, 0x0..=0xF, "Raptor Lake"),
+    (VendorMask::INTEL,         0x06, 0xBD..=0xBD, 0x0..=0xF, "Lunar Lake"),
+    (VendorMask::INTEL,         0x06, 0xBE..=0xBE, 0x0..=0xF, "Gracemont"),
+    (VendorMask::INTEL,         0x06, 0xBF..=0xBF, 0x0..=0xF, "Raptor Lake"),
+    (VendorMask::INTEL,         0x06, 0xC5..=0xC6, 0x0..=0xF, "Arrow Lake"),
+    (VendorMask::INTEL,         0x06, 0xCF..=0xCF, 0x0..=0xF, "Emerald Rapids"),
+    (VendorMask::INTEL,         0x0B, 0x01..=0x01, 0x0..=0xF, "Knights Corner"),
+    (VendorMask::INTEL,         0x0F, 0x00..=0x01, 0x0..=0xF, "Willamette"),
+    (VendorMask::INTEL,         0x0F, 0x02..=0x02, 0x0..=0xF, "Northwood"),
+    (VendorMask::INTEL,         0x0F, 0x03..=0x04, 0x0..=0xF, "Prescott"),
+    (VendorMask::INTEL,         0x0F, 0x06..=0x06, 0x0..=0xF, "Cedar Mill"),
+
+    // AMD and Hygon
+    (VendorMask::AMD,           0x04, 0x03..=0x09, 0x0..=0xF, "Am486"),
+    (VendorMask::AMD,           0x04, 0x0E..=0x0F, 0x0..=0xF, "Am5x86"),
+    (VendorMask::AMD,           0x05, 0x00..=0x03, 0x0..=0xF, "K5"),
+    (VendorMask::AMD,           0x05, 0x06..=0x09, 0x0..=0xF, "K6"),
+    (VendorMask::AMD,           0x05, 0x0A..=0x0A, 0x0..=0xF, "Geode LX"),
+    (VendorMask::AMD,           0x05, 0x0D..=0x0D, 0x0..=0xF, "K6"),
+    (VendorMask::AMD,           0x06, 0x00..=0xFF, 0x0..=0xF, "K7"),
+    (VendorMask::AMD,           0x0F, 0x00..=0xFF, 0x0..=0xF, "K8"),
+    (VendorMask::AMD,           0x10, 0x00..=0xFF, 0x0..=0xF, "K10"),
+    (VendorMask::AMD,           0x11, 0x00..=0xFF, 0x0..=0xF, "K8"),
+    (VendorMask::AMD,           0x12, 0x00..=0xFF, 0x0..=0xF, "K10"),
+    (VendorMask::AMD,           0x14, 0x00..=0xFF, 0x0..=0xF, "Bobcat"),
+    (VendorMask::AMD,           0x15, 0x00..=0x01, 0x0..=0xF, "Bulldozer"),
+    (VendorMask::AMD,           0x15, 0x02..=0x02, 0x0..=0xF, "Piledriver"),
+    (VendorMask::AMD,           0x15, 0x10..=0x1F, 0x0..=0xF, "Piledriver"),
+    (VendorMask::AMD,           0x15, 0x30..=0x3F, 0x0..=0xF, "Steamroller"),
+    (VendorMask::AMD,           0x15, 0x60..=0x7F, 0x0..=0xF, "Excavator"),
+    (VendorMask::AMD,           0x16, 0x00..=0x2F, 0x0..=0xF, "Jaguar"),
+    (VendorMask::AMD,           0x16, 0x30..=0x3F, 0x0..=0xF, "Puma"),
+    (VendorMask::AMD,           0x17, 0x08..=0x08, 0x0..=0xF, "Zen+"),
+    (VendorMask::AMD,           0x17, 0x18..=0x18, 0x0..=0xF, "Zen+"),
+    (VendorMask::AMD,           0x17, 0x00..=0x2F, 0x0..=0xF, "Zen"),
+    (VendorMask::AMD,           0x17, 0x50..=0x5F, 0x0..=0xF, "Zen"),
+    (VendorMask::AMD,           0x17, 0x30..=0xFF, 0x0..=0xF, "Zen 2"),
+    (VendorMask::HYGON,         0x18, 0x00..=0xFF, 0x0..=0xF, "Zen"),
+    (VendorMask::AMD,           0x19, 0x10..=0x1F, 0x0..=0xF, "Zen 4"),
+    (VendorMask::AMD,           0x19, 0x40..=0x4F, 0x0..=0xF, "Zen 3+"),
+    (VendorMask::AMD,           0x19, 0x60..=0xAF, 0x0..=0xF, "Zen 4"),
+    (VendorMask::AMD,           0x19, 0x00..=0x5F, 0x0..=0xF, "Zen 3"),
+    (VendorMask::AMD,           0x1A, 0x00..=0xFF, 0x0..=0xF, "Zen 5"),
+
+    // Centaur (VIA)
+    (VendorMask::CENTAUR,       0x05, 0x04..=0x04, 0x0..=0xF, "WinChip C6"),
+    (VendorMask::CENTAUR,       0x05, 0x08..=0x08, 0x0..=0xF, "WinChip 2"),
+    (VendorMask::CENTAUR,       0x05, 0x09..=0x09, 0x0..=0xF, "WinChip 3"),
+    (VendorMask::CENTAUR,       0x06, 0x06..=0x06, 0x0..=0xF, "Samuel"),
+    (VendorMask::CENTAUR,       0x06, 0x07..=0x07, 0x0..=0x7, "Samuel 2"),
+    (VendorMask::CENTAUR,       0x06, 0x07..=0x07, 0x8..=0xF, "Ezra"),
+    (VendorMask::CENTAUR,       0x06, 0x08..=0x08, 0x0..=0xF, "Ezra-T"),
+    (VendorMask::CENTAUR,       0x06, 0x09..=0x09, 0x0..=0xF, "Nehemiah"),
+    (VendorMask::CENTAUR,       0x06, 0x0A..=0x0A, 0x0..=0xF, "Esther"),
+    (VendorMask::CENTAUR,       0x06, 0x0D..=0x0D, 0x0..=0xF, "Esther"),
+    (VendorMask::CENTAUR,       0x06, 0x0F..=0x0F, 0x0..=0xF, "Isaiah"),
+
+    // Other vendors
+    (VendorMask::CYRIX,         0x04, 0x04..=0x04, 0x0..=0xF, "MediaGX"),
+    (VendorMask::CYRIX,         0x04, 0x09..=0x09, 0x0..=0xF, "5x86"),
+    (VendorMask::CYRIX,         0x05, 0x02..=0x03, 0x0..=0xF, "6x86"),
+    (VendorMask::CYRIX,         0x05, 0x04..=0x04, 0x0..=0xF, "MediaGX"),
+    (VendorMask::CYRIX,         0x06, 0x00..=0x00, 0x0..=0xF, "6x86MX"),
+    (VendorMask::TRANSMETA,     0x05, 0x00..=0xFF, 0x0..=0xF, "Crusoe"),
+    (VendorMask::TRANSMETA,     0x0F, 0x00..=0xFF, 0x0..=0xF, "Efficeon"),
+    (VendorMask::RISE,          0x05, 0x00..=0xFF, 0x0..=0xF, "mP6"),
+];
@@ -1,2 +1,5 @@
 pub mod cache_descriptors;
 pub mod feature_flags;
+pub mod microarchitectures;
+pub mod prelude;
+pub mod util;
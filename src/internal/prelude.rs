@@ -0,0 +1,10 @@
+// Re-exports of the `alloc` types used throughout the crate, so the decoding
+// modules build identically with or without `std`.
+
+pub use alloc::borrow::ToOwned;
+pub use alloc::string::{String, ToString};
+pub use alloc::sync::Arc;
+pub use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+pub use alloc::{format, vec};
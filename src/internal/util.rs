@@ -0,0 +1,13 @@
+use crate::internal::prelude::*;
+
+/// Prefixes each non-blank line in `s` with `prefix`.
+pub fn indent(s: &str, prefix: &str) -> String {
+    let mut result = String::with_capacity(s.len() + prefix.len() * 4);
+    for line in s.split_inclusive('\n') {
+        if !line.trim().is_empty() {
+            result.push_str(prefix);
+        }
+        result.push_str(line);
+    }
+    result
+}
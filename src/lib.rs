@@ -1,7 +1,21 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 #[macro_use]
 
 pub mod cpuid;
 pub mod cache;
+pub mod diff;
+pub mod error;
 pub mod feature;
 pub(crate) mod internal;
 pub mod topology;
+pub mod address;
+pub mod security;
+pub mod rdt;
+pub mod trace;
+pub mod validate;
+pub mod xsave;
+pub mod frequency;
+pub mod virtualization;
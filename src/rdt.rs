@@ -0,0 +1,387 @@
+#![allow(dead_code)]
+
+use core::fmt;
+use log::*;
+use modular_bitfield::prelude::*;
+
+use crate::cpuid::{Processor, RegisterName, VendorMask};
+use crate::internal::prelude::*;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes Resource Director Technology (RDT) / Platform Quality of Service
+/// capabilities, from leaves `0x0000_000F` (monitoring) and `0x0000_0010`
+/// (allocation).
+pub struct RdtInfo {
+    /// Highest RMID supported by any monitored resource, or zero if monitoring
+    /// isn't supported.
+    pub max_rmid: u32,
+
+    /// L3 cache monitoring capabilities, if supported.
+    pub l3_monitoring: Option<RdtMonitoring>,
+
+    /// L3 cache allocation (CAT) capabilities, if supported.
+    pub l3_allocation: Option<CacheAllocation>,
+
+    /// L2 cache allocation (CAT) capabilities, if supported.
+    pub l2_allocation: Option<CacheAllocation>,
+
+    /// Memory bandwidth allocation (MBA) capabilities, if supported.
+    pub memory_bandwidth_allocation: Option<MemoryBandwidthAllocation>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes the L3 cache monitoring capabilities, from leaf `0x0000_000F`
+/// subleaf 1.
+pub struct RdtMonitoring {
+    /// Highest RMID supported for L3 monitoring.
+    pub max_rmid: u32,
+
+    /// Factor to convert the reported counter values to bytes.
+    pub upscaling_factor: u32,
+
+    /// Width of the monitoring counters, in bits.
+    pub counter_width: u8,
+
+    /// `true` if L3 occupancy monitoring is supported.
+    pub occupancy: bool,
+
+    /// `true` if L3 total memory bandwidth monitoring is supported.
+    pub total_bandwidth: bool,
+
+    /// `true` if L3 local memory bandwidth monitoring is supported.
+    pub local_bandwidth: bool,
+}
+
+impl fmt::Display for RdtMonitoring {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} RMIDs", self.max_rmid as u64 + 1)?;
+        for (supported, name) in [
+            (self.occupancy, "occupancy"),
+            (self.total_bandwidth, "total bandwidth"),
+            (self.local_bandwidth, "local bandwidth"),
+        ]
+        .iter()
+        {
+            if *supported {
+                write!(f, ", {}", name)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes the cache allocation (CAT) capabilities of a cache level, from
+/// leaf `0x0000_0010` subleaf 1 (L3) or 2 (L2).
+pub struct CacheAllocation {
+    /// Number of classes of service (COS).
+    pub cos_count: u32,
+
+    /// Length of the capacity bitmask (CBM), in bits.
+    pub cbm_length: u8,
+
+    /// Bitmap of capacity bitmask bits which may be shared with other
+    /// entities, such as I/O devices.
+    pub shareable_bitmap: u32,
+
+    /// `true` if code and data prioritization (CDP) is supported.
+    pub cdp: bool,
+
+    /// `true` if non-contiguous capacity bitmasks are supported.
+    pub noncontiguous_cbm: bool,
+}
+
+impl fmt::Display for CacheAllocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} COS, {}-bit CBM", self.cos_count, self.cbm_length)?;
+        if self.cdp {
+            write!(f, ", CDP")?;
+        }
+        if self.noncontiguous_cbm {
+            write!(f, ", non-contiguous")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes the memory bandwidth allocation (MBA) capabilities, from leaf
+/// `0x0000_0010` subleaf 3.
+pub struct MemoryBandwidthAllocation {
+    /// Number of classes of service (COS).
+    pub cos_count: u32,
+
+    /// Maximum throttling value supported.
+    pub max_throttle: u16,
+
+    /// `true` if the response of the delay values is linear.
+    pub linear: bool,
+}
+
+impl fmt::Display for MemoryBandwidthAllocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} COS, max throttle {}", self.cos_count, self.max_throttle)?;
+        if self.linear {
+            write!(f, ", linear")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes the AMD Platform QoS Extended enforcement capabilities, from leaf
+/// `0x8000_0020`.
+pub struct AmdQosInfo {
+    /// `true` if L3 memory bandwidth enforcement (L3MBE) is supported.
+    pub l3_mbe: bool,
+
+    /// `true` if L3 slow memory bandwidth enforcement (L3SMBE) is supported.
+    pub l3_smbe: bool,
+
+    /// `true` if bandwidth monitoring event configuration (BMEC) is
+    /// supported.
+    pub bmec: bool,
+
+    /// L3 memory bandwidth enforcement limits, if reported.
+    pub l3_bandwidth_enforcement: Option<BandwidthEnforcement>,
+}
+
+impl fmt::Display for AmdQosInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names: Vec<&str> = [(self.l3_mbe, "L3MBE"), (self.l3_smbe, "L3SMBE"), (self.bmec, "BMEC")]
+            .iter()
+            .filter(|(supported, _)| *supported)
+            .map(|(_, name)| *name)
+            .collect();
+        if names.is_empty() {
+            write!(f, "none")?;
+        } else {
+            write!(f, "{}", names.join(", "))?;
+        }
+        if let Some(enforcement) = &self.l3_bandwidth_enforcement {
+            write!(f, " ({})", enforcement)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes the limits of AMD L3 memory bandwidth enforcement, from leaf
+/// `0x8000_0020` subleaf 1.
+pub struct BandwidthEnforcement {
+    /// Number of classes of service (COS).
+    pub cos_count: u32,
+
+    /// Size of the bandwidth specifier field, in bits.
+    pub bandwidth_length: u32,
+}
+
+impl fmt::Display for BandwidthEnforcement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} COS, {}-bit bandwidth", self.cos_count, self.bandwidth_length)
+    }
+}
+
+#[bitfield(bits = 32)]
+struct EdxMonitoring {
+    #[skip]
+    __: bool,
+    l3: bool,
+    #[skip]
+    __: B30,
+}
+
+#[bitfield(bits = 32)]
+struct EaxL3Monitoring {
+    counter_width_offset: B8,
+    #[skip]
+    __: B24,
+}
+
+#[bitfield(bits = 32)]
+struct EdxL3Monitoring {
+    occupancy: bool,
+    total_bandwidth: bool,
+    local_bandwidth: bool,
+    #[skip]
+    __: B29,
+}
+
+#[bitfield(bits = 32)]
+struct EbxAllocation {
+    #[skip]
+    __: bool,
+    l3: bool,
+    l2: bool,
+    memory_bandwidth: bool,
+    #[skip]
+    __: B28,
+}
+
+#[bitfield(bits = 32)]
+struct EaxCacheAllocation {
+    cbm_length: B5,
+    #[skip]
+    __: B27,
+}
+
+#[bitfield(bits = 32)]
+struct EcxCacheAllocation {
+    #[skip]
+    __: B2,
+    cdp: bool,
+    noncontiguous_cbm: bool,
+    #[skip]
+    __: B28,
+}
+
+#[bitfield(bits = 32)]
+struct EaxMemoryBandwidth {
+    max_throttle: B12,
+    #[skip]
+    __: B20,
+}
+
+#[bitfield(bits = 32)]
+struct EcxMemoryBandwidth {
+    #[skip]
+    __: B2,
+    linear: bool,
+    #[skip]
+    __: B29,
+}
+
+#[bitfield(bits = 32)]
+struct EdxCos {
+    highest_cos: B16,
+    #[skip]
+    __: B16,
+}
+
+#[bitfield(bits = 32)]
+struct EbxAmdQos {
+    #[skip]
+    __: bool,
+    l3_mbe: bool,
+    l3_smbe: bool,
+    bmec: bool,
+    #[skip]
+    __: B28,
+}
+
+fn describe_cache_allocation(cpu: &Processor, subleaf: u32) -> Option<CacheAllocation> {
+    let raw = cpu.get_subleaf(0x0000_0010, subleaf)?;
+    let eax = EaxCacheAllocation::from_bytes(raw.output.eax.to_le_bytes());
+    let ecx = EcxCacheAllocation::from_bytes(raw.output.ecx.to_le_bytes());
+    let edx = EdxCos::from_bytes(raw.output.edx.to_le_bytes());
+    if raw.output.eax == 0 && raw.output.edx == 0 {
+        return None;
+    }
+    Some(CacheAllocation {
+        cos_count: edx.highest_cos() as u32 + 1,
+        cbm_length: eax.cbm_length() + 1,
+        shareable_bitmap: raw.output.ebx,
+        cdp: ecx.cdp(),
+        noncontiguous_cbm: ecx.noncontiguous_cbm(),
+    })
+}
+
+pub(crate) fn describe_rdt(cpu: &Processor, vendor: VendorMask) -> Option<RdtInfo> {
+    if !vendor.intersects(VendorMask::INTELAMD) {
+        return None;
+    }
+
+    // Leaf 0x0000_0007 EBX bit 12 indicates RDT monitoring support, and bit 15
+    // indicates RDT allocation support.
+    let monitoring = cpu.has_feature_bit(0x0000_0007, 0, RegisterName::EBX, 12);
+    let allocation = cpu.has_feature_bit(0x0000_0007, 0, RegisterName::EBX, 15);
+    if !monitoring && !allocation {
+        return None;
+    }
+
+    let mut info = RdtInfo::default();
+
+    if monitoring {
+        if let Some(raw) = cpu.get_subleaf(0x0000_000F, 0) {
+            info.max_rmid = raw.output.ebx;
+            let edx = EdxMonitoring::from_bytes(raw.output.edx.to_le_bytes());
+            if edx.l3() {
+                if let Some(raw) = cpu.get_subleaf(0x0000_000F, 1) {
+                    let eax = EaxL3Monitoring::from_bytes(raw.output.eax.to_le_bytes());
+                    let edx = EdxL3Monitoring::from_bytes(raw.output.edx.to_le_bytes());
+                    info.l3_monitoring = Some(RdtMonitoring {
+                        max_rmid: raw.output.ecx,
+                        upscaling_factor: raw.output.ebx,
+                        counter_width: eax.counter_width_offset().saturating_add(24),
+                        occupancy: edx.occupancy(),
+                        total_bandwidth: edx.total_bandwidth(),
+                        local_bandwidth: edx.local_bandwidth(),
+                    });
+                }
+            }
+        }
+    }
+
+    if allocation {
+        if let Some(raw) = cpu.get_subleaf(0x0000_0010, 0) {
+            let ebx = EbxAllocation::from_bytes(raw.output.ebx.to_le_bytes());
+            if ebx.l3() {
+                info.l3_allocation = describe_cache_allocation(cpu, 1);
+            }
+            if ebx.l2() {
+                info.l2_allocation = describe_cache_allocation(cpu, 2);
+            }
+            if ebx.memory_bandwidth() {
+                if let Some(raw) = cpu.get_subleaf(0x0000_0010, 3) {
+                    let eax = EaxMemoryBandwidth::from_bytes(raw.output.eax.to_le_bytes());
+                    let ecx = EcxMemoryBandwidth::from_bytes(raw.output.ecx.to_le_bytes());
+                    let edx = EdxCos::from_bytes(raw.output.edx.to_le_bytes());
+                    info.memory_bandwidth_allocation = Some(MemoryBandwidthAllocation {
+                        cos_count: edx.highest_cos() as u32 + 1,
+                        max_throttle: eax.max_throttle() + 1,
+                        linear: ecx.linear(),
+                    });
+                }
+            }
+        }
+    }
+
+    debug!("describe_rdt() found {:?}", info);
+
+    Some(info)
+}
+
+pub(crate) fn describe_amd_qos(cpu: &Processor, vendor: VendorMask) -> Option<AmdQosInfo> {
+    if !vendor.contains(VendorMask::AMD) {
+        return None;
+    }
+
+    // Leaf 0x0000_0007 EBX bit 15 indicates Platform QoS Enforcement support.
+    if !cpu.has_feature_bit(0x0000_0007, 0, RegisterName::EBX, 15) {
+        return None;
+    }
+
+    let raw = cpu.get_subleaf(0x8000_0020, 0)?;
+    let ebx = EbxAmdQos::from_bytes(raw.output.ebx.to_le_bytes());
+    let mut info = AmdQosInfo {
+        l3_mbe: ebx.l3_mbe(),
+        l3_smbe: ebx.l3_smbe(),
+        bmec: ebx.bmec(),
+        l3_bandwidth_enforcement: None,
+    };
+
+    if info.l3_mbe {
+        if let Some(raw) = cpu.get_subleaf(0x8000_0020, 1) {
+            if raw.output.eax != 0 || raw.output.edx != 0 {
+                info.l3_bandwidth_enforcement = Some(BandwidthEnforcement {
+                    cos_count: raw.output.edx.saturating_add(1),
+                    bandwidth_length: raw.output.eax,
+                });
+            }
+        }
+    }
+
+    debug!("describe_amd_qos() found {:?}", info);
+
+    Some(info)
+}
@@ -0,0 +1,160 @@
+#![allow(dead_code)]
+
+use core::fmt;
+use log::*;
+use modular_bitfield::prelude::*;
+
+use crate::cpuid::{Processor, RegisterName, VendorMask};
+use crate::internal::prelude::*;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes AMD's Secure Memory Encryption (SME) and Secure Encrypted
+/// Virtualization (SEV) capabilities, from leaf `0x8000_001F`.
+pub struct SevInfo {
+    /// `true` if Secure Memory Encryption is supported.
+    pub sme: bool,
+
+    /// `true` if Secure Encrypted Virtualization is supported.
+    pub sev: bool,
+
+    /// `true` if SEV Encrypted State is supported.
+    pub sev_es: bool,
+
+    /// `true` if SEV Secure Nested Paging is supported.
+    pub sev_snp: bool,
+
+    /// Page table bit position used to indicate encryption (the "C-bit").
+    pub cbit_position: u8,
+
+    /// Reduction of physical address space, in bits, when memory encryption
+    /// is enabled.
+    pub phys_addr_reduction: u8,
+
+    /// Number of encrypted guests supported simultaneously.
+    pub encrypted_guests: u32,
+
+    /// Minimum ASID value for an SEV-enabled, SEV-ES-disabled guest.
+    pub min_sev_asid: u32,
+}
+
+impl fmt::Display for SevInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut names: Vec<&str> = vec![];
+        if self.sme {
+            names.push("SME");
+        }
+        if self.sev {
+            names.push("SEV");
+        }
+        if self.sev_es {
+            names.push("SEV-ES");
+        }
+        if self.sev_snp {
+            names.push("SEV-SNP");
+        }
+        write!(
+            f,
+            "{}, C-bit {}, {}-bit address reduction, {} encrypted guests",
+            names.join(", "),
+            self.cbit_position,
+            self.phys_addr_reduction,
+            self.encrypted_guests
+        )
+    }
+}
+
+#[bitfield(bits = 32)]
+struct EaxSev {
+    sme: bool,
+    sev: bool,
+    page_flush_msr: bool,
+    sev_es: bool,
+    sev_snp: bool,
+    #[skip]
+    __: B27,
+}
+
+#[bitfield(bits = 32)]
+struct EbxSev {
+    cbit_position: B6,
+    phys_addr_reduction: B6,
+    #[skip]
+    __: B20,
+}
+
+pub(crate) fn describe_sev(cpu: &Processor, vendor: VendorMask) -> Option<SevInfo> {
+    if !vendor.intersects(VendorMask::AMD | VendorMask::HYGON) {
+        return None;
+    }
+    if let Some(raw) = cpu.get_subleaf(0x8000_001F, 0) {
+        let eax = EaxSev::from_bytes(raw.output.eax.to_le_bytes());
+        let ebx = EbxSev::from_bytes(raw.output.ebx.to_le_bytes());
+        if !eax.sme() && !eax.sev() {
+            return None;
+        }
+        let info = SevInfo {
+            sme: eax.sme(),
+            sev: eax.sev(),
+            sev_es: eax.sev_es(),
+            sev_snp: eax.sev_snp(),
+            cbit_position: ebx.cbit_position(),
+            phys_addr_reduction: ebx.phys_addr_reduction(),
+            encrypted_guests: raw.output.ecx,
+            min_sev_asid: raw.output.edx,
+        };
+        debug!("describe_sev() found {:?}", info);
+        Some(info)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes the target types supported by the `PCONFIG` instruction, from
+/// leaf `0x0000_001B`.
+pub struct PconfigInfo {
+    /// Supported target identifiers, in the order they are enumerated. `1` is
+    /// Multi-Key Total Memory Encryption (MKTME).
+    pub targets: Vec<u32>,
+}
+
+impl fmt::Display for PconfigInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names: Vec<String> = self
+            .targets
+            .iter()
+            .map(|target| match target {
+                1 => "MKTME".to_string(),
+                _ => format!("unknown ({})", target),
+            })
+            .collect();
+        write!(f, "{}", names.join(", "))
+    }
+}
+
+pub(crate) fn describe_pconfig(cpu: &Processor, vendor: VendorMask) -> Option<PconfigInfo> {
+    if !vendor.contains(VendorMask::INTEL) {
+        return None;
+    }
+
+    // Leaf 0x0000_0007 EDX bit 18 indicates PCONFIG support.
+    if !cpu.has_feature_bit(0x0000_0007, 0, RegisterName::EDX, 18) {
+        return None;
+    }
+
+    let mut info = PconfigInfo::default();
+    for raw in cpu.iter_leaves().filter(|leaf| leaf.input.eax == 0x0000_001B) {
+        // Subleaf type 1 lists target identifiers in EBX, ECX and EDX. Any
+        // other type, including 0, carries no targets.
+        if raw.output.eax & 0xFFF != 1 {
+            continue;
+        }
+        for target in [raw.output.ebx, raw.output.ecx, raw.output.edx].iter() {
+            if *target != 0 {
+                info.targets.push(*target);
+            }
+        }
+    }
+    debug!("describe_pconfig() found {:?}", info);
+    Some(info)
+}
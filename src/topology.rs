@@ -1,10 +1,12 @@
 #![allow(dead_code)]
 
+use core::fmt;
 use log::*;
 use modular_bitfield::prelude::*;
-use std::fmt;
 
-use crate::cpuid::{Processor, System};
+use crate::cache::{CacheDescription, CacheLevel};
+use crate::cpuid::{Processor, RegisterName, System, VendorMask};
+use crate::internal::prelude::*;
 
 #[derive(Debug, Clone)]
 pub struct TopologyProp {
@@ -28,6 +30,18 @@ impl TopologyProp {
 #[derive(Debug, Clone)]
 pub struct TopologyProps {
     pub socket: TopologyProp,
+
+    /// Die level from leaf `0x0000_001F`, whose total is the number of dies
+    /// per socket.
+    pub die: TopologyProp,
+
+    /// Module level from leaf `0x0000_001F`, whose total is the number of
+    /// modules per die, or per socket without a die level.
+    pub module: TopologyProp,
+
+    /// Core level, whose total is the number of cores per socket. The core
+    /// mask spans the die and module levels so that core IDs are unique
+    /// within a socket.
     pub core: TopologyProp,
     pub thread: TopologyProp,
 }
@@ -36,6 +50,8 @@ impl TopologyProps {
     pub fn new() -> TopologyProps {
         TopologyProps {
             socket: TopologyProp::new(),
+            die: TopologyProp::new(),
+            module: TopologyProp::new(),
             core: TopologyProp::new(),
             thread: TopologyProp::new(),
         }
@@ -45,6 +61,16 @@ impl TopologyProps {
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct TopologyInferred {
     pub sockets: u32,
+
+    /// Number of dies per socket, if reported.
+    pub dies_per_socket: Option<u16>,
+
+    /// Number of modules per die, or per socket without a die level, if
+    /// reported.
+    pub modules_per_die: Option<u16>,
+
+    /// Number of cores per socket, or per die or module when those levels are
+    /// reported.
     pub cores_per_socket: u16,
     pub threads_per_core: u8,
 }
@@ -53,30 +79,62 @@ impl TopologyInferred {
     pub fn new() -> TopologyInferred {
         TopologyInferred {
             sockets: 0,
+            dies_per_socket: None,
+            modules_per_die: None,
             cores_per_socket: 0,
             threads_per_core: 0,
         }
     }
 
     pub fn valid(&self) -> bool {
-        self.sockets != 0 && self.cores_per_socket != 0 && self.threads_per_core != 0
+        self.sockets != 0
+            && self.dies_per_socket != Some(0)
+            && self.modules_per_die != Some(0)
+            && self.cores_per_socket != 0
+            && self.threads_per_core != 0
+    }
+
+    /// Total number of logical CPUs, i.e. the product of all the reported
+    /// topology levels.
+    pub fn total(&self) -> u64 {
+        self.sockets as u64
+            * self.dies_per_socket.unwrap_or(1) as u64
+            * self.modules_per_die.unwrap_or(1) as u64
+            * self.cores_per_socket as u64
+            * self.threads_per_core as u64
     }
 }
 
 impl fmt::Display for TopologyInferred {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.dies_per_socket.is_none() && self.modules_per_die.is_none() {
+            return write!(
+                f,
+                "{} logical CPUs ({} sockets, {} cores per socket, {} threads per core)",
+                self.total(),
+                self.sockets,
+                self.cores_per_socket,
+                self.threads_per_core
+            );
+        }
+        write!(f, "{} sockets", self.sockets)?;
+        if let Some(dies) = self.dies_per_socket {
+            write!(f, " × {} dies", dies)?;
+        }
+        if let Some(modules) = self.modules_per_die {
+            write!(f, " × {} modules", modules)?;
+        }
         write!(
             f,
-            "{} logical CPUs ({} sockets, {} cores per socket, {} threads per core)",
-            self.sockets * self.cores_per_socket as u32 * self.threads_per_core as u32,
-            self.sockets,
+            " × {} cores × {} threads = {} CPUs",
             self.cores_per_socket,
-            self.threads_per_core
+            self.threads_per_core,
+            self.total()
         )
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct TopologyID {
     pub socket: u32,
     pub core: u32,
@@ -101,7 +159,99 @@ impl fmt::Display for TopologyID {
     }
 }
 
-fn describe_topology_cpu(state: &System, cpu: &Processor) -> Option<(TopologyProps, TopologyInferred)> {
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+/// Describes the type of core a logical CPU belongs to on hybrid processors,
+/// as reported by leaf `0x0000_001A`.
+pub enum CoreType {
+    Unknown = 0x00,
+    Atom    = 0x20,
+    Core    = 0x40,
+}
+
+impl fmt::Display for CoreType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                CoreType::Unknown => "unknown core type",
+                CoreType::Atom => "Atom (E-core)",
+                CoreType::Core => "Core (P-core)",
+            }
+        )
+    }
+}
+
+#[bitfield(bits = 32)]
+struct EaxHybrid {
+    native_model_id: B24,
+    core_type: B8,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// Describes a logical CPU on a hybrid processor, as reported by leaf
+/// `0x0000_001A`.
+pub struct HybridInfo {
+    /// Type of core this logical CPU belongs to.
+    pub core_type: CoreType,
+
+    /// Native model ID of the core, which identifies the core's
+    /// microarchitecture independently of the processor signature. Can be used
+    /// to tell apart different generations of the same core type.
+    pub native_model_id: u32,
+}
+
+impl fmt::Display for HybridInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}, native model ID {:#08x}",
+            self.core_type, self.native_model_id
+        )
+    }
+}
+
+pub(crate) fn describe_hybrid_info(cpu: &Processor) -> Option<HybridInfo> {
+    match cpu.get_subleaf(0x0000_001A, 0) {
+        Some(raw) if raw.output.eax != 0 => {
+            let eax = EaxHybrid::from_bytes(raw.output.eax.to_le_bytes());
+            let core_type = match eax.core_type() {
+                0x20 => CoreType::Atom,
+                0x40 => CoreType::Core,
+                _ => CoreType::Unknown,
+            };
+            Some(HybridInfo {
+                core_type: core_type,
+                native_model_id: eax.native_model_id(),
+            })
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn describe_core_type(cpu: &Processor) -> Option<CoreType> {
+    if let Some(raw) = cpu.get_subleaf(0x0000_001A, 0) {
+        let eax = EaxHybrid::from_bytes(raw.output.eax.to_le_bytes());
+        let core_type = match eax.core_type() {
+            0x00 => return None,
+            0x20 => CoreType::Atom,
+            0x40 => CoreType::Core,
+            _ => CoreType::Unknown,
+        };
+        debug!("describe_core_type() found {:?} for CPU {}", core_type, cpu.index);
+        Some(core_type)
+    } else {
+        None
+    }
+}
+
+/// Decodes the masks and shifts which split `cpu`'s x2APIC ID into socket,
+/// core and thread IDs, along with the number of logical processors at each
+/// level, from leaf `0x0000_001F` if supported, or else leaf `0x0000_000B`.
+/// The die and module levels are only reported by leaf `0x0000_001F`; tile
+/// and die group levels are folded into the modules and dies.
+pub(crate) fn describe_topology_props(cpu: &Processor) -> Option<TopologyProps> {
     #[bitfield(bits = 32)]
     struct EaxX2Apic {
         shift: B5,
@@ -129,7 +279,13 @@ fn describe_topology_cpu(state: &System, cpu: &Processor) -> Option<(TopologyPro
         x2apic_id: u32,
     }
 
-    if let Some(feature_check) = cpu.get_subleaf(0x0000_000B, 0) {
+    // Leaf 0x0000_001F reads as all zeroes when it is within range but
+    // unsupported.
+    let leaf_id = match cpu.get_subleaf(0x0000_001F, 0) {
+        Some(leaf) if leaf.output.ebx != 0 => 0x0000_001F,
+        _ => 0x0000_000B,
+    };
+    if let Some(feature_check) = cpu.get_subleaf(leaf_id, 0) {
         if feature_check.output.eax == 0 && feature_check.output.ebx == 0 {
             return None;
         }
@@ -142,7 +298,13 @@ fn describe_topology_cpu(state: &System, cpu: &Processor) -> Option<(TopologyPro
     x2apic.socket.reported = true;
     x2apic.socket.mask = 0xFFFF_FFFF;
 
-    for leaf in cpu.get(0x0000_000B).iter() {
+    // Logical processors and shift of the level below the current one, and
+    // of the domains the die and module levels are made of.
+    let mut below: (u16, u8) = (1, 0);
+    let mut per_die: Option<(u16, u8)> = None;
+    let mut per_module: Option<(u16, u8)> = None;
+
+    for leaf in cpu.get(leaf_id).iter() {
         debug!("Leaf {:x?}", leaf);
         if leaf.output.eax == 0 && leaf.output.ebx == 0 {
             continue;
@@ -172,10 +334,45 @@ fn describe_topology_cpu(state: &System, cpu: &Processor) -> Option<(TopologyPro
                 x2apic.socket.mask = 0xFFFF_FFFF ^ x2apic.core.mask;
             }
 
+            // Module, tile, die and die group levels
+            3..=6 if leaf_id == 0x0000_001F && x2apic.core.reported => {
+                match ecx.leveltype() {
+                    3 => per_module = Some(below),
+                    5 => per_die = Some(below),
+                    _ => {}
+                }
+                x2apic.core.total = ebx.count();
+                x2apic.core.mask = !(0xFFFF_FFFF << eax.shift());
+
+                x2apic.socket.shift = eax.shift();
+                x2apic.socket.mask = 0xFFFF_FFFF ^ x2apic.core.mask;
+            }
+
             _ => {
                 break;
             }
         }
+        below = (ebx.count(), eax.shift());
+    }
+
+    // The last level reports the logical processors per socket.
+    let per_socket = (x2apic.core.total, x2apic.socket.shift);
+    if let Some((dies, shift)) = per_die {
+        if dies != 0 && per_socket.0 % dies == 0 {
+            x2apic.die.total = per_socket.0 / dies;
+            x2apic.die.shift = shift;
+            x2apic.die.mask = !(0xFFFF_FFFF << per_socket.1) ^ !(0xFFFF_FFFF << shift);
+            x2apic.die.reported = true;
+        }
+    }
+    if let Some((modules, shift)) = per_module {
+        let per_die = per_die.unwrap_or(per_socket);
+        if modules != 0 && per_die.0 % modules == 0 {
+            x2apic.module.total = per_die.0 / modules;
+            x2apic.module.shift = shift;
+            x2apic.module.mask = !(0xFFFF_FFFF << per_die.1) ^ !(0xFFFF_FFFF << shift);
+            x2apic.module.reported = true;
+        }
     }
 
     if x2apic.thread.reported && x2apic.core.reported {
@@ -196,6 +393,8 @@ fn describe_topology_cpu(state: &System, cpu: &Processor) -> Option<(TopologyPro
     }
 
     debug!("Socket {:x?}", x2apic.socket);
+    debug!("Die {:x?}", x2apic.die);
+    debug!("Module {:x?}", x2apic.module);
     debug!("Core {:x?}", x2apic.core);
     debug!("Thread {:x?}", x2apic.thread);
 
@@ -207,17 +406,189 @@ fn describe_topology_cpu(state: &System, cpu: &Processor) -> Option<(TopologyPro
         x2apic.core.total /= x2apic.thread.total;
     }
 
+    Some(x2apic)
+}
+
+fn describe_topology_cpu(state: &System, cpu: &Processor) -> Option<(TopologyProps, TopologyInferred)> {
+    let x2apic = describe_topology_props(cpu)?;
+
     let mut inferred: TopologyInferred = TopologyInferred::new();
     inferred.sockets = state.cpu_count as u32 / (x2apic.core.total as u32 * x2apic.thread.total as u32);
     inferred.cores_per_socket = x2apic.core.total;
     inferred.threads_per_core = x2apic.thread.total as u8;
+    if x2apic.die.reported {
+        inferred.dies_per_socket = Some(x2apic.die.total);
+        inferred.cores_per_socket /= x2apic.die.total;
+    }
+    if x2apic.module.reported {
+        inferred.modules_per_die = Some(x2apic.module.total);
+        inferred.cores_per_socket /= x2apic.module.total;
+    }
 
     Some((x2apic, inferred))
 }
 
+/// Estimates the topology from the number of logical CPUs sharing each cache,
+/// for processors without the x2APIC topology leaves. The lowest level cache is
+/// assumed to be private to a core and the highest level cache to be shared by
+/// a whole socket. The sharing counts are maximums, so on processors with
+/// several last level caches per socket this overestimates the socket count.
+fn describe_topology_caches(system: &System) -> Option<TopologyInferred> {
+    let caches: Vec<&CacheDescription> = system
+        .caches
+        .iter()
+        .filter(|cache| {
+            !cache.cachetype.is_tlb() && cache.level != CacheLevel::Unknown && cache.max_threads_sharing > 0
+        })
+        .collect();
+    let threads_per_core = caches.iter().min_by_key(|cache| cache.level)?.max_threads_sharing;
+    let threads_per_socket = caches.iter().max_by_key(|cache| cache.level)?.max_threads_sharing;
+    if threads_per_core > u8::MAX as u16 || threads_per_socket % threads_per_core != 0 {
+        return None;
+    }
+
+    let mut inferred: TopologyInferred = TopologyInferred::new();
+    inferred.sockets = system.cpu_count as u32 / threads_per_socket as u32;
+    inferred.cores_per_socket = threads_per_socket / threads_per_core;
+    inferred.threads_per_core = threads_per_core as u8;
+    debug!("describe_topology_caches() inferred {:?}", inferred);
+
+    Some(inferred)
+}
+
 pub(crate) fn describe_topology(system: &mut System) {
-    if let Some((topo_props, topo)) = describe_topology_cpu(system, &system.cpus[0]) {
+    let cpu = match system.cpus.first() {
+        Some(cpu) => cpu,
+        None => return,
+    };
+    if let Some((topo_props, topo)) = describe_topology_cpu(system, cpu) {
         system.topology = topo;
         system.topology_props = topo_props;
+    } else if let Some(topo) = describe_topology_caches(system) {
+        system.topology = topo;
+    }
+}
+
+/// Tests if the processors can run more than one thread per core, whether or
+/// not SMT is currently enabled. Firmware which disables SMT usually leaves
+/// the thread level of the x2APIC topology leaf 4 bits wide, so that is
+/// checked first. Otherwise falls back to comparing the maximum number of
+/// logical processors per package from leaf `0x0000_0001` (only valid when HTT
+/// is set) against the number of cores per package from leaf `0x0000_0004`,
+/// which is only defined by Intel.
+pub(crate) fn describe_smt_capable(system: &System) -> bool {
+    if system.topology.valid() && system.topology.threads_per_core > 1 {
+        return true;
+    }
+    if system.topology_props.thread.reported {
+        return system.topology_props.thread.mask != 0;
+    }
+    let cpu = match system.cpus.first() {
+        Some(cpu) => cpu,
+        None => return false,
+    };
+    let htt = cpu.has_feature_bit(0x0000_0001, 0, RegisterName::EDX, 28);
+    if !htt || !system.vendor.contains(VendorMask::INTEL) {
+        return false;
+    }
+    let logical = match cpu.get_subleaf(0x0000_0001, 0) {
+        Some(raw) => (raw.output.ebx >> 16) & 0xFF,
+        None => return false,
+    };
+    let cores = match cpu.get_subleaf(0x0000_0004, 0) {
+        Some(raw) if raw.output.eax & 0x1F != 0 => (raw.output.eax >> 26) + 1,
+        _ => 1,
+    };
+    debug!(
+        "describe_smt_capable() found {} logical processors and {} cores per package",
+        logical, cores
+    );
+    logical > cores
+}
+
+/// Counts the distinct package (socket) IDs across all the processors in the
+/// system. Only meaningful once each processor's topology has been decoded,
+/// and only when more than one processor was enumerated.
+pub(crate) fn count_sockets(system: &System) -> Option<u32> {
+    if system.cpus.len() < 2 {
+        return None;
+    }
+    let mut sockets: Vec<u32> = vec![];
+    for cpu in system.cpus.iter() {
+        match cpu.topology() {
+            Some(id) => sockets.push(id.socket),
+            None => return None,
+        }
+    }
+    sockets.sort_unstable();
+    sockets.dedup();
+    debug!("count_sockets() found {} distinct package IDs", sockets.len());
+    Some(sockets.len() as u32)
+}
+
+/// Topology IDs of every processor which has them, as
+/// `(socket, core, thread, index, core type)`, sorted by topology ID. Empty if
+/// the IDs weren't decoded from the x2APIC topology leaves.
+fn sorted_topology_ids(system: &System) -> Vec<(u32, u32, u32, u32, Option<CoreType>)> {
+    if !system.topology_props.socket.reported {
+        return vec![];
+    }
+    let mut ids: Vec<(u32, u32, u32, u32, Option<CoreType>)> = system
+        .cpus
+        .iter()
+        .filter_map(|cpu| {
+            cpu.topology()
+                .as_ref()
+                .map(|id| (id.socket, id.core, id.thread, cpu.index, cpu.core_type()))
+        })
+        .collect();
+    ids.sort();
+    ids
+}
+
+pub(crate) fn render_topology_tree(system: &System) -> String {
+    let mut output = String::new();
+    let mut last: Option<(u32, u32)> = None;
+    for (socket, core, thread, index, core_type) in sorted_topology_ids(system).iter() {
+        if last.map(|(last_socket, _)| last_socket) != Some(*socket) {
+            output.push_str(&format!("Socket {}\n", socket));
+        }
+        if last != Some((*socket, *core)) {
+            match core_type {
+                Some(core_type) => output.push_str(&format!("  Core {}: {}\n", core, core_type)),
+                None => output.push_str(&format!("  Core {}\n", core)),
+            }
+        }
+        output.push_str(&format!("    Thread {}: CPU {}\n", thread, index));
+        last = Some((*socket, *core));
+    }
+    output
+}
+
+pub(crate) fn render_topology_dot(system: &System) -> String {
+    let mut output = String::from("graph topology {\n");
+    let mut last: Option<(u32, u32)> = None;
+    for (socket, core, thread, index, core_type) in sorted_topology_ids(system).iter() {
+        let socket_node = format!("socket{}", socket);
+        let core_node = format!("socket{}_core{}", socket, core);
+        if last.map(|(last_socket, _)| last_socket) != Some(*socket) {
+            output.push_str(&format!("    {} [label=\"Socket {}\"];\n", socket_node, socket));
+        }
+        if last != Some((*socket, *core)) {
+            let label = match core_type {
+                Some(core_type) => format!("Core {}\\n{}", core, core_type),
+                None => format!("Core {}", core),
+            };
+            output.push_str(&format!("    {} [label=\"{}\"];\n", core_node, label));
+            output.push_str(&format!("    {} -- {};\n", socket_node, core_node));
+        }
+        output.push_str(&format!(
+            "    cpu{} [label=\"Thread {}\\nCPU {}\"];\n",
+            index, thread, index
+        ));
+        output.push_str(&format!("    {} -- cpu{};\n", core_node, index));
+        last = Some((*socket, *core));
     }
+    output.push_str("}\n");
+    output
 }
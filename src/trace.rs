@@ -0,0 +1,143 @@
+#![allow(dead_code)]
+
+use log::*;
+use modular_bitfield::prelude::*;
+
+use crate::cpuid::{Processor, RegisterName, VendorMask};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes Intel Processor Trace capabilities, from leaf `0x0000_0014`.
+pub struct ProcessorTraceInfo {
+    /// `true` if CR3 filtering is supported.
+    pub cr3_filtering: bool,
+
+    /// `true` if configurable PSB and cycle-accurate mode are supported.
+    pub configurable_psb: bool,
+
+    /// `true` if IP filtering and TraceStop filtering are supported, and
+    /// filtering is preserved across warm reset.
+    pub ip_filtering: bool,
+
+    /// `true` if MTC timing packets are supported.
+    pub mtc: bool,
+
+    /// `true` if the PTWRITE instruction is supported.
+    pub ptwrite: bool,
+
+    /// `true` if Power Event Trace is supported.
+    pub power_event_trace: bool,
+
+    /// `true` if PSB and PMI preservation MSRs are supported.
+    pub psb_pmi_preservation: bool,
+
+    /// `true` if the ToPA output scheme is supported.
+    pub topa: bool,
+
+    /// `true` if ToPA tables can hold any number of output entries.
+    pub topa_multiple_entries: bool,
+
+    /// `true` if the single-range output scheme is supported.
+    pub single_range_output: bool,
+
+    /// `true` if output to the Trace Transport subsystem is supported.
+    pub trace_transport: bool,
+
+    /// `true` if IP payloads contain linear addresses (LIP) rather than
+    /// effective addresses.
+    pub lip: bool,
+
+    /// Number of configurable address ranges for filtering.
+    pub address_ranges: u8,
+
+    /// Bitmap of supported MTC period encodings.
+    pub mtc_period_encodings: u16,
+
+    /// Bitmap of supported cycle threshold encodings.
+    pub cycle_threshold_encodings: u16,
+
+    /// Bitmap of supported configurable PSB frequency encodings.
+    pub psb_frequency_encodings: u16,
+}
+
+#[bitfield(bits = 32)]
+struct EbxTrace {
+    cr3_filtering: bool,
+    configurable_psb: bool,
+    ip_filtering: bool,
+    mtc: bool,
+    ptwrite: bool,
+    power_event_trace: bool,
+    psb_pmi_preservation: bool,
+    #[skip]
+    __: B25,
+}
+
+#[bitfield(bits = 32)]
+struct EcxTrace {
+    topa: bool,
+    topa_multiple_entries: bool,
+    single_range_output: bool,
+    trace_transport: bool,
+    #[skip]
+    __: B27,
+    lip: bool,
+}
+
+#[bitfield(bits = 32)]
+struct EaxTraceRanges {
+    address_ranges: B3,
+    #[skip]
+    __: B13,
+    mtc_period_encodings: B16,
+}
+
+#[bitfield(bits = 32)]
+struct EbxTraceEncodings {
+    cycle_threshold_encodings: B16,
+    psb_frequency_encodings: B16,
+}
+
+pub(crate) fn describe_processor_trace(cpu: &Processor, vendor: VendorMask) -> Option<ProcessorTraceInfo> {
+    if !vendor.contains(VendorMask::INTEL) {
+        return None;
+    }
+
+    // Leaf 0x0000_0007 EBX bit 25 indicates Intel PT support.
+    if !cpu.has_feature_bit(0x0000_0007, 0, RegisterName::EBX, 25) {
+        return None;
+    }
+
+    let mut info = ProcessorTraceInfo::default();
+
+    if let Some(raw) = cpu.get_subleaf(0x0000_0014, 0) {
+        let ebx = EbxTrace::from_bytes(raw.output.ebx.to_le_bytes());
+        let ecx = EcxTrace::from_bytes(raw.output.ecx.to_le_bytes());
+        info.cr3_filtering = ebx.cr3_filtering();
+        info.configurable_psb = ebx.configurable_psb();
+        info.ip_filtering = ebx.ip_filtering();
+        info.mtc = ebx.mtc();
+        info.ptwrite = ebx.ptwrite();
+        info.power_event_trace = ebx.power_event_trace();
+        info.psb_pmi_preservation = ebx.psb_pmi_preservation();
+        info.topa = ecx.topa();
+        info.topa_multiple_entries = ecx.topa_multiple_entries();
+        info.single_range_output = ecx.single_range_output();
+        info.trace_transport = ecx.trace_transport();
+        info.lip = ecx.lip();
+    } else {
+        return None;
+    }
+
+    if let Some(raw) = cpu.get_subleaf(0x0000_0014, 1) {
+        let eax = EaxTraceRanges::from_bytes(raw.output.eax.to_le_bytes());
+        let ebx = EbxTraceEncodings::from_bytes(raw.output.ebx.to_le_bytes());
+        info.address_ranges = eax.address_ranges();
+        info.mtc_period_encodings = eax.mtc_period_encodings();
+        info.cycle_threshold_encodings = ebx.cycle_threshold_encodings();
+        info.psb_frequency_encodings = ebx.psb_frequency_encodings();
+    }
+
+    debug!("describe_processor_trace() found {:?}", info);
+
+    Some(info)
+}
@@ -0,0 +1,199 @@
+use core::fmt;
+
+use crate::cpuid::{base_of, LeafID, Processor, RawCPUIDResponse, RegisterName, System, CPUID_BASES};
+use crate::internal::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Describes a suspicious property of the raw CPUID data of a processor, as
+/// returned by [System::validate](../cpuid/struct.System.html#method.validate).
+pub enum ValidationWarning {
+    /// A leaf above the maximum leaf advertised by its base was answered.
+    LeafAboveMax {
+        /// Logical index of the processor.
+        cpu: u32,
+
+        /// The leaf which is out of range.
+        leaf: LeafID,
+
+        /// Maximum leaf advertised by the base.
+        max_leaf: u32,
+    },
+
+    /// A leaf within the range advertised by its base is missing.
+    LeafMissing {
+        /// Logical index of the processor.
+        cpu: u32,
+
+        /// The missing leaf.
+        leaf: u32,
+
+        /// Maximum leaf advertised by the base.
+        max_leaf: u32,
+    },
+
+    /// The same leaf and subleaf was captured more than once.
+    DuplicateLeaf {
+        /// Logical index of the processor.
+        cpu: u32,
+
+        /// The duplicated leaf.
+        leaf: LeafID,
+    },
+
+    /// Bits documented as reserved are set in a register.
+    ReservedBitsSet {
+        /// Logical index of the processor.
+        cpu: u32,
+
+        /// The leaf containing the register.
+        leaf: LeafID,
+
+        /// The register with reserved bits set.
+        register: RegisterName,
+
+        /// The reserved bits which are set.
+        bits: u32,
+    },
+
+    /// A base advertises a valid maximum leaf, but every leaf in its range
+    /// reads as zero.
+    EmptyBase {
+        /// Logical index of the processor.
+        cpu: u32,
+
+        /// The base leaf, e.g. `0x4000_0000`.
+        base: u32,
+    },
+}
+
+impl fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationWarning::LeafAboveMax { cpu, leaf, max_leaf } => write!(
+                f,
+                "CPU {}: leaf {:08x}:{:02x} is above the advertised maximum leaf {:08x}",
+                cpu, leaf.eax, leaf.ecx, max_leaf
+            ),
+            ValidationWarning::LeafMissing { cpu, leaf, max_leaf } => write!(
+                f,
+                "CPU {}: leaf {:08x} is missing, but the advertised maximum leaf is {:08x}",
+                cpu, leaf, max_leaf
+            ),
+            ValidationWarning::DuplicateLeaf { cpu, leaf } => write!(
+                f,
+                "CPU {}: leaf {:08x}:{:02x} was captured more than once",
+                cpu, leaf.eax, leaf.ecx
+            ),
+            ValidationWarning::ReservedBitsSet {
+                cpu,
+                leaf,
+                register,
+                bits,
+            } => write!(
+                f,
+                "CPU {}: leaf {:08x}:{:02x} has reserved bits {:#010x} set in {}",
+                cpu, leaf.eax, leaf.ecx, bits, register
+            ),
+            ValidationWarning::EmptyBase { cpu, base } => write!(
+                f,
+                "CPU {}: base {:08x} advertises leaves, but they all read as zero",
+                cpu, base
+            ),
+        }
+    }
+}
+
+/// Reserved bits which should read as zero, as `(leaf, register, mask)`. The
+/// mask applies to every subleaf of the leaf.
+const RESERVED_BITS: [(u32, RegisterName, u32); 4] = [
+    // Bits 15:14 and 31:28 of the processor signature.
+    (0x0000_0001, RegisterName::EAX, 0xF000_C000),
+    // Bits 13:10 of the deterministic cache parameters.
+    (0x0000_0004, RegisterName::EAX, 0x0000_3C00),
+    // Bits 31:16 of the logical processor count and level type.
+    (0x0000_000B, RegisterName::EBX, 0xFFFF_0000),
+    (0x0000_000B, RegisterName::ECX, 0xFFFF_0000),
+];
+
+fn validate_processor(cpu: &Processor, warnings: &mut Vec<ValidationWarning>) {
+    let leaves: Vec<&RawCPUIDResponse> = cpu.iter_leaves().collect();
+    for (position, leaf) in leaves.iter().enumerate() {
+        // Only report each duplicated leaf once, on its second occurrence.
+        let earlier = leaves[..position]
+            .iter()
+            .filter(|other| other.input == leaf.input)
+            .count();
+        if earlier == 1 {
+            warnings.push(ValidationWarning::DuplicateLeaf {
+                cpu: cpu.index,
+                leaf: leaf.input.clone(),
+            });
+        }
+        if earlier > 0 {
+            continue;
+        }
+
+        for (reserved_leaf, register, mask) in RESERVED_BITS.iter() {
+            if leaf.input.eax != *reserved_leaf {
+                continue;
+            }
+            let bits = leaf.output.register(*register).unwrap_or(0) & *mask;
+            if bits != 0 {
+                warnings.push(ValidationWarning::ReservedBitsSet {
+                    cpu: cpu.index,
+                    leaf: leaf.input.clone(),
+                    register: *register,
+                    bits: bits,
+                });
+            }
+        }
+    }
+
+    for base in CPUID_BASES.iter() {
+        let max_leaf = match cpu.max_leaf(*base) {
+            Some(max_leaf) => max_leaf,
+            None => continue,
+        };
+        let in_base: Vec<_> = leaves
+            .iter()
+            .filter(|leaf| base_of(leaf.input.eax) == Some(*base))
+            .collect();
+
+        for leaf in in_base.iter().filter(|leaf| leaf.input.eax > max_leaf) {
+            warnings.push(ValidationWarning::LeafAboveMax {
+                cpu: cpu.index,
+                leaf: leaf.input.clone(),
+                max_leaf: max_leaf,
+            });
+        }
+        for leaf_id in (*base + 1)..=max_leaf {
+            if !in_base.iter().any(|leaf| leaf.input.eax == leaf_id) {
+                warnings.push(ValidationWarning::LeafMissing {
+                    cpu: cpu.index,
+                    leaf: leaf_id,
+                    max_leaf: max_leaf,
+                });
+            }
+        }
+
+        let empty = in_base.iter().all(|leaf| {
+            let output = &leaf.output;
+            let eax = if leaf.input.eax == *base { 0 } else { output.eax };
+            eax == 0 && output.ebx == 0 && output.ecx == 0 && output.edx == 0
+        });
+        if max_leaf > *base && empty {
+            warnings.push(ValidationWarning::EmptyBase {
+                cpu: cpu.index,
+                base: *base,
+            });
+        }
+    }
+}
+
+pub(crate) fn validate_system(system: &System) -> Vec<ValidationWarning> {
+    let mut warnings: Vec<ValidationWarning> = vec![];
+    for cpu in system.cpus.iter() {
+        validate_processor(cpu, &mut warnings);
+    }
+    warnings
+}
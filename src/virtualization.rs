@@ -0,0 +1,421 @@
+#![allow(dead_code)]
+
+use core::fmt;
+use log::*;
+use modular_bitfield::prelude::*;
+
+use crate::cpuid::{Processor, RegisterName, VendorMask};
+use crate::internal::prelude::*;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes AMD's Secure Virtual Machine (SVM) capabilities, from leaf
+/// `0x8000_000A`.
+pub struct SvmInfo {
+    /// SVM revision number.
+    pub revision: u8,
+
+    /// Number of address space identifiers (ASIDs) supported.
+    pub asids: u32,
+
+    /// `true` if nested paging is supported.
+    pub nested_paging: bool,
+
+    /// `true` if VMCB clean bits are supported.
+    pub vmcb_clean_bits: bool,
+
+    /// `true` if flushing the TLB by ASID is supported.
+    pub flush_by_asid: bool,
+
+    /// `true` if instruction decode assists are supported.
+    pub decode_assists: bool,
+}
+
+impl fmt::Display for SvmInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "revision {}, {} ASIDs", self.revision, self.asids)?;
+        if self.nested_paging {
+            write!(f, ", nested paging")?;
+        }
+        if self.vmcb_clean_bits {
+            write!(f, ", VMCB clean bits")?;
+        }
+        Ok(())
+    }
+}
+
+#[bitfield(bits = 32)]
+struct EaxSvm {
+    revision: B8,
+    #[skip]
+    __: B24,
+}
+
+#[bitfield(bits = 32)]
+struct EdxSvm {
+    nested_paging: bool,
+    lbr_virtualization: bool,
+    svm_lock: bool,
+    nrip_save: bool,
+    tsc_rate_msr: bool,
+    vmcb_clean_bits: bool,
+    flush_by_asid: bool,
+    decode_assists: bool,
+    #[skip]
+    __: B24,
+}
+
+pub(crate) fn describe_svm(cpu: &Processor, vendor: VendorMask) -> Option<SvmInfo> {
+    if !vendor.contains(VendorMask::AMD) {
+        return None;
+    }
+
+    // Leaf 0x8000_0001 ECX bit 2 indicates SVM support.
+    if !cpu.has_feature_bit(0x8000_0001, 0, RegisterName::ECX, 2) {
+        return None;
+    }
+
+    if let Some(raw) = cpu.get_subleaf(0x8000_000A, 0) {
+        let eax = EaxSvm::from_bytes(raw.output.eax.to_le_bytes());
+        let edx = EdxSvm::from_bytes(raw.output.edx.to_le_bytes());
+        let info = SvmInfo {
+            revision: eax.revision(),
+            asids: raw.output.ebx,
+            nested_paging: edx.nested_paging(),
+            vmcb_clean_bits: edx.vmcb_clean_bits(),
+            flush_by_asid: edx.flush_by_asid(),
+            decode_assists: edx.decode_assists(),
+        };
+        debug!("describe_svm() found {:?}", info);
+        Some(info)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes the Microsoft Hyper-V hypervisor interface, from leaves
+/// `0x4000_0001` through `0x4000_0006`.
+pub struct HyperVInfo {
+    /// Hypervisor interface signature, e.g. `Hv#1`.
+    pub interface_signature: String,
+
+    /// Hypervisor build number.
+    pub build: u32,
+
+    /// Hypervisor major version.
+    pub major_version: u16,
+
+    /// Hypervisor minor version.
+    pub minor_version: u16,
+
+    /// Bitmap of the partition privileges granted to this partition.
+    pub partition_privileges: u64,
+
+    /// Bitmap of the behaviors the hypervisor recommends the guest use.
+    pub recommendations: u32,
+
+    /// Recommended number of spinlock retries before notifying the
+    /// hypervisor. `0xFFFF_FFFF` means the guest should never notify.
+    pub spinlock_retries: u32,
+
+    /// Maximum number of virtual processors supported.
+    pub max_virtual_processors: u32,
+
+    /// Maximum number of logical processors supported.
+    pub max_logical_processors: u32,
+
+    /// Bitmap of the hardware features used by the hypervisor.
+    pub hardware_features: u32,
+}
+
+impl fmt::Display for HyperVInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}, version {}.{}.{}",
+            self.interface_signature, self.major_version, self.minor_version, self.build
+        )
+    }
+}
+
+pub(crate) fn describe_hyperv(cpu: &Processor, vendor: VendorMask) -> Option<HyperVInfo> {
+    if !vendor.contains(VendorMask::HYPERV) {
+        return None;
+    }
+
+    let mut info = HyperVInfo::default();
+
+    if let Some(raw) = cpu.get_subleaf(0x4000_0001, 0) {
+        info.interface_signature = raw
+            .output
+            .eax
+            .to_le_bytes()
+            .iter()
+            .filter(|byte| byte.is_ascii_graphic())
+            .map(|byte| *byte as char)
+            .collect();
+    } else {
+        return None;
+    }
+    if let Some(raw) = cpu.get_subleaf(0x4000_0002, 0) {
+        info.build = raw.output.eax;
+        info.major_version = (raw.output.ebx >> 16) as u16;
+        info.minor_version = (raw.output.ebx & 0xFFFF) as u16;
+    }
+    if let Some(raw) = cpu.get_subleaf(0x4000_0003, 0) {
+        info.partition_privileges = ((raw.output.ebx as u64) << 32) | raw.output.eax as u64;
+    }
+    if let Some(raw) = cpu.get_subleaf(0x4000_0004, 0) {
+        info.recommendations = raw.output.eax;
+        info.spinlock_retries = raw.output.ebx;
+    }
+    if let Some(raw) = cpu.get_subleaf(0x4000_0005, 0) {
+        info.max_virtual_processors = raw.output.eax;
+        info.max_logical_processors = raw.output.ebx;
+    }
+    if let Some(raw) = cpu.get_subleaf(0x4000_0006, 0) {
+        info.hardware_features = raw.output.eax;
+    }
+
+    debug!("describe_hyperv() found {:?}", info);
+
+    Some(info)
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes the paravirtualization features exposed by the KVM hypervisor,
+/// from leaf `0x4000_0001`.
+pub struct KvmFeatures {
+    /// `true` if the original kvmclock MSRs are available.
+    pub clocksource: bool,
+
+    /// `true` if I/O port delays are not necessary.
+    pub nop_io_delay: bool,
+
+    /// `true` if the deprecated MMU op hypercall is available.
+    pub mmu_op: bool,
+
+    /// `true` if the new kvmclock MSRs are available.
+    pub clocksource2: bool,
+
+    /// `true` if asynchronous page faults are supported.
+    pub async_pf: bool,
+
+    /// `true` if steal time accounting is supported.
+    pub steal_time: bool,
+
+    /// `true` if paravirtualized end-of-interrupt signaling is supported.
+    pub pv_eoi: bool,
+
+    /// `true` if the paravirtualized spinlock unhalt hypercall is supported.
+    pub pv_unhalt: bool,
+
+    /// `true` if paravirtualized TLB flushes are supported.
+    pub pv_tlb_flush: bool,
+
+    /// `true` if asynchronous page fault VM exits are supported.
+    pub async_pf_vmexit: bool,
+
+    /// `true` if the paravirtualized send IPI hypercall is supported.
+    pub pv_send_ipi: bool,
+
+    /// `true` if host-side halt polling can be disabled by the guest.
+    pub poll_control: bool,
+
+    /// `true` if the paravirtualized sched yield hypercall is supported.
+    pub pv_sched_yield: bool,
+
+    /// `true` if asynchronous page fault delivery by interrupt is supported.
+    pub async_pf_int: bool,
+
+    /// `true` if extended destination IDs in MSI are supported.
+    pub msi_ext_dest_id: bool,
+
+    /// `true` if the map GPA range hypercall is supported.
+    pub hc_map_gpa_range: bool,
+
+    /// `true` if the migration control MSR is available.
+    pub migration_control: bool,
+
+    /// `true` if the guest can rely on kvmclock being stable.
+    pub clocksource_stable: bool,
+}
+
+#[bitfield(bits = 32)]
+struct EaxKvm {
+    clocksource: bool,
+    nop_io_delay: bool,
+    mmu_op: bool,
+    clocksource2: bool,
+    async_pf: bool,
+    steal_time: bool,
+    pv_eoi: bool,
+    pv_unhalt: bool,
+    #[skip]
+    __: B1,
+    pv_tlb_flush: bool,
+    async_pf_vmexit: bool,
+    pv_send_ipi: bool,
+    poll_control: bool,
+    pv_sched_yield: bool,
+    async_pf_int: bool,
+    msi_ext_dest_id: bool,
+    hc_map_gpa_range: bool,
+    migration_control: bool,
+    #[skip]
+    __: B6,
+    clocksource_stable: bool,
+    #[skip]
+    __: B7,
+}
+
+pub(crate) fn describe_kvm(cpu: &Processor, vendor: VendorMask) -> Option<KvmFeatures> {
+    if !vendor.contains(VendorMask::KVM) {
+        return None;
+    }
+
+    if let Some(raw) = cpu.get_subleaf(0x4000_0001, 0) {
+        let eax = EaxKvm::from_bytes(raw.output.eax.to_le_bytes());
+        let features = KvmFeatures {
+            clocksource: eax.clocksource(),
+            nop_io_delay: eax.nop_io_delay(),
+            mmu_op: eax.mmu_op(),
+            clocksource2: eax.clocksource2(),
+            async_pf: eax.async_pf(),
+            steal_time: eax.steal_time(),
+            pv_eoi: eax.pv_eoi(),
+            pv_unhalt: eax.pv_unhalt(),
+            pv_tlb_flush: eax.pv_tlb_flush(),
+            async_pf_vmexit: eax.async_pf_vmexit(),
+            pv_send_ipi: eax.pv_send_ipi(),
+            poll_control: eax.poll_control(),
+            pv_sched_yield: eax.pv_sched_yield(),
+            async_pf_int: eax.async_pf_int(),
+            msi_ext_dest_id: eax.msi_ext_dest_id(),
+            hc_map_gpa_range: eax.hc_map_gpa_range(),
+            migration_control: eax.migration_control(),
+            clocksource_stable: eax.clocksource_stable(),
+        };
+        debug!("describe_kvm() found {:?}", features);
+        Some(features)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes the timing information exposed by several hypervisors (VMware,
+/// KVM, Hyper-V) in leaf `0x4000_0010`. Guests can use this instead of
+/// calibrating the TSC and APIC timer themselves.
+pub struct HypervisorTiming {
+    /// Virtual TSC frequency in kHz, or 0 if not provided.
+    pub tsc_khz: u32,
+
+    /// Virtual APIC bus frequency in kHz, or 0 if not provided.
+    pub apic_khz: u32,
+}
+
+impl fmt::Display for HypervisorTiming {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts: Vec<String> = vec![];
+        if self.tsc_khz != 0 {
+            parts.push(format!("TSC {} kHz", self.tsc_khz));
+        }
+        if self.apic_khz != 0 {
+            parts.push(format!("APIC bus {} kHz", self.apic_khz));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+pub(crate) fn describe_hypervisor_timing(cpu: &Processor, vendor: VendorMask) -> Option<HypervisorTiming> {
+    if vendor.hypervisor_vendor().is_empty() {
+        return None;
+    }
+
+    match cpu.get_subleaf(0x4000_0000, 0) {
+        Some(raw) if raw.output.eax >= 0x4000_0010 => {}
+        _ => return None,
+    }
+
+    if let Some(raw) = cpu.get_subleaf(0x4000_0010, 0) {
+        let timing = HypervisorTiming {
+            tsc_khz: raw.output.eax,
+            apic_khz: raw.output.ebx,
+        };
+        if timing.tsc_khz == 0 && timing.apic_khz == 0 {
+            return None;
+        }
+        debug!("describe_hypervisor_timing() found {:?}", timing);
+        Some(timing)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes the VMware-specific details in leaf `0x4000_0010`. Besides the
+/// timing information shared with other hypervisors, VMware reports which
+/// instruction its hypercall interface expects.
+pub struct VmwareInfo {
+    /// Virtual TSC and APIC bus frequencies, if reported.
+    pub timing: Option<HypervisorTiming>,
+
+    /// `true` if hypercalls should use the `VMCALL` instruction (Intel).
+    pub vmcall: bool,
+
+    /// `true` if hypercalls should use the `VMMCALL` instruction (AMD).
+    pub vmmcall: bool,
+}
+
+impl fmt::Display for VmwareInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts: Vec<String> = vec![];
+        if let Some(timing) = &self.timing {
+            parts.push(timing.to_string());
+        }
+        if self.vmcall {
+            parts.push("VMCALL hypercalls".to_string());
+        }
+        if self.vmmcall {
+            parts.push("VMMCALL hypercalls".to_string());
+        }
+        if parts.is_empty() {
+            parts.push("backdoor I/O port hypercalls".to_string());
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+#[bitfield(bits = 32)]
+struct EcxVmware {
+    vmmcall: bool,
+    vmcall: bool,
+    #[skip]
+    __: B30,
+}
+
+pub(crate) fn describe_vmware(cpu: &Processor, vendor: VendorMask) -> Option<VmwareInfo> {
+    if !vendor.contains(VendorMask::VMWARE) {
+        return None;
+    }
+
+    let mut info = VmwareInfo {
+        timing: describe_hypervisor_timing(cpu, vendor),
+        ..VmwareInfo::default()
+    };
+    match cpu.get_subleaf(0x4000_0000, 0) {
+        Some(raw) if raw.output.eax >= 0x4000_0010 => {
+            if let Some(raw) = cpu.get_subleaf(0x4000_0010, 0) {
+                let ecx = EcxVmware::from_bytes(raw.output.ecx.to_le_bytes());
+                info.vmcall = ecx.vmcall();
+                info.vmmcall = ecx.vmmcall();
+            }
+        }
+        _ => {}
+    }
+
+    debug!("describe_vmware() found {:?}", info);
+
+    Some(info)
+}
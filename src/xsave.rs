@@ -0,0 +1,128 @@
+#![allow(dead_code)]
+
+use core::fmt;
+use log::*;
+use modular_bitfield::prelude::*;
+
+use crate::cpuid::Processor;
+use crate::internal::prelude::*;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes a single XSAVE state component, from leaf `0x0000_000D` subleaves
+/// 2 and up.
+pub struct XsaveComponent {
+    /// State component index (also the subleaf it was discovered in).
+    pub component: u8,
+
+    /// Offset of this component from the start of the XSAVE area, in the
+    /// standard (non-compacted) format. Always zero for supervisor components.
+    pub offset: u32,
+
+    /// Size of this component, in bytes.
+    pub size: u32,
+
+    /// `true` if this component is a supervisor state component (managed via
+    /// `IA32_XSS`), `false` if it is a user state component (managed via
+    /// `XCR0`).
+    pub supervisor: bool,
+}
+
+impl XsaveComponent {
+    /// Name of the processor state this component holds.
+    pub fn name(&self) -> &'static str {
+        match self.component {
+            0 => "x87",
+            1 => "SSE",
+            2 => "AVX",
+            3 => "MPX BNDREGS",
+            4 => "MPX BNDCSR",
+            5 => "AVX-512 opmask",
+            6 => "AVX-512 ZMM_Hi256",
+            7 => "AVX-512 Hi16_ZMM",
+            8 => "Processor Trace",
+            9 => "PKRU",
+            10 => "PASID",
+            11 => "CET user",
+            12 => "CET supervisor",
+            13 => "HDC",
+            14 => "UINTR",
+            15 => "LBR",
+            16 => "HWP",
+            17 => "AMX TILECFG",
+            18 => "AMX TILEDATA",
+            19 => "APX",
+            _ => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for XsaveComponent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}): {} bytes", self.component, self.name(), self.size)?;
+        if self.supervisor {
+            write!(f, ", supervisor")
+        } else {
+            write!(f, " at offset {}", self.offset)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Describes the XSAVE area and the extended state components supported by the
+/// processor, from leaf `0x0000_000D`.
+pub struct XsaveInfo {
+    /// Size in bytes of the XSAVE area required by the components currently
+    /// enabled in `XCR0`.
+    pub enabled_size: u32,
+
+    /// Maximum size in bytes of the XSAVE area, if all supported user state
+    /// components were enabled.
+    pub max_size: u32,
+
+    /// Supported state components, from subleaf 2 onward.
+    pub components: Vec<XsaveComponent>,
+}
+
+impl fmt::Display for XsaveInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} bytes (max {} bytes)", self.enabled_size, self.max_size)
+    }
+}
+
+#[bitfield(bits = 32)]
+struct EcxComponent {
+    supervisor: bool,
+    aligned: bool,
+    #[skip]
+    __: B30,
+}
+
+pub(crate) fn describe_xsave(cpu: &Processor) -> Option<XsaveInfo> {
+    let mut info = XsaveInfo::default();
+    if let Some(raw) = cpu.get_subleaf(0x0000_000D, 0) {
+        if raw.output.ebx == 0 && raw.output.ecx == 0 {
+            return None;
+        }
+        info.enabled_size = raw.output.ebx;
+        info.max_size = raw.output.ecx;
+    } else {
+        return None;
+    }
+
+    for raw in cpu.get(0x0000_000D).iter() {
+        if raw.input.ecx < 2 || raw.input.ecx > 63 || raw.output.eax == 0 {
+            continue;
+        }
+        let ecx = EcxComponent::from_bytes(raw.output.ecx.to_le_bytes());
+        let component = XsaveComponent {
+            component: raw.input.ecx as u8,
+            offset: raw.output.ebx,
+            size: raw.output.eax,
+            supervisor: ecx.supervisor(),
+        };
+        debug!("describe_xsave() found component {:?}", component);
+        info.components.push(component);
+    }
+
+    Some(info)
+}
@@ -0,0 +1,76 @@
+use cpuid::cpuid::parse_cpu_selection;
+use cpuid::error::CpuidError;
+
+const AVAILABLE: [u32; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+
+#[test]
+fn select_single() {
+    assert_eq!(parse_cpu_selection("3", &AVAILABLE), Ok(vec![3]));
+    assert_eq!(parse_cpu_selection(" 0 ", &AVAILABLE), Ok(vec![0]));
+}
+
+#[test]
+fn select_range() {
+    assert_eq!(parse_cpu_selection("2-5", &AVAILABLE), Ok(vec![2, 3, 4, 5]));
+    assert_eq!(parse_cpu_selection("4-4", &AVAILABLE), Ok(vec![4]));
+}
+
+#[test]
+fn select_list() {
+    assert_eq!(parse_cpu_selection("1,3,5", &AVAILABLE), Ok(vec![1, 3, 5]));
+    assert_eq!(parse_cpu_selection("6, 0-2, 1", &AVAILABLE), Ok(vec![0, 1, 2, 6]));
+}
+
+#[test]
+fn select_all() {
+    assert_eq!(parse_cpu_selection("all", &AVAILABLE), Ok(AVAILABLE.to_vec()));
+    assert_eq!(parse_cpu_selection("-1", &AVAILABLE), Ok(AVAILABLE.to_vec()));
+    assert_eq!(parse_cpu_selection("all", &[5, 1, 3]), Ok(vec![1, 3, 5]));
+}
+
+#[test]
+fn select_invalid() {
+    for spec in ["", "x", "1,", "1,,2", "3-", "-3", "5-2", "1-2-3", "all,1", "1.5"].iter() {
+        assert!(
+            matches!(
+                parse_cpu_selection(spec, &AVAILABLE),
+                Err(CpuidError::InvalidCpuSelection(_))
+            ),
+            "{:?} should not parse",
+            spec
+        );
+    }
+    assert_eq!(
+        parse_cpu_selection("5-2", &AVAILABLE),
+        Err(CpuidError::InvalidCpuSelection("5-2".to_string()))
+    );
+}
+
+#[test]
+fn select_out_of_range() {
+    let out_of_range = Err(CpuidError::CpuOutOfRange {
+        index: 8,
+        first: 0,
+        last: 7,
+    });
+    assert_eq!(parse_cpu_selection("8", &AVAILABLE), out_of_range);
+    assert_eq!(parse_cpu_selection("6-9", &AVAILABLE), out_of_range);
+    assert_eq!(parse_cpu_selection("1,8", &AVAILABLE), out_of_range);
+    assert_eq!(
+        parse_cpu_selection("2", &[0, 1, 4]),
+        Err(CpuidError::CpuOutOfRange {
+            index: 2,
+            first: 0,
+            last: 4,
+        })
+    );
+    assert_eq!(
+        CpuidError::CpuOutOfRange {
+            index: 8,
+            first: 0,
+            last: 7,
+        }
+        .to_string(),
+        "CPU 8 does not exist (valid range: 0 to 7)"
+    );
+}
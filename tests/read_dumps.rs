@@ -1,6 +1,24 @@
-use cpuid::cache::{CacheAssociativityType, CacheFlags, CacheLevel, CacheType};
-use cpuid::cpuid::{Signature, System, VendorMask};
-use cpuid::topology::TopologyInferred;
+#![cfg(feature = "std")]
+
+use bitflags::Flags;
+use cpuid::address::AddressSizes;
+use cpuid::cache::{
+    CacheAssociativity, CacheAssociativityType, CacheDescription, CacheDescriptorTable, CacheFlags, CacheLevel,
+    CacheType,
+};
+use cpuid::cpuid::{
+    walk_range, LeafFilter, LeafID, LeafStatus, ParseRawCPUIDResponseError, ParseRegisterNameError, Processor,
+    RawCPUIDResponse, RegisterName, Registers, Signature, System, VendorMask,
+};
+use cpuid::error::CpuidError;
+use cpuid::feature::{FeatureCategory, FeatureLeaf, FeatureRow, FeatureSpec};
+use cpuid::frequency::{FrequencyInfo, TscInfo};
+use cpuid::rdt::{AmdQosInfo, BandwidthEnforcement, CacheAllocation, RdtInfo, RdtMonitoring};
+use cpuid::security::{PconfigInfo, SevInfo};
+use cpuid::topology::{CoreType, HybridInfo, TopologyID, TopologyInferred};
+use cpuid::validate::ValidationWarning;
+use cpuid::virtualization::{HypervisorTiming, KvmFeatures, SvmInfo, VmwareInfo};
+use cpuid::xsave::XsaveComponent;
 use std::path::PathBuf;
 
 fn dump_path(name: &str) -> String {
@@ -55,9 +73,8 @@ fn import_dump_transmeta() {
         assert_eq!(import.cpu_count, 1);
         assert_eq!(import.vendor, VendorMask::TRANSMETA);
         assert_eq!(import.name_string, "Transmeta Efficeon(tm) Processor TM8000");
-        #[cfg(feature = "legacy-tlb-descriptors")]
-        assert_eq!(import.caches.0.len(), 1);
-        #[cfg(not(feature = "legacy-tlb-descriptors"))]
+        // Leaf 0x0000_0002 only contains the iteration count in AL, which
+        // collides with the 0x01 code TLB descriptor and must not be decoded.
         assert_eq!(import.caches.0.len(), 0);
         assert_eq!(import.features.0.len(), 25);
     }
@@ -109,6 +126,29 @@ fn import_dump_rise() {
     }
 }
 
+#[test]
+fn import_dump_vortex86() {
+    {
+        let import = System::from_file(&dump_path("Vortex86_SoC/Vortex86 SoC0000522_Vortex86DX_CPUID.txt"))
+            .unwrap()
+            .with_decoded();
+        assert_eq!(import.cpu_count, import.cpus.len());
+        assert_eq!(import.cpu_count, 1);
+        assert_eq!(import.vendor, VendorMask::VORTEX86);
+        assert_eq!(import.name_string, "");
+        assert_eq!(import.caches.0.len(), 0);
+        assert_eq!(import.features.0.len(), 3);
+        assert_eq!(
+            import.cpus[0].signature,
+            Signature {
+                family: 0x5,
+                model: 0x2,
+                stepping: 0x2,
+            }
+        );
+    }
+}
+
 #[test]
 fn import_dump_virtualcpu() {
     {
@@ -126,7 +166,7 @@ fn import_dump_virtualcpu() {
         assert_eq!(import.caches.0.len(), 2);
         #[cfg(not(any(feature = "legacy-cache-descriptors", feature = "legacy-tlb-descriptors")))]
         assert_eq!(import.caches.0.len(), 0);
-        assert_eq!(import.features.0.len(), 18);
+        assert_eq!(import.features.0.len(), 19);
         assert_eq!(
             import.cpus[0].signature,
             Signature {
@@ -148,9 +188,15 @@ fn import_dump_hygon() {
         assert_eq!(import.cpu_count, 16);
         assert!(import.vendor.contains(VendorMask::AMD));
         assert_eq!(import.vendor, VendorMask::HYGON);
+        assert_eq!(import.vendor.to_string(), "HygonGenuine");
+        assert_eq!(import.hyperv, None);
+        assert_eq!(import.hypervisor_max_leaf(), None);
+        assert_eq!(import.hypervisor_interface_signature(), None);
+        assert!(import.features.iter().any(|feature| feature.shortname == "IBPB"));
+        assert_eq!(import.vendor.hypervisor_vendor(), VendorMask::UNKNOWN);
         assert_eq!(import.name_string, "Hygon C86 3185 8-core Processor");
         assert_eq!(import.caches.0.len(), 14);
-        assert_eq!(import.features.0.len(), 113);
+        assert_eq!(import.features.0.len(), 114);
         assert_eq!(
             import.cpus[0].signature,
             Signature {
@@ -200,13 +246,12 @@ fn import_dump_centaur() {
             import.topology,
             TopologyInferred {
                 sockets: 1,
+                dies_per_socket: None,
+                modules_per_die: None,
                 cores_per_socket: 4,
                 threads_per_core: 1
             }
         );
-        #[cfg(feature = "legacy-tlb-descriptors")]
-        assert_eq!(import.caches.0.len(), 4);
-        #[cfg(not(feature = "legacy-tlb-descriptors"))]
         assert_eq!(import.caches.0.len(), 3);
         for cache in import.caches.0.iter() {
             match cache.cachetype {
@@ -217,13 +262,37 @@ fn import_dump_centaur() {
                 CacheType::Unified => {
                     assert_eq!(cache.level, CacheLevel::L2);
                     assert_eq!(cache.instances, 1);
-                }
-                CacheType::CodeTLB => {
-                    assert_eq!(cache.flags, CacheFlags::new().with_pages_4k(true));
+                    assert_eq!(cache.instances_per_socket, 1);
                 }
                 _ => panic!("unexpected cache type"),
             }
         }
+        let padlock: Vec<&str> = import
+            .features
+            .0
+            .iter()
+            .filter(|f| f.leaf.eax == 0xC000_0001)
+            .map(|f| f.shortname)
+            .collect();
+        assert_eq!(padlock.len(), 11);
+        for shortname in ["RNG", "RNG_EN", "ACE", "ACE_EN", "ACE2", "PHE", "PHE_EN", "PMM", "PMM_EN"].iter() {
+            assert!(padlock.contains(shortname));
+        }
+        assert!(!padlock.contains(&"ACE2_EN"));
+
+        // The same processor reporting the Zhaoxin vendor string should decode
+        // the same Centaur feature leaf.
+        let mut leaves: Vec<RawCPUIDResponse> = import.cpus[0].leaves.clone();
+        for leaf in leaves.iter_mut() {
+            if leaf.input.eax == 0x0000_0000 {
+                leaf.output.ebx = 0x6853_2020;
+                leaf.output.edx = 0x6867_6e61;
+                leaf.output.ecx = 0x2020_6961;
+            }
+        }
+        let zhaoxin = System::from_slice(&leaves).with_decoded();
+        assert_eq!(zhaoxin.vendor, VendorMask::ZHAOXIN);
+        assert_eq!(zhaoxin.features.0.iter().filter(|f| f.leaf.eax == 0xC000_0001).count(), 11);
     }
 }
 
@@ -269,6 +338,8 @@ fn import_dump_amd() {
                 CacheType::Code | CacheType::Data => {
                     assert_eq!(cache.level, CacheLevel::L1);
                     assert_eq!(cache.size, 64);
+                    assert_eq!(cache.total_size_bytes(), Some(64 * 1024));
+                    assert_eq!(cache.tlb_entries(), None);
                     // AMD doesn't expose this information in older processors.
                     assert_eq!(cache.instances, 0);
                 }
@@ -287,6 +358,8 @@ fn import_dump_amd() {
                         CacheLevel::L1 => match cache.flags.pages_4k() {
                             true => {
                                 assert_eq!(cache.size, 24);
+                                assert_eq!(cache.tlb_entries(), Some(24));
+                                assert_eq!(cache.total_size_bytes(), None);
                                 assert_eq!(
                                     cache.associativity.mapping,
                                     CacheAssociativityType::FullyAssociative
@@ -315,8 +388,62 @@ fn import_dump_amd() {
         assert_eq!(import.cpu_count, import.cpus.len());
         assert_eq!(import.cpu_count, 256);
         assert_eq!(import.caches.0.len(), 14);
+        for cache in import.caches.0.iter() {
+            match (cache.level, cache.cachetype) {
+                (CacheLevel::L1, CacheType::Data) | (CacheLevel::L2, CacheType::Unified) => {
+                    assert_eq!(cache.instances, 128);
+                    assert_eq!(cache.instances_per_socket, 64);
+                }
+                (CacheLevel::L3, CacheType::Unified) => {
+                    assert_eq!(cache.instances, 32);
+                    assert_eq!(cache.instances_per_socket, 16);
+                }
+                _ => {}
+            }
+        }
         assert_eq!(import.vendor, VendorMask::AMD);
+        assert!(!import.hypervisor_present());
         assert_eq!(import.name_string, "AMD EPYC 7742 64-Core Processor");
+        assert_eq!(
+            import.address_sizes,
+            Some(AddressSizes {
+                physical_bits: 48,
+                linear_bits: 48,
+                guest_physical_bits: 0,
+            })
+        );
+        assert_eq!(import.physical_core_count(), Some(64));
+        assert_eq!(import.cpus[0].max_leaf(0x0000_0000), Some(0x0000_0010));
+        assert_eq!(import.cpus[0].max_leaf(0x8000_0000), Some(0x8000_0020));
+        assert_eq!(import.cpus[0].max_leaf(0x4000_0000), None);
+        assert_eq!(import.cpus[0].supported_bases(), vec![0x0000_0000, 0x8000_0000]);
+        assert_eq!(
+            import.sev,
+            Some(SevInfo {
+                sme: true,
+                sev: true,
+                sev_es: true,
+                sev_snp: false,
+                cbit_position: 47,
+                phys_addr_reduction: 5,
+                encrypted_guests: 509,
+                min_sev_asid: 1,
+            })
+        );
+        assert!(import.features.0.iter().any(|f| f.shortname == "SEV-ES"));
+        assert!(import.features.0.iter().any(|f| f.shortname == "IBPB"));
+        assert_eq!(
+            import.svm,
+            Some(SvmInfo {
+                revision: 1,
+                asids: 32768,
+                nested_paging: true,
+                vmcb_clean_bits: true,
+                flush_by_asid: true,
+                decode_assists: true,
+            })
+        );
+        assert!(import.features.0.iter().any(|f| f.shortname == "SSBD"));
         assert_eq!(
             import.cpus[0].signature,
             Signature {
@@ -329,11 +456,41 @@ fn import_dump_amd() {
             import.topology,
             TopologyInferred {
                 sockets: 2,
+                dies_per_socket: None,
+                modules_per_die: None,
                 cores_per_socket: 64,
                 threads_per_core: 2
             }
         );
     }
+    {
+        let import = System::from_file(&dump_path("AuthenticAMD/AuthenticAMD0700F01_K16_Kabini3_CPUID.txt"))
+            .unwrap()
+            .with_decoded();
+        assert_eq!(import.cpu_count, import.cpus.len());
+        assert!(import.vendor.contains(VendorMask::AMD));
+        assert!(import.vendor.contains(VendorMask::HYPERV));
+        assert!(import.hypervisor_present());
+        assert_eq!(import.vendor.cpu_vendor(), VendorMask::AMD);
+        assert_eq!(import.vendor.hypervisor_vendor(), VendorMask::HYPERV);
+        assert_eq!(import.vendor.to_string(), "AuthenticAMD, Microsoft Hv");
+        assert_eq!("AuthenticAMD, Microsoft Hv".parse::<VendorMask>().unwrap(), import.vendor);
+
+        assert_eq!(import.hypervisor_max_leaf(), Some(0x4000_000B));
+        assert_eq!(import.hypervisor_interface_signature(), Some("Hv#1"));
+
+        let hyperv = import.hyperv.unwrap();
+        assert_eq!(hyperv.interface_signature, "Hv#1");
+        assert_eq!(hyperv.build, 18362);
+        assert_eq!(hyperv.major_version, 10);
+        assert_eq!(hyperv.minor_version, 0);
+        assert_eq!(hyperv.partition_privileges, 0x002b_b9ff_0000_3fff);
+        assert_eq!(hyperv.recommendations, 0x0004_2d1c);
+        assert_eq!(hyperv.max_virtual_processors, 320);
+        assert_eq!(hyperv.max_logical_processors, 512);
+        assert_eq!(hyperv.hardware_features, 0x0002_020e);
+        assert_eq!(hyperv.to_string(), "Hv#1, version 10.0.18362");
+    }
 }
 
 #[test]
@@ -428,7 +585,7 @@ fn import_dump_intel() {
         assert_eq!(import.vendor, VendorMask::INTEL);
         assert_eq!(import.name_string, "Intel(R) Celeron(R) M processor 1300MHz");
         #[cfg(all(feature = "legacy-cache-descriptors", feature = "legacy-tlb-descriptors"))]
-        assert_eq!(import.caches.0.len(), 8);
+        assert_eq!(import.caches.0.len(), 7);
         #[cfg(not(any(feature = "legacy-cache-descriptors", feature = "legacy-tlb-descriptors")))]
         assert_eq!(import.caches.0.len(), 0);
         assert_eq!(import.features.0.len(), 24);
@@ -444,11 +601,28 @@ fn import_dump_intel() {
         assert_eq!(import.vendor, VendorMask::INTEL);
         assert_eq!(import.name_string, "Intel(R) Pentium(R) 4 CPU 1700MHz");
         #[cfg(all(feature = "legacy-cache-descriptors", feature = "legacy-tlb-descriptors"))]
-        assert_eq!(import.caches.0.len(), 6);
+        assert_eq!(import.caches.0.len(), 5);
         #[cfg(not(any(feature = "legacy-cache-descriptors", feature = "legacy-tlb-descriptors")))]
         assert_eq!(import.caches.0.len(), 0);
         assert_eq!(import.features.0.len(), 27);
     }
+    {
+        let import = System::from_file(&dump_path("GenuineIntel/GenuineIntel0040661_CrystalWell_CPUID.txt"))
+            .unwrap()
+            .with_decoded();
+        assert_eq!(import.cpu_count, 8);
+        let l4 = import
+            .caches
+            .iter()
+            .find(|cache| cache.level == CacheLevel::L4)
+            .unwrap();
+        assert_eq!(l4.cachetype, CacheType::Unified);
+        assert_eq!(l4.size, 128 * 1024);
+        assert_eq!(l4.total_size_bytes(), Some(128 * 1024 * 1024));
+        assert_eq!(l4.associativity.ways, 16);
+        assert_eq!(l4.partitions, 16);
+        assert_eq!(l4.max_threads_sharing, 16);
+    }
     {
         let import = System::from_file(&dump_path(
             "GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt",
@@ -459,11 +633,55 @@ fn import_dump_intel() {
         assert_eq!(import.cpu_count, 16);
         assert_eq!(import.vendor, VendorMask::INTEL);
         assert_eq!(import.name_string, "Genuine Intel(R) CPU @ 0000 @ 2.67GHz");
+        assert_eq!(
+            import.address_sizes,
+            Some(AddressSizes {
+                physical_bits: 40,
+                linear_bits: 48,
+                guest_physical_bits: 0,
+            })
+        );
+        assert_eq!(import.physical_core_count(), None);
+        assert_eq!(
+            import.cpus[0].topology(),
+            &Some(TopologyID {
+                socket: 0,
+                core: 0,
+                thread: 0,
+            })
+        );
+        assert_eq!(
+            import.cpus[1].topology(),
+            &Some(TopologyID {
+                socket: 1,
+                core: 0,
+                thread: 0,
+            })
+        );
+        assert_eq!(
+            import.cpus[15].topology(),
+            &Some(TopologyID {
+                socket: 1,
+                core: 3,
+                thread: 1,
+            })
+        );
+        let mut ids: Vec<(u32, u32, u32)> = import
+            .cpus
+            .iter()
+            .map(|cpu| {
+                let id = cpu.topology().clone().unwrap();
+                (id.socket, id.core, id.thread)
+            })
+            .collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), 16);
         #[cfg(all(feature = "legacy-cache-descriptors", feature = "legacy-tlb-descriptors"))]
-        assert_eq!(import.caches.0.len(), 10);
+        assert_eq!(import.caches.0.len(), 9);
         #[cfg(not(any(feature = "legacy-cache-descriptors", feature = "legacy-tlb-descriptors")))]
         assert_eq!(import.caches.0.len(), 4);
-        assert_eq!(import.features.0.len(), 50);
+        assert_eq!(import.features.0.len(), 51);
         assert_eq!(
             import.cpus[0].signature,
             Signature {
@@ -476,6 +694,8 @@ fn import_dump_intel() {
             import.topology,
             TopologyInferred {
                 sockets: 2,
+                dies_per_socket: None,
+                modules_per_die: None,
                 cores_per_socket: 4,
                 threads_per_core: 2
             }
@@ -496,16 +716,2371 @@ fn import_dump_intel() {
         );
         assert_eq!(import.caches.0.len(), 12);
         assert!(import.features.0.len() >= 138);
+        assert!(import.features.0.iter().any(|f| f.shortname == "HWP"));
+        assert!(import.features.0.iter().any(|f| f.shortname == "ARAT"));
+
+        let xsave = import.xsave.unwrap();
+        assert_eq!(xsave.enabled_size, 2688);
+        assert_eq!(xsave.max_size, 2696);
+        assert_eq!(
+            xsave.components[0],
+            XsaveComponent {
+                component: 2,
+                offset: 576,
+                size: 256,
+                supervisor: false,
+            }
+        );
+        assert_eq!(xsave.components[0].name(), "AVX");
+
+        let trace = import.processor_trace.unwrap();
+        assert!(trace.cr3_filtering);
+        assert!(trace.ip_filtering);
+        assert!(!trace.ptwrite);
+        assert!(!trace.power_event_trace);
+        assert!(trace.topa);
+        assert!(!trace.lip);
+        assert_eq!(trace.address_ranges, 2);
+        assert_eq!(trace.mtc_period_encodings, 0x0249);
+        assert_eq!(trace.cycle_threshold_encodings, 0x1fff);
+        assert_eq!(trace.psb_frequency_encodings, 0x003f);
+
+        assert_eq!(
+            import.frequency,
+            Some(FrequencyInfo {
+                base_mhz: 2800,
+                max_mhz: 4700,
+                bus_mhz: 100,
+            })
+        );
+        assert_eq!(
+            format!("{}", import.frequency.unwrap()),
+            "Base 2800 MHz, Max 4700 MHz, Bus 100 MHz"
+        );
+
+        let tsc = import.tsc.unwrap();
+        assert_eq!(
+            tsc,
+            TscInfo {
+                denominator: 2,
+                numerator: 146,
+                crystal_hz: 38_400_000,
+            }
+        );
+        assert_eq!(tsc.tsc_hz(), Some(2_803_200_000));
+        assert_eq!(import.cpus[0].core_type(), None);
+        assert_eq!(import.cpus[0].hybrid_info(), None);
+        assert!(!import.is_hybrid());
+
+        let l3 = import
+            .caches
+            .iter()
+            .find(|cache| cache.level == CacheLevel::L3)
+            .unwrap();
+        assert_eq!(l3.max_threads_sharing, 16);
+        assert_eq!(
+            format!("{}", l3),
+            " 1 x    12MB L3 unified cache, 12-way set associative, 64 byte line size
+             Self-initializing
+             Complex indexing
+             Shared by max 16 threads"
+        );
+    }
+    {
+        let import = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806A1_Lakefield_CPUID.txt"))
+            .unwrap()
+            .with_decoded();
+        assert_eq!(import.cpu_count, 5);
+        assert!(import.is_hybrid());
+        for cpu in import.cpus.iter().take(4) {
+            assert_eq!(cpu.core_type(), Some(CoreType::Atom));
+        }
+        assert_eq!(import.cpus[4].core_type(), Some(CoreType::Core));
+        assert_eq!(
+            import.cpus[4].hybrid_info(),
+            Some(HybridInfo {
+                core_type: CoreType::Core,
+                native_model_id: 0,
+            })
+        );
+        assert_eq!(
+            import.cpus[0].hybrid_info().unwrap().to_string(),
+            "Atom (E-core), native model ID 0x000000"
+        );
     }
 }
 
+fn malformed_path(name: &str) -> String {
+    let mut pathbuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    pathbuf.push("resources/test/malformed");
+    pathbuf.push(name);
+    pathbuf.as_path().to_str().unwrap().to_string()
+}
+
 #[test]
-fn import_dump_localsystem() {
-    let import = System::from_local().with_decoded();
-    #[cfg(not(target_os = "macos"))]
-    assert_eq!(import.cpu_count, import.cpus.len());
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    assert!(import.vendor.intersects(VendorMask::ANY_CPU));
-    // TODO: more tests here, probably by getting information from other sources and
-    // cross-referencing.
+fn import_dump_malformed() {
+    {
+        let path = malformed_path("GenuineIntel0000480_486_comments.txt");
+        let import = System::from_file_strict(&path).unwrap().with_decoded();
+        assert_eq!(import.cpu_count, 1);
+        assert_eq!(import.cpus[0].leaves.len(), 6);
+        assert_eq!(import.vendor, VendorMask::INTEL);
+    }
+    {
+        let path = malformed_path("GenuineIntel0000480_486_truncated.txt");
+        let import = System::from_file(&path).unwrap().with_decoded();
+        assert_eq!(import.cpu_count, 1);
+        assert_eq!(import.cpus[0].leaves.len(), 2);
+
+        let err = System::from_file_strict(&path).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains(":6: "));
+    }
+    {
+        let path = malformed_path("empty.txt");
+        let import = System::from_file(&path).unwrap().with_decoded();
+        assert_eq!(import.cpu_count, 0);
+        assert!(import.cpus.is_empty());
+        assert_eq!(import.vendor, VendorMask::UNKNOWN);
+
+        let err = System::from_file_strict(&path).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().ends_with("no processors found"));
+    }
+}
+
+#[test]
+#[cfg(feature = "gzip")]
+fn import_dump_gzip() {
+    let mut pathbuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    pathbuf.push("resources/test/compressed/GenuineIntel0000480_486_CPUID.txt.gz");
+    let compressed = System::from_file_strict(pathbuf.to_str().unwrap()).unwrap().with_decoded();
+    let plain = System::from_file(&dump_path("GenuineIntel/GenuineIntel0000480_486_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(compressed.cpu_count, 1);
+    assert_eq!(compressed.vendor, VendorMask::INTEL);
+    assert!(compressed.diff(&plain).is_empty());
+}
+
+#[test]
+fn import_from_reader() {
+    let path = dump_path("GenuineIntel/GenuineIntel0000480_486_CPUID.txt");
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let import = System::from_reader(contents.as_bytes()).unwrap().with_decoded();
+    let plain = System::from_file(&path).unwrap().with_decoded();
+    assert_eq!(import.cpu_count, plain.cpu_count);
+    assert!(import.diff(&plain).is_empty());
+    assert_eq!(import.vendor, VendorMask::INTEL);
+}
+
+#[test]
+fn dumps_round_trip() {
+    let mut directories = vec![PathBuf::from(dump_path(""))];
+    let mut count = 0;
+    while let Some(directory) = directories.pop() {
+        for entry in std::fs::read_dir(&directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                directories.push(path);
+                continue;
+            }
+            let contents = std::fs::read_to_string(&path).unwrap().replace("\r", "");
+            let import = System::from_reader(contents.as_bytes()).unwrap();
+            let mut output: Vec<u8> = vec![];
+            import.write_dump(&mut output).unwrap();
+            assert!(
+                String::from_utf8(output).unwrap() == contents,
+                "{} does not round-trip",
+                path.display()
+            );
+            count += 1;
+        }
+    }
+    assert!(count > 400);
+}
+
+#[test]
+fn dump_without_ascii() {
+    let import = System::from_file(&dump_path("GenuineIntel/GenuineIntel00006F6_Merom_CPUID.txt")).unwrap();
+    let mut output: Vec<u8> = vec![];
+    import.write_dump_with(&mut output, false).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.starts_with("CPU 0:\nCPUID 00000000:00 = "));
+    assert!(!output.contains('|'));
+
+    let reimport = System::from_reader(output.as_bytes()).unwrap();
+    assert_eq!(reimport.cpus[0].leaves, import.cpus[0].leaves);
+    assert_eq!(format!("{:#}", import.cpus[0].leaves[0]), output.lines().nth(1).unwrap());
+}
+
+#[test]
+fn sorted_leaves() {
+    // This dump has a stray, all-zero leaf 0x8000_0001 in place of 0x8000_001D.
+    let path = dump_path("AuthenticAMD/AuthenticAMD0670F00_K15_StoneyRidge_CPUID.txt");
+    let import = System::from_file(&path).unwrap();
+    let mut shuffled = System::from_file(&path).unwrap();
+    shuffled.cpus.reverse();
+    for cpu in shuffled.cpus.iter_mut() {
+        let last = cpu.leaves.pop().unwrap();
+        cpu.leaves.insert(0, last);
+    }
+
+    let sorted = shuffled.sorted();
+    let key = |leaf: &RawCPUIDResponse| (leaf.input.eax, leaf.input.ecx);
+    assert_eq!(sorted.cpus[0].index, 0);
+    for (cpu, original) in sorted.cpus.iter().zip(import.cpus.iter()) {
+        assert_eq!(cpu.leaves.len(), original.leaves.len());
+        assert!(cpu.leaves.windows(2).all(|pair| key(&pair[0]) <= key(&pair[1])));
+        // Duplicated leaves keep their order, so the first one still wins.
+        assert_eq!(cpu.get_subleaf(0x8000_0001, 0), original.get_subleaf(0x8000_0001, 0));
+    }
+    assert_eq!(sorted.with_decoded().features.len(), import.with_decoded().features.len());
+}
+
+#[test]
+fn import_without_decoding() {
+    let import =
+        System::from_file(&dump_path("GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt")).unwrap();
+    assert_eq!(import.cpu_count, 16);
+    assert_eq!(import.vendor, VendorMask::UNKNOWN);
+    assert_eq!(import.name_string, "");
+    assert_eq!(import.caches.0.len(), 0);
+    assert_eq!(import.features.0.len(), 0);
+    assert!(!import.topology.valid());
+    assert!(import.cpus[0].topology().is_none());
+
+    let decoded = import.with_decoded();
+    assert_eq!(decoded.vendor, VendorMask::INTEL);
+    assert!(decoded.topology.valid());
+    assert!(decoded.cpus[0].topology().is_some());
+}
+
+static CUSTOM_FEATURES_0000_0001_EBX: [FeatureSpec; 1] = [FeatureSpec {
+    bit: 11,
+    vendor_mask: VendorMask::INTEL,
+    category: FeatureCategory::Misc,
+    shortname: "CUSTOM",
+    name: "Custom feature bit",
+}];
+
+static CUSTOM_FEATURES_0000_0007_0_EBX: [FeatureSpec; 1] = [FeatureSpec {
+    bit: 5,
+    vendor_mask: VendorMask::INTEL,
+    category: FeatureCategory::Misc,
+    shortname: "AVX2-DUP",
+    name: "Duplicate of a built-in feature bit",
+}];
+
+static CUSTOM_FEATURE_LEAVES: [FeatureLeaf; 2] = [
+    FeatureLeaf {
+        leaf: LeafID { eax: 0x0000_0001, ecx: 0 },
+        vendor_mask: VendorMask::INTEL,
+        register: RegisterName::EBX,
+        bits: &CUSTOM_FEATURES_0000_0001_EBX,
+    },
+    FeatureLeaf {
+        leaf: LeafID { eax: 0x0000_0007, ecx: 0 },
+        vendor_mask: VendorMask::INTEL,
+        register: RegisterName::EBX,
+        bits: &CUSTOM_FEATURES_0000_0007_0_EBX,
+    },
+];
+
+#[test]
+fn decode_custom_features() {
+    let import = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID3.txt"))
+        .unwrap()
+        .with_decoded();
+    let features = import.features_with(&CUSTOM_FEATURE_LEAVES);
+    assert_eq!(features.len(), import.features.len() + 1);
+    assert!(features.iter().any(|feature| feature.shortname == "CUSTOM"));
+    assert!(!features.iter().any(|feature| feature.shortname == "AVX2-DUP"));
+    assert!(features.iter().any(|feature| feature.shortname == "AVX2"));
+}
+
+static MACRO_FEATURE_LEAVES: [FeatureLeaf; 2] = [
+    cpuid::feature_leaf! {
+        leaf: 0x0000_0001, 0;
+        vendor_mask: VendorMask::INTEL;
+        register: EBX;
+        bits: [
+            (4, VendorMask::INTEL, Misc, "CUSTOM", "Custom feature bit"),
+        ]
+    },
+    cpuid::feature_leaf! {
+        leaf: 0x0000_0007, 0;
+        vendor_mask: VendorMask::INTEL;
+        register: EBX;
+        bits: [
+            (5, VendorMask::INTEL, Misc, "AVX2-DUP", "Duplicate of a built-in feature bit"),
+        ]
+    },
+];
+
+#[test]
+fn decode_macro_features() {
+    let import = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID3.txt"))
+        .unwrap()
+        .with_decoded();
+    let key = |feature: &cpuid::feature::Feature| {
+        (feature.leaf.eax, feature.register, feature.bit, feature.shortname)
+    };
+    let from_macro: Vec<_> = import.features_with(&MACRO_FEATURE_LEAVES).iter().map(key).collect();
+    let by_hand: Vec<_> = import.features_with(&CUSTOM_FEATURE_LEAVES).iter().map(key).collect();
+    assert_eq!(from_macro, by_hand);
+
+    let leaf = &MACRO_FEATURE_LEAVES[0];
+    assert_eq!(leaf.leaf, LeafID::new(0x0000_0001, 0));
+    assert_eq!(leaf.register, RegisterName::EBX);
+    assert_eq!(leaf.bits.len(), 1);
+    assert_eq!(leaf.bits[0].category, FeatureCategory::Misc);
+}
+
+#[test]
+fn decode_custom_cache_descriptors() {
+    let import = System::from_file(&dump_path("GenuineIntel/GenuineIntel0000695_PM_Banias_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let table = CacheDescriptorTable::new().with_override(
+        0x86,
+        CacheDescription {
+            cachetype: CacheType::Unified,
+            level: CacheLevel::L2,
+            size: 1024,
+            linesize: 64,
+            associativity: CacheAssociativity::from_identifier(0x08),
+            ..Default::default()
+        },
+    );
+    assert_eq!(table.lookup(0x86).unwrap().size, 1024);
+    let caches = import.caches_with(&table);
+    let l2 = caches.iter().find(|cache| cache.level == CacheLevel::L2).unwrap();
+    assert_eq!(l2.size, 1024);
+    assert_eq!(l2.associativity.ways, 8);
+}
+
+#[test]
+fn build_cache_description() {
+    let built = CacheDescription::builder()
+        .level(CacheLevel::L1)
+        .cachetype(CacheType::Data)
+        .size(32)
+        .linesize(64)
+        .associativity(CacheAssociativity::from_identifier(0x08))
+        .max_threads_sharing(2)
+        .build();
+    assert_eq!(
+        built,
+        CacheDescription {
+            cachetype: CacheType::Data,
+            level: CacheLevel::L1,
+            size: 32,
+            linesize: 64,
+            associativity: CacheAssociativity::from_identifier(0x08),
+            ..Default::default()
+        }
+    );
+    assert_eq!(built.max_threads_sharing, 2);
+    assert_eq!(CacheDescriptorTable::new().lookup(0x2C), Some(built));
+}
+
+#[test]
+fn legacy_descriptor_49() {
+    let mut system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00006F6_Conroe_CPUID.txt")).unwrap();
+    // Force the legacy descriptors to be used by dropping leaf 0x0000_0004.
+    for cpu in system.cpus.iter_mut() {
+        cpu.leaves.retain(|leaf| leaf.input.eax != 0x0000_0004);
+    }
+    system.decode();
+    let l2 = system.caches.iter().find(|cache| cache.size == 4096).unwrap();
+    assert_eq!(l2.level, CacheLevel::L2);
+    assert_eq!(l2.cachetype, CacheType::Unified);
+    assert_eq!(l2.associativity.ways, 16);
+
+    // Intel Xeon MP, family 0Fh model 06h, reports its L3 with the same descriptor.
+    for cpu in system.cpus.iter_mut() {
+        cpu.get_subleaf_mut(0x0000_0001, 0).unwrap().output.eax = 0x0000_0f68;
+        cpu.re_fill();
+    }
+    system.decode();
+    let l3 = system.caches.iter().find(|cache| cache.size == 4096).unwrap();
+    assert_eq!(l3.level, CacheLevel::L3);
+    assert!(!system.caches.iter().any(|cache| cache.level == CacheLevel::L2 && cache.size == 4096));
+}
+
+#[test]
+fn prefetch_stride() {
+    let mut system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00006F6_Conroe_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(system.prefetch_stride, Some(64));
+
+    // Swap the 0xF0 descriptor for 0xF1.
+    for cpu in system.cpus.iter_mut() {
+        cpu.get_subleaf_mut(0x0000_0002, 0).unwrap().output.ebx = 0x005657f1;
+    }
+    system.decode();
+    assert_eq!(system.prefetch_stride, Some(128));
+
+    let system = System::from_file(&dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(system.prefetch_stride, None);
+}
+
+#[test]
+fn iterate_caches_and_features() {
+    let import = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert!(!import.caches.is_empty());
+    assert_eq!(import.caches.len(), import.caches.0.len());
+    assert_eq!(import.caches.iter().count(), import.caches.len());
+    assert_eq!((&import.caches).into_iter().count(), import.caches.len());
+    assert_eq!(import.caches[0], import.caches.0[0]);
+    for cache in &import.caches {
+        assert!(cache.size > 0);
+    }
+
+    assert!(!import.features.is_empty());
+    assert_eq!(import.features.len(), import.features.0.len());
+    assert!(import.features.iter().any(|feature| feature.shortname == "AVX2"));
+    assert_eq!(import.features[0].name, import.features.0[0].name);
+    let count = import.features.len();
+    assert_eq!(import.features.into_iter().count(), count);
+}
+
+#[test]
+fn import_from_slice() {
+    let file = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let import = System::from_slice(&file.cpus[0].leaves).with_decoded();
+    assert_eq!(import.cpu_count, 1);
+    assert_eq!(import.cpus.len(), 1);
+    assert_eq!(import.vendor, file.vendor);
+    assert_eq!(import.cpus[0].signature, file.cpus[0].signature);
+    assert_eq!(import.name_string, file.name_string);
+    assert_eq!(import.caches.0.len(), file.caches.0.len());
+    assert_eq!(import.features.0.len(), file.features.0.len());
+    assert_eq!(import.frequency, file.frequency);
+}
+
+/// Builds the leaves of a guest running on `host`, with the hypervisor leaves
+/// replaced by `hypervisor_leaves`.
+fn guest_leaves(host: &System, hypervisor_leaves: &[(u32, Registers)]) -> Vec<RawCPUIDResponse> {
+    let mut leaves: Vec<RawCPUIDResponse> = host.cpus[0]
+        .leaves
+        .iter()
+        .filter(|leaf| leaf.input.eax & 0xFFFF_0000 != 0x4000_0000)
+        .cloned()
+        .collect();
+    for (eax, output) in hypervisor_leaves.iter() {
+        leaves.push(RawCPUIDResponse {
+            input: LeafID { eax: *eax, ecx: 0 },
+            output: output.clone(),
+        });
+    }
+    leaves
+}
+
+#[test]
+fn decode_single_processor() {
+    let file = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert!(file.cpus.iter().all(|cpu| cpu.reliable()));
+    let cpu = Processor::from_leaves(file.cpus[3].leaves.clone());
+    assert!(cpu.reliable());
+
+    let features = cpu.features(cpu.vendor);
+    assert_eq!(features.len(), file.features.len());
+    assert!(features.iter().any(|feature| feature.shortname == "AVX512F"));
+
+    assert_eq!(cpu.describe_caches(&file).0, file.caches.0);
+
+    let props = cpu.topology_props().unwrap();
+    assert_eq!(props.thread.total, 2);
+    assert_eq!(props.core.total, 4);
+    assert_eq!(props.thread.mask, file.topology_props.thread.mask);
+    assert_eq!(props.core.mask, file.topology_props.core.mask);
+    assert_eq!(props.socket.mask, file.topology_props.socket.mask);
+
+    // Leaf 0x0000_000B is required for the x2APIC topology.
+    let file = System::from_file(&dump_path("GenuineIntel/GenuineIntel00006F6_Merom_CPUID.txt")).unwrap();
+    assert!(file.cpus[0].topology_props().is_none());
+}
+
+#[test]
+fn construct_from_processors() {
+    let file = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let processors: Vec<Processor> = file
+        .cpus
+        .iter()
+        .map(|cpu| {
+            let mut processor = Processor::from_leaves(cpu.leaves.clone());
+            assert_eq!(processor.vendor, VendorMask::INTEL);
+            assert_eq!(processor.signature, cpu.signature);
+            processor.index = cpu.index;
+            processor
+        })
+        .collect();
+    let system = System::from_processors(processors.clone()).unwrap();
+    assert_eq!(system.cpu_count, file.cpus.len());
+    assert_eq!(system.cpus.len(), file.cpus.len());
+    assert_eq!(system.cpus[1].index, file.cpus[1].index);
+    assert_eq!(system.vendor, file.vendor);
+    assert_eq!(system.name_string, file.name_string);
+    assert_eq!(system.caches.0, file.caches.0);
+    assert_eq!(system.features.len(), file.features.len());
+    assert_eq!(system.topology.sockets, file.topology.sockets);
+    assert_eq!(system.topology.cores_per_socket, file.topology.cores_per_socket);
+    assert_eq!(system.topology.threads_per_core, file.topology.threads_per_core);
+
+    assert_eq!(System::from_processors(vec![]), Err(CpuidError::NoProcessors));
+    let mut mixed = processors;
+    let leaf = mixed[1].get_subleaf_mut(0x0000_0001, 0).unwrap();
+    leaf.output.eax += 1;
+    mixed[1].re_fill();
+    assert_eq!(
+        System::from_processors(mixed.clone()),
+        Err(CpuidError::MismatchedSignature {
+            index: mixed[1].index,
+            expected: mixed[0].signature.clone(),
+            found: mixed[1].signature.clone(),
+        })
+    );
+}
+
+#[test]
+fn decode_mixed_and_empty_systems() {
+    let mut system =
+        System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt")).unwrap();
+    let leaf = system.cpus[1].get_subleaf_mut(0x0000_0001, 0).unwrap();
+    leaf.output.eax += 1;
+    let system = system.with_decoded();
+    assert_eq!(system.vendor, VendorMask::INTEL);
+    assert_ne!(system.cpus[1].signature, system.cpus[0].signature);
+
+    let system = System::from_reader("".as_bytes()).unwrap().with_decoded();
+    assert!(system.cpus.is_empty());
+    assert_eq!(system.vendor, VendorMask::UNKNOWN);
+    assert!(!system.topology.valid());
+    assert!(system.caches_with(&CacheDescriptorTable::new()).is_empty());
+    assert!(system.features_with(&[]).is_empty());
+}
+
+#[test]
+fn topology_consistency() {
+    let mut system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert!(system.topology.valid());
+    assert!(system.topology_consistent());
+    assert_eq!(system.total_logical_cpus(), system.cpu_count);
+
+    // Pretend the OS reported more CPUs than the topology accounts for.
+    system.cpu_count = 12;
+    system.decode();
+    assert!(system.topology.valid());
+    assert!(!system.topology_consistent());
+    assert_eq!(system.total_logical_cpus(), 8);
+
+    // Without a valid topology, the OS-reported count is all there is.
+    let system = System::from_file(&dump_path("CyrixInstead/CyrixInstead0000520_6x86_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert!(!system.topology.valid());
+    assert!(system.topology_consistent());
+    assert_eq!(system.total_logical_cpus(), 1);
+}
+
+#[test]
+fn topology_from_caches() {
+    // Neither of these has leaf 0x0000_000B, but leaf 0x0000_0004 reports how
+    // many threads share each cache.
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00006F6_Merom_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(
+        system.topology,
+        TopologyInferred {
+            sockets: 1,
+            dies_per_socket: None,
+            modules_per_die: None,
+            cores_per_socket: 2,
+            threads_per_core: 1,
+        }
+    );
+    assert_eq!(system.topology_tree(), "");
+
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel0000F43_P4_Prescott_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(system.topology.sockets, 1);
+    assert_eq!(system.topology.cores_per_socket, 1);
+    assert_eq!(system.topology.threads_per_core, 2);
+
+    // Without leaf 0x0000_0004 there is nothing to go on, since the legacy
+    // descriptors in leaf 0x0000_0002 don't describe cache sharing.
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel0000683_P3_Coppermine_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert!(!system.topology.valid());
+}
+
+#[test]
+fn smt_capable_and_enabled() {
+    let mut system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert!(system.smt_capable());
+    assert!(system.smt_enabled());
+
+    // With SMT disabled in firmware the thread level still reserves an APIC ID
+    // bit, but only reports one thread.
+    for cpu in system.cpus.iter_mut() {
+        cpu.get_subleaf_mut(0x0000_000B, 0).unwrap().output.ebx = 1;
+    }
+    system.decode();
+    assert_eq!(system.topology.threads_per_core, 1);
+    assert!(system.smt_capable());
+    assert!(!system.smt_enabled());
+
+    // HTT is set on multi-core processors without SMT too, but leaf
+    // 0x0000_0004 accounts for all the logical processors as cores.
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00006F6_Merom_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert!(!system.smt_capable());
+    assert!(!system.smt_enabled());
+
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel0000F43_P4_Prescott_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert!(system.smt_capable());
+    assert!(system.smt_enabled());
+}
+
+#[test]
+fn system_summary() {
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID3.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(system.features.simd_level(), Some("AVX-512"));
+    assert_eq!(
+        system.summary(),
+        "GenuineIntel 11th Gen Intel(R) Core(TM) i7-1165G7 @ 2.80GHz (Family 6h, Model 8Ch, Stepping 1h) \
+         — 4C/8T, L3 12MB, AVX-512"
+    );
+
+    // Without a valid topology only the logical CPU count is known.
+    let system = System::from_file(&dump_path("CyrixInstead/CyrixInstead0000520_6x86_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert!(system.summary().starts_with("CyrixInstead "));
+    assert!(system.summary().contains(" — 1T"));
+}
+
+#[test]
+fn topology_levels() {
+    let mut topology = TopologyInferred {
+        sockets: 2,
+        dies_per_socket: None,
+        modules_per_die: None,
+        cores_per_socket: 8,
+        threads_per_core: 2,
+    };
+    assert!(topology.valid());
+    assert_eq!(topology.total(), 32);
+    assert_eq!(
+        topology.to_string(),
+        "32 logical CPUs (2 sockets, 8 cores per socket, 2 threads per core)"
+    );
+
+    topology.dies_per_socket = Some(2);
+    assert!(topology.valid());
+    assert_eq!(topology.total(), 64);
+    assert_eq!(topology.to_string(), "2 sockets × 2 dies × 8 cores × 2 threads = 64 CPUs");
+
+    topology.modules_per_die = Some(2);
+    assert_eq!(topology.total(), 128);
+    assert_eq!(
+        topology.to_string(),
+        "2 sockets × 2 dies × 2 modules × 8 cores × 2 threads = 128 CPUs"
+    );
+
+    topology.modules_per_die = Some(0);
+    assert!(!topology.valid());
+    assert_eq!(topology.total(), 0);
+    assert!(!TopologyInferred::new().valid());
+}
+
+#[test]
+fn decode_die_and_module_levels() {
+    let file = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt")).unwrap();
+    let mut leaves: Vec<RawCPUIDResponse> = file.cpus[0]
+        .leaves
+        .iter()
+        .filter(|leaf| leaf.input.eax != 0x0000_001F)
+        .cloned()
+        .collect();
+    // 2 threads per core, 2 cores per module, 2 modules per die and 2 dies.
+    let levels: [(u32, u32, u32); 5] = [
+        (1, 2, 0x100),
+        (2, 4, 0x201),
+        (3, 8, 0x302),
+        (4, 16, 0x503),
+        (0, 0, 0x4),
+    ];
+    for (subleaf, (shift, count, level)) in levels.iter().enumerate() {
+        leaves.push(RawCPUIDResponse {
+            input: LeafID { eax: 0x0000_001F, ecx: subleaf as u32 },
+            output: Registers::new(*shift, *count, *level, 0x6),
+        });
+    }
+
+    let props = Processor::from_leaves(leaves.clone()).topology_props().unwrap();
+    assert_eq!((props.thread.total, props.core.total), (2, 8));
+    assert_eq!((props.die.total, props.module.total), (2, 2));
+    assert_eq!(props.thread.mask, 0x1);
+    assert_eq!(props.core.mask, 0xE);
+    assert_eq!(props.module.mask, 0x4);
+    assert_eq!(props.die.mask, 0x8);
+    assert_eq!(props.socket.mask, 0xFFFF_FFF0);
+
+    let mut system = System::from_slice(&leaves);
+    system.cpu_count = 16;
+    system.decode();
+    assert_eq!(
+        system.topology,
+        TopologyInferred {
+            sockets: 1,
+            dies_per_socket: Some(2),
+            modules_per_die: Some(2),
+            cores_per_socket: 2,
+            threads_per_core: 2,
+        }
+    );
+    assert_eq!(
+        system.topology.to_string(),
+        "1 sockets × 2 dies × 2 modules × 2 cores × 2 threads = 16 CPUs"
+    );
+    assert_eq!(
+        system.cpus[0].topology(),
+        &Some(TopologyID {
+            socket: 0,
+            core: 3,
+            thread: 0,
+        })
+    );
+
+    // Without leaf 0x0000_001F only the socket, core and thread levels are known.
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(system.topology.dies_per_socket, None);
+    assert_eq!(system.topology.modules_per_die, None);
+    assert!(!system.topology_props.die.reported);
+}
+
+#[test]
+fn apic_ids() {
+    let path = dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt");
+    let system = System::from_file(&path).unwrap();
+    assert_eq!(system.cpus[0].apic_id(), Some(0));
+    assert_eq!(system.cpus[1].apic_id(), Some(1));
+    let table = system.apic_id_table();
+    assert_eq!(table.len(), system.cpus.len());
+    assert_eq!(table[1], (system.cpus[1].index, 1));
+
+    // Without leaf 0x0000_000B, the initial APIC ID from leaf 0x0000_0001 is used.
+    let leaves: Vec<RawCPUIDResponse> = system.cpus[1]
+        .leaves
+        .iter()
+        .filter(|leaf| leaf.input.eax != 0x0000_000B && leaf.input.eax != 0x0000_001F)
+        .cloned()
+        .collect();
+    let processor = Processor::from_leaves(leaves);
+    assert_eq!(
+        processor.apic_id(),
+        Some(system.cpus[1].get_subleaf(0x0000_0001, 0).unwrap().output.ebx >> 24)
+    );
+
+    assert_eq!(Processor::new().apic_id(), None);
+}
+
+#[test]
+fn validate_leaves() {
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt")).unwrap();
+    assert_eq!(system.validate(), vec![]);
+
+    // This capture recorded a subleaf of 0x8000_001D as another 0x8000_0001.
+    let system = System::from_file(&dump_path("AuthenticAMD/AuthenticAMD0610F01_K15_Piledriver_CPUID.txt"))
+        .unwrap();
+    let warnings = system.validate();
+    assert_eq!(
+        warnings,
+        vec![
+            ValidationWarning::DuplicateLeaf {
+                cpu: 0,
+                leaf: LeafID { eax: 0x8000_0001, ecx: 0 },
+            },
+            ValidationWarning::LeafMissing {
+                cpu: 0,
+                leaf: 0x8000_001D,
+                max_leaf: 0x8000_001E,
+            },
+        ]
+    );
+    assert_eq!(
+        warnings[1].to_string(),
+        "CPU 0: leaf 8000001d is missing, but the advertised maximum leaf is 8000001e"
+    );
+
+    let leaves: Vec<RawCPUIDResponse> = [
+        "CPUID 00000000:00 = 00000001 756e6547 6c65746e 49656e69",
+        "CPUID 00000001:00 = 800806c1 00100800 7ffafbbf bfebfbff",
+        "CPUID 00000002:00 = 00feff01 000000f0 00000000 00000000",
+        "CPUID 40000000:00 = 40000001 00000000 00000000 00000000",
+        "CPUID 40000001:00 = 00000000 00000000 00000000 00000000",
+    ]
+    .iter()
+    .map(|line| line.parse().unwrap())
+    .collect();
+    let warnings = System::from_slice(&leaves).validate();
+    assert_eq!(
+        warnings,
+        vec![
+            ValidationWarning::ReservedBitsSet {
+                cpu: 0,
+                leaf: LeafID { eax: 0x0000_0001, ecx: 0 },
+                register: RegisterName::EAX,
+                bits: 0x8000_0000,
+            },
+            ValidationWarning::LeafAboveMax {
+                cpu: 0,
+                leaf: LeafID { eax: 0x0000_0002, ecx: 0 },
+                max_leaf: 0x0000_0001,
+            },
+            ValidationWarning::EmptyBase {
+                cpu: 0,
+                base: 0x4000_0000,
+            },
+        ]
+    );
+    assert_eq!(
+        warnings[0].to_string(),
+        "CPU 0: leaf 00000001:00 has reserved bits 0x80000000 set in EAX"
+    );
+}
+
+#[test]
+fn import_deduplicated() {
+    let path = dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt");
+    let full = System::from_file(&path).unwrap().with_decoded();
+    let dedup = System::from_file_deduplicated(&path).unwrap().with_decoded();
+    assert_eq!(dedup.cpus.len(), 256);
+    assert_eq!(dedup.cpu_count, dedup.cpus.len());
+    assert!(dedup.cpus.iter().all(|cpu| cpu.shares_leaves_with(&dedup.cpus[0])));
+    assert!(!full.cpus[1].shares_leaves_with(&full.cpus[0]));
+    assert_eq!(dedup.cpus, full.cpus);
+    assert_eq!(dedup.cpu(5).unwrap().index, 5);
+    assert_eq!(dedup.cpu(5).unwrap().apic_id(), full.cpu(5).unwrap().apic_id());
+    assert_eq!(dedup.apic_id_table(), full.apic_id_table());
+    assert_eq!(dedup.cache_domains(), full.cache_domains());
+    assert_eq!(dedup.topology, full.topology);
+    assert_eq!(dedup.name_string, full.name_string);
+    assert_eq!(dedup.caches.0, full.caches.0);
+    assert_eq!(dedup.features.len(), full.features.len());
+
+    let mut full_dump: Vec<u8> = vec![];
+    full.write_dump(&mut full_dump).unwrap();
+    let mut dedup_dump: Vec<u8> = vec![];
+    dedup.write_dump(&mut dedup_dump).unwrap();
+    assert_eq!(dedup_dump, full_dump);
+
+    // Sorting keeps the leaves shared.
+    let sorted = System::from_file_deduplicated(&path).unwrap().sorted();
+    assert!(sorted.cpus[1].shares_leaves_with(&sorted.cpus[0]));
+    assert_eq!(sorted.cpus, System::from_file(&path).unwrap().sorted().cpus);
+
+    // Modifying a processor's leaves only affects that processor.
+    let mut dedup = System::from_file_deduplicated(&path).unwrap();
+    dedup.cpus[1].get_subleaf_mut(0x0000_0007, 0).unwrap().output.ebx = 0;
+    assert!(!dedup.cpus[1].shares_leaves_with(&dedup.cpus[0]));
+    assert!(dedup.cpus[2].shares_leaves_with(&dedup.cpus[0]));
+    assert_eq!(dedup.cpus[0].get_subleaf(0x0000_0007, 0), full.cpus[0].get_subleaf(0x0000_0007, 0));
+    assert_eq!(dedup.cpus[1].get_subleaf(0x0000_0001, 0), full.cpus[1].get_subleaf(0x0000_0001, 0));
+
+    // Processors differing in more than their APIC IDs don't share leaves.
+    let path = dump_path("GenuineIntel/GenuineIntel00806A1_Lakefield_CPUID.txt");
+    let dedup = System::from_file_deduplicated(&path).unwrap();
+    assert!(!dedup.cpus.iter().all(|cpu| cpu.shares_leaves_with(&dedup.cpus[0])));
+}
+
+#[test]
+fn override_leaves() {
+    let path = dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt");
+    let mut system = System::from_file(&path).unwrap().with_decoded();
+    assert!(system.features.iter().any(|feature| feature.shortname == "AVX2"));
+
+    // Mask off AVX2 (leaf 0x0000_0007, ebx bit 5) and decode again.
+    system.cpus[0].get_subleaf_mut(0x0000_0007, 0).unwrap().output.ebx &= !(1 << 5);
+    system.decode();
+    assert!(!system.features.iter().any(|feature| feature.shortname == "AVX2"));
+    assert!(system.cpus[0].get_subleaf_mut(0x0000_0007, 0x42).is_none());
+
+    // Replace the vendor string, and add a leaf which wasn't captured.
+    let processor = &mut system.cpus[0];
+    let leaves = processor.leaves.len();
+    processor.set_leaf("CPUID 00000000:00 = 0000001b 68747541 444d4163 69746e65".parse().unwrap());
+    assert_eq!(processor.leaves.len(), leaves);
+    assert_eq!(processor.vendor, VendorMask::INTEL);
+    processor.re_fill();
+    assert_eq!(processor.vendor, VendorMask::AMD);
+    assert_eq!(processor.signature.family, 0x6);
+
+    processor.set_leaf("CPUID 4fffffff:00 = 00000001 00000002 00000003 00000004".parse().unwrap());
+    assert_eq!(processor.leaves.len(), leaves + 1);
+    assert_eq!(processor.get_subleaf(0x4FFF_FFFF, 0).unwrap().output.edx, 4);
+}
+
+#[test]
+fn pconfig_targets() {
+    let path = dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt");
+    let mut system = System::from_file(&path).unwrap().with_decoded();
+    assert_eq!(system.pconfig, None);
+
+    // Advertise PCONFIG (leaf 0x0000_0007, edx bit 18) with MKTME as a target.
+    let processor = &mut system.cpus[0];
+    processor.get_subleaf_mut(0x0000_0007, 0).unwrap().output.edx |= 1 << 18;
+    processor.set_leaf("CPUID 0000001b:00 = 00000001 00000001 00000000 00000000".parse().unwrap());
+    processor.set_leaf("CPUID 0000001b:01 = 00000000 00000000 00000000 00000000".parse().unwrap());
+    system.decode();
+    assert_eq!(system.pconfig, Some(PconfigInfo { targets: vec![1] }));
+    assert_eq!(system.pconfig.unwrap().to_string(), "MKTME");
+}
+
+#[test]
+fn sev_c_bit() {
+    let path = dump_path("AuthenticAMD/AuthenticAMD0600F01_K15_Bulldozer_CPUID.txt");
+    let mut system = System::from_file(&path).unwrap().with_decoded();
+    assert_eq!(system.sev, None);
+
+    // SME only, with the C-bit at 43 and a 1-bit address space reduction.
+    for cpu in system.cpus.iter_mut() {
+        cpu.set_leaf("CPUID 8000001f:00 = 00000001 0000006b 00000000 00000000".parse().unwrap());
+    }
+    system.decode();
+    let sev = system.sev.clone().unwrap();
+    assert!(sev.sme);
+    assert!(!sev.sev);
+    assert_eq!(sev.cbit_position, 43);
+    assert_eq!(sev.phys_addr_reduction, 1);
+    assert_eq!(sev.to_string(), "SME, C-bit 43, 1-bit address reduction, 0 encrypted guests");
+
+    // Without SME or SEV the encryption fields are meaningless.
+    for cpu in system.cpus.iter_mut() {
+        cpu.set_leaf("CPUID 8000001f:00 = 00000000 0000006b 00000000 00000000".parse().unwrap());
+    }
+    system.decode();
+    assert_eq!(system.sev, None);
+
+    // Hygon processors report the same leaf.
+    let path = dump_path("HygonGenuine/HygonGenuine0900F02_Hygon_CPUID.txt");
+    let mut system = System::from_file(&path).unwrap().with_decoded();
+    assert_eq!(system.sev, None);
+    for cpu in system.cpus.iter_mut() {
+        cpu.set_leaf("CPUID 8000001f:00 = 00000001 0000006b 00000000 00000000".parse().unwrap());
+    }
+    system.decode();
+    assert_eq!(system.sev.unwrap().cbit_position, 43);
+}
+
+#[test]
+fn vendor_override() {
+    let path = dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt");
+    let system = System::from_file(&path).unwrap().with_decoded();
+    assert!(system.features.iter().any(|feature| feature.shortname == "PQM"));
+    let amd_caches = system.caches.len();
+
+    let mut forced = system.with_vendor_override(VendorMask::INTEL);
+    assert_eq!(forced.vendor, VendorMask::INTEL);
+    assert!(forced.features.iter().any(|feature| feature.shortname == "RDT-M"));
+    assert!(!forced.features.iter().any(|feature| feature.shortname == "PQM"));
+    assert_ne!(forced.caches.len(), amd_caches);
+
+    // Decoding again goes back to the detected vendor.
+    forced.decode();
+    assert_eq!(forced.vendor, VendorMask::AMD);
+    assert_eq!(forced.caches.len(), amd_caches);
+}
+
+#[test]
+fn walk_range_bounds() {
+    let base = |line: &str| line.parse::<RawCPUIDResponse>().unwrap();
+    assert_eq!(
+        walk_range(&base("CPUID 00000000:00 = 0000001b 756e6547 6c65746e 49656e69")),
+        0x0000_0000..0x0000_001c
+    );
+    // Maximum leaf below the base.
+    assert!(walk_range(&base("CPUID 80000000:00 = 00000010 00000000 00000000 00000000")).is_empty());
+    // Maximum leaf far beyond the base.
+    assert!(walk_range(&base("CPUID 40000000:00 = ffffffff 00000000 00000000 00000000")).is_empty());
+    // In range, but implausibly large.
+    assert_eq!(
+        walk_range(&base("CPUID 40000000:00 = 4000ffff 00000000 00000000 00000000")),
+        0x4000_0000..0x4000_0400
+    );
+}
+
+#[test]
+fn topology_tree() {
+    let path = dump_path("GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt");
+    let system = System::from_file(&path).unwrap().with_decoded();
+    let tree = system.topology_tree();
+    assert!(tree.starts_with("Socket 0\n  Core 0\n    Thread 0: CPU 0\n"));
+    assert!(tree.contains("Socket 1\n  Core 0\n    Thread 0: CPU 1\n"));
+    assert_eq!(tree.lines().filter(|line| line.starts_with("Socket")).count(), 2);
+    assert_eq!(tree.lines().filter(|line| line.starts_with("  Core")).count(), 8);
+    assert_eq!(tree.lines().filter(|line| line.starts_with("    Thread")).count(), 16);
+
+    let dot = system.topology_dot();
+    assert!(dot.starts_with("graph topology {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains("    socket1 -- socket1_core0;\n"));
+    assert!(dot.contains("    cpu1 [label=\"Thread 0\\nCPU 1\"];\n"));
+    assert!(dot.contains("    socket1_core0 -- cpu1;\n"));
+
+    let path = dump_path("GenuineIntel/GenuineIntel00806A1_Lakefield_CPUID.txt");
+    let system = System::from_file(&path).unwrap().with_decoded();
+    let tree = system.topology_tree();
+    assert!(tree.contains(": Atom (E-core)\n"));
+    assert!(tree.contains(": Core (P-core)\n"));
+
+    // Topology IDs are only available once decoded, and only with leaf 0x0000_000B.
+    let system = System::from_file(&path).unwrap();
+    assert_eq!(system.topology_tree(), "");
+    assert_eq!(system.topology_dot(), "graph topology {\n}\n");
+    let path = dump_path("CyrixInstead/CyrixInstead0000520_6x86_CPUID.txt");
+    let system = System::from_file(&path).unwrap().with_decoded();
+    assert_eq!(system.topology_tree(), "");
+}
+
+#[test]
+fn import_multiple_files() {
+    let path = dump_path("GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt");
+    let single = System::from_file(&path).unwrap().with_decoded();
+    let merged = System::from_files(&[&path, &path]).unwrap();
+    assert_eq!(merged.cpu_count, 32);
+    let indices: Vec<u32> = merged.cpus.iter().map(|cpu| cpu.index).collect();
+    assert_eq!(indices, (0..32).collect::<Vec<u32>>());
+    assert_eq!(merged.vendor, VendorMask::INTEL);
+    assert_eq!(merged.name_string, single.name_string);
+    assert_eq!(merged.features.len(), single.features.len());
+    assert_eq!(merged.cpus[16].signature, single.cpus[0].signature);
+
+    assert!(System::from_files(&[&path, "/nonexistent/dump.txt"]).is_err());
+
+    let other = dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt");
+    let error = System::from_files(&[&path, &other]).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(error.to_string().starts_with(&other));
+}
+
+#[test]
+fn partial_processor_name() {
+    let file = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt")).unwrap();
+    let leaves: Vec<RawCPUIDResponse> = file.cpus[0]
+        .leaves
+        .iter()
+        .filter(|leaf| leaf.input.eax != 0x8000_0004)
+        .cloned()
+        .collect();
+    let system = System::from_slice(&leaves).with_decoded();
+    assert_eq!(system.name_string, "11th Gen Intel(R) Core(TM) i5-11");
+
+    let leaves: Vec<RawCPUIDResponse> = file.cpus[0]
+        .leaves
+        .iter()
+        .filter(|leaf| leaf.input.eax < 0x8000_0002 || leaf.input.eax > 0x8000_0004)
+        .cloned()
+        .collect();
+    let system = System::from_slice(&leaves).with_decoded();
+    assert_eq!(system.name_string, "");
+}
+
+#[test]
+fn decode_kvm_features() {
+    let host = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt")).unwrap();
+    let leaves = guest_leaves(
+        &host,
+        &[
+            (
+                0x4000_0000,
+                Registers {
+                    eax: 0x4000_0001,
+                    ebx: 0x4b4d_564b,
+                    ecx: 0x564b_4d56,
+                    edx: 0x0000_004d,
+                },
+            ),
+            (
+                0x4000_0001,
+                Registers {
+                    eax: 0x0100_7afb,
+                    ebx: 0,
+                    ecx: 0,
+                    edx: 0,
+                },
+            ),
+        ],
+    );
+    let system = System::from_slice(&leaves).with_decoded();
+    assert!(system.vendor.contains(VendorMask::KVM));
+    assert_eq!(
+        system.kvm,
+        Some(KvmFeatures {
+            clocksource: true,
+            nop_io_delay: true,
+            mmu_op: false,
+            clocksource2: true,
+            async_pf: true,
+            steal_time: true,
+            pv_eoi: true,
+            pv_unhalt: true,
+            pv_tlb_flush: true,
+            async_pf_vmexit: false,
+            pv_send_ipi: true,
+            poll_control: true,
+            pv_sched_yield: true,
+            async_pf_int: true,
+            msi_ext_dest_id: false,
+            hc_map_gpa_range: false,
+            migration_control: false,
+            clocksource_stable: true,
+        })
+    );
+    let kvm_features: Vec<_> = system
+        .features
+        .iter()
+        .filter(|feature| feature.leaf_name() == "KVM Paravirtualization")
+        .collect();
+    assert_eq!(kvm_features.len(), 13);
+
+    let host = host.with_decoded();
+    assert_eq!(host.kvm, None);
+}
+
+#[test]
+fn decode_hypervisor_timing() {
+    let host = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt")).unwrap();
+    let vmware = Registers {
+        eax: 0x4000_0010,
+        ebx: 0x6177_4d56,
+        ecx: 0x4d56_6572,
+        edx: 0x6572_6177,
+    };
+    let leaves = guest_leaves(
+        &host,
+        &[
+            (0x4000_0000, vmware.clone()),
+            (
+                0x4000_0010,
+                Registers {
+                    eax: 2_600_000,
+                    ebx: 1_000_000,
+                    ecx: 0,
+                    edx: 0,
+                },
+            ),
+        ],
+    );
+    let system = System::from_slice(&leaves).with_decoded();
+    assert!(system.vendor.contains(VendorMask::VMWARE));
+    assert_eq!(
+        system.hypervisor_timing,
+        Some(HypervisorTiming {
+            tsc_khz: 2_600_000,
+            apic_khz: 1_000_000,
+        })
+    );
+
+    // Leaf 0x4000_0010 is ignored when the hypervisor's maximum leaf is lower.
+    let leaves = guest_leaves(
+        &host,
+        &[
+            (
+                0x4000_0000,
+                Registers {
+                    eax: 0x4000_0001,
+                    ..vmware
+                },
+            ),
+            (
+                0x4000_0010,
+                Registers {
+                    eax: 2_600_000,
+                    ebx: 1_000_000,
+                    ecx: 0,
+                    edx: 0,
+                },
+            ),
+        ],
+    );
+    let system = System::from_slice(&leaves).with_decoded();
+    assert_eq!(system.hypervisor_timing, None);
+
+    let host = host.with_decoded();
+    assert_eq!(host.hypervisor_timing, None);
+}
+
+#[test]
+fn decode_vmware() {
+    let host = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt")).unwrap();
+    let vmware = Registers {
+        eax: 0x4000_0010,
+        ebx: 0x6177_4d56,
+        ecx: 0x4d56_6572,
+        edx: 0x6572_6177,
+    };
+    let leaves = guest_leaves(
+        &host,
+        &[
+            (0x4000_0000, vmware.clone()),
+            (
+                0x4000_0010,
+                Registers {
+                    eax: 2_600_000,
+                    ebx: 1_000_000,
+                    ecx: 0x0000_0002,
+                    edx: 0,
+                },
+            ),
+        ],
+    );
+    let system = System::from_slice(&leaves).with_decoded();
+    assert_eq!(
+        system.vmware,
+        Some(VmwareInfo {
+            timing: Some(HypervisorTiming {
+                tsc_khz: 2_600_000,
+                apic_khz: 1_000_000,
+            }),
+            vmcall: true,
+            vmmcall: false,
+        })
+    );
+    assert_eq!(
+        system.vmware.unwrap().to_string(),
+        "TSC 2600000 kHz, APIC bus 1000000 kHz, VMCALL hypercalls"
+    );
+
+    // Older VMware releases stop at leaf 0x4000_0001 and only support the
+    // backdoor I/O port.
+    let leaves = guest_leaves(
+        &host,
+        &[(
+            0x4000_0000,
+            Registers {
+                eax: 0x4000_0001,
+                ..vmware
+            },
+        )],
+    );
+    let system = System::from_slice(&leaves).with_decoded();
+    assert_eq!(system.vmware, Some(VmwareInfo::default()));
+    assert_eq!(system.vmware.unwrap().to_string(), "backdoor I/O port hypercalls");
+
+    let host = host.with_decoded();
+    assert_eq!(host.vmware, None);
+}
+
+#[test]
+fn feature_rows() {
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let rows = system.features.rows();
+    assert_eq!(rows.len(), system.features.len());
+    assert!(rows.contains(&FeatureRow {
+        name: "Advanced Vector Extensions 2.0",
+        shortname: "AVX2",
+        leaf: LeafID { eax: 0x0000_0007, ecx: 0 },
+        register: RegisterName::EBX,
+        bit: 5,
+    }));
+    for (row, feature) in rows.iter().zip(system.features.iter()) {
+        assert_eq!(row.name, feature.name);
+        assert_eq!(row.leaf, feature.leaf);
+    }
+}
+
+#[test]
+fn rustc_target_features() {
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(
+        system.rustc_target_features(),
+        "+fxsr,+sse,+sse2,+sse3,+pclmulqdq,+ssse3,+fma,+cmpxchg16b,+sse4.1,+sse4.2,+movbe,+popcnt,+aes,+xsave,\
+         +avx,+f16c,+rdrand,+bmi1,+avx2,+bmi2,+avx512f,+avx512dq,+rdseed,+adx,+avx512ifma,+avx512cd,+sha,\
+         +avx512bw,+avx512vl,+avx512vbmi,+avx512vbmi2,+gfni,+vaes,+vpclmulqdq,+avx512vnni,+avx512bitalg,\
+         +avx512vpopcntdq,+lzcnt"
+    );
+
+    let system = System::from_file(&dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(
+        system.rustc_target_features(),
+        "+fxsr,+sse,+sse2,+sse3,+pclmulqdq,+ssse3,+fma,+cmpxchg16b,+sse4.1,+sse4.2,+movbe,+popcnt,+aes,+xsave,\
+         +avx,+f16c,+rdrand,+bmi1,+avx2,+bmi2,+rdseed,+adx,+sha,+lzcnt,+sse4a"
+    );
+}
+
+#[test]
+fn diff_systems() {
+    let file = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt")).unwrap();
+    let before = System::from_slice(&file.cpus[0].leaves).with_decoded();
+    let diff = before.diff(&before);
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "No differences\n");
+
+    // Drop a leaf, clear AVX2 and add a new subleaf.
+    let mut leaves: Vec<RawCPUIDResponse> = file.cpus[0]
+        .leaves
+        .iter()
+        .filter(|leaf| leaf.input != LeafID { eax: 0x8000_0002, ecx: 0 })
+        .cloned()
+        .collect();
+    for leaf in leaves.iter_mut() {
+        if leaf.input == (LeafID { eax: 0x0000_0007, ecx: 0 }) {
+            leaf.output.ebx &= !(1 << 5);
+        }
+    }
+    leaves.push(RawCPUIDResponse {
+        input: LeafID { eax: 0x0000_0007, ecx: 5 },
+        output: Registers {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+    });
+    let after = System::from_slice(&leaves).with_decoded();
+    let diff = before.diff(&after);
+    assert!(!diff.is_empty());
+    assert_eq!(diff.removed_leaves.len(), 1);
+    assert_eq!(diff.removed_leaves[0].input, LeafID { eax: 0x8000_0002, ecx: 0 });
+    assert_eq!(diff.added_leaves.len(), 1);
+    assert_eq!(diff.added_leaves[0].input, LeafID { eax: 0x0000_0007, ecx: 5 });
+    assert_eq!(diff.changed_leaves.len(), 1);
+    assert_eq!(diff.changed_leaves[0].0.output.ebx, 0xf3bf_a7eb);
+    assert_eq!(diff.changed_leaves[0].1.output.ebx, 0xf3bf_a7cb);
+    assert_eq!(diff.removed_features.len(), 1);
+    assert_eq!(diff.removed_features[0].shortname, "AVX2");
+    assert!(diff.added_features.is_empty());
+    assert!(diff.added_caches.is_empty());
+    assert!(diff.removed_caches.is_empty());
+    assert!(diff
+        .to_string()
+        .contains("- Leaf 00000007:00, register EBX, bit 5: Advanced Vector Extensions 2.0 (AVX2)\n"));
+}
+
+#[test]
+fn features_sorted() {
+    for path in [
+        "GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt",
+        "AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt",
+    ]
+    .iter()
+    {
+        let system = System::from_file(&dump_path(path)).unwrap().with_decoded();
+        for pair in system.features.0.windows(2) {
+            let (a, b) = (&pair[0], &pair[1]);
+            let key_a = (a.leaf.eax, a.leaf.ecx, a.register as u8, a.bit);
+            let key_b = (b.leaf.eax, b.leaf.ecx, b.register as u8, b.bit);
+            assert!(key_a < key_b, "{:?} is not sorted before {:?}", key_a, key_b);
+        }
+    }
+}
+
+#[test]
+fn vendor_specific_feature_names() {
+    let shortnames = |path: &str| -> Vec<&'static str> {
+        let system = System::from_file(&dump_path(path)).unwrap().with_decoded();
+        system.features.iter().map(|feature| feature.shortname).collect()
+    };
+
+    let amd = shortnames("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt");
+    for shortname in ["PQM", "PQE", "EffFreq"].iter() {
+        assert!(amd.contains(shortname), "{} missing on AMD", shortname);
+    }
+    for shortname in ["RDT-M", "RDT-A", "HCFC"].iter() {
+        assert!(!amd.contains(shortname), "{} reported on AMD", shortname);
+    }
+
+    let intel = shortnames("GenuineIntel/GenuineIntel00406F1_BroadwellE_CPUID.txt");
+    for shortname in ["RDT-M", "RDT-A", "HCFC"].iter() {
+        assert!(intel.contains(shortname), "{} missing on Intel", shortname);
+    }
+    for shortname in ["PQM", "PQE", "EffFreq"].iter() {
+        assert!(!intel.contains(shortname), "{} reported on Intel", shortname);
+    }
+}
+
+#[test]
+fn amd_performance_optimization_features() {
+    let shortnames = |path: &str| -> Vec<&'static str> {
+        let system = System::from_file(&dump_path(path)).unwrap().with_decoded();
+        system
+            .features
+            .iter()
+            .filter(|feature| feature.leaf.eax == 0x8000_001A)
+            .map(|feature| feature.shortname)
+            .collect()
+    };
+
+    // Bulldozer splits 256-bit operations into two 128-bit halves.
+    assert_eq!(
+        shortnames("AuthenticAMD/AuthenticAMD0600F01_K15_Bulldozer_CPUID.txt"),
+        vec!["FP128", "MOVU"]
+    );
+    // Zen 2 executes 256-bit operations at full width.
+    assert_eq!(
+        shortnames("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt"),
+        vec!["MOVU", "FP256"]
+    );
+    assert!(shortnames("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt").is_empty());
+}
+
+#[test]
+fn amd_ibs_features() {
+    let system = System::from_file(&dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let ibs: Vec<&str> = system
+        .features
+        .iter()
+        .filter(|feature| feature.leaf.eax == 0x8000_001B)
+        .map(|feature| feature.shortname)
+        .collect();
+    assert_eq!(
+        ibs,
+        vec![
+            "IBSFFV",
+            "FetchSam",
+            "OpSam",
+            "RdWrOpCnt",
+            "OpCnt",
+            "BrnTrgt",
+            "OpCntExt",
+            "RipInvalidChk",
+            "OpBrnFuse",
+            "IbsFetchCtlExtd",
+        ]
+    );
+    assert!(!system.unknown_features.iter().any(|(leaf, _, _)| leaf.eax == 0x8000_001B));
+}
+
+#[test]
+fn features_by_category() {
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let crypto = system.features.by_category(FeatureCategory::Crypto);
+    assert!(crypto.iter().all(|feature| feature.category == FeatureCategory::Crypto));
+    for shortname in ["AES-NI", "PCLMULQDQ", "RDRAND", "RDSEED", "SHA", "VAES"].iter() {
+        assert!(crypto.iter().any(|feature| feature.shortname == *shortname));
+    }
+    assert!(!crypto.iter().any(|feature| feature.shortname == "AVX2"));
+
+    let simd = system.features.by_category(FeatureCategory::Simd);
+    assert!(simd.iter().any(|feature| feature.shortname == "AVX2"));
+
+    let total: usize = [
+        FeatureCategory::Simd,
+        FeatureCategory::Crypto,
+        FeatureCategory::Security,
+        FeatureCategory::Virtualization,
+        FeatureCategory::Power,
+        FeatureCategory::Memory,
+        FeatureCategory::Debug,
+        FeatureCategory::Misc,
+    ]
+    .iter()
+    .map(|category| system.features.by_category(*category).len())
+    .sum();
+    assert_eq!(total, system.features.len());
+
+    assert_eq!("crypto".parse::<FeatureCategory>().unwrap(), FeatureCategory::Crypto);
+    assert_eq!("SIMD".parse::<FeatureCategory>().unwrap(), FeatureCategory::Simd);
+    assert_eq!(FeatureCategory::Simd.to_string(), "SIMD");
+    assert!("bogus".parse::<FeatureCategory>().is_err());
+}
+
+#[test]
+fn cache_domains() {
+    // APIC IDs on this dump are interleaved, so CPU n and n + 4 are siblings.
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let domains = system.cache_domains();
+    let l2: Vec<_> = domains.iter().filter(|domain| domain.cache.level == CacheLevel::L2).collect();
+    assert_eq!(l2.len(), 4);
+    assert_eq!(l2[0].cpus, vec![0, 4]);
+    assert_eq!(l2[3].domain, 3);
+    assert_eq!(l2[3].cpus, vec![3, 7]);
+    assert_eq!(l2[1].to_string(), "L2 unified cache domain 1: CPUs 1,5");
+    let l3: Vec<_> = domains.iter().filter(|domain| domain.cache.level == CacheLevel::L3).collect();
+    assert_eq!(l3.len(), 1);
+    assert_eq!(l3[0].to_string(), "L3 unified cache domain 0: CPUs 0-7");
+
+    // Each CCX has its own L3.
+    let system = System::from_file(&dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let domains = system.cache_domains();
+    let l3: Vec<_> = domains.iter().filter(|domain| domain.cache.level == CacheLevel::L3).collect();
+    assert_eq!(l3.len(), 32);
+    assert!(l3.iter().all(|domain| domain.cpus.len() == 8));
+    assert_eq!(l3[1].to_string(), "L3 unified cache domain 1: CPUs 8-15");
+    let l1: Vec<_> = domains
+        .iter()
+        .filter(|domain| domain.cache.level == CacheLevel::L1 && domain.cache.cachetype == CacheType::Data)
+        .collect();
+    assert_eq!(l1.len(), 128);
+    assert_eq!(l1[127].cpus, vec![254, 255]);
+}
+
+#[test]
+fn dat_reserved_subleaves() {
+    let tlbs = |system: &System| system.caches.iter().filter(|cache| cache.cachetype.is_tlb()).count();
+    let mut system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(tlbs(&system), 8);
+
+    // A reserved subleaf in the middle is skipped without ending the walk, and
+    // subleaves beyond the maximum in subleaf 0 are ignored.
+    for cpu in system.cpus.iter_mut() {
+        cpu.get_subleaf_mut(0x0000_0018, 4).unwrap().output.edx = 0;
+        cpu.set_leaf("CPUID 00000018:09 = 00000000 00080001 00000010 00004022".parse().unwrap());
+    }
+    system.decode();
+    assert_eq!(tlbs(&system), 7);
+    assert!(system
+        .caches
+        .iter()
+        .any(|cache| cache.level == CacheLevel::L2 && cache.cachetype == CacheType::SharedTLB));
+
+    for cpu in system.cpus.iter_mut() {
+        cpu.get_subleaf_mut(0x0000_0018, 0).unwrap().output.eax = 3;
+    }
+    system.decode();
+    assert_eq!(tlbs(&system), 3);
+}
+
+#[test]
+fn cache_effective_ways() {
+    assert_eq!(CacheAssociativity::from_identifier(0x00).effective_ways(Some(64)), None);
+    assert_eq!(CacheAssociativity::from_identifier(0x01).effective_ways(None), Some(1));
+    assert_eq!(CacheAssociativity::from_identifier(0x01).effective_ways(Some(64)), Some(1));
+    assert_eq!(CacheAssociativity::from_identifier(0x08).effective_ways(None), Some(8));
+    assert_eq!(CacheAssociativity::from_identifier(0x10).effective_ways(Some(64)), Some(16));
+    assert_eq!(CacheAssociativity::from_identifier(0xFF).effective_ways(None), None);
+
+    // A fully associative 48-entry TLB, and a 4KB cache with 64-byte lines.
+    assert_eq!(CacheAssociativity::from_identifier(0xFF).effective_ways(Some(48)), Some(48));
+    let blocks = 4 * 1024 / 64;
+    assert_eq!(CacheAssociativity::from_identifier(0xFF).effective_ways(Some(blocks)), Some(64));
+}
+
+#[test]
+fn cache_exact_equality() {
+    let system = System::from_file(&dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let cache = system.caches.iter().find(|cache| cache.level == CacheLevel::L3).unwrap();
+    assert!(cache.eq_exact(&cache.clone()));
+
+    let mut ecc = cache.clone();
+    ecc.flags = CacheFlags::new().with_ecc(!cache.flags.ecc());
+    assert!(*cache == ecc);
+    assert!(!cache.eq_exact(&ecc));
+
+    let mut shared = cache.clone();
+    shared.max_threads_sharing += 1;
+    assert!(*cache == shared);
+    assert!(!cache.eq_exact(&shared));
+}
+
+#[test]
+fn cache_type_and_level_names() {
+    let cachetypes = [
+        CacheType::Unknown,
+        CacheType::Code,
+        CacheType::Trace,
+        CacheType::Data,
+        CacheType::Unified,
+        CacheType::DataTLB,
+        CacheType::CodeTLB,
+        CacheType::SharedTLB,
+        CacheType::LoadOnlyTLB,
+        CacheType::StoreOnlyTLB,
+    ];
+    for cachetype in cachetypes.iter() {
+        assert_eq!(cachetype.to_string().parse::<CacheType>().unwrap(), *cachetype);
+        assert_eq!(cachetype.to_string().to_uppercase().parse::<CacheType>().unwrap(), *cachetype);
+    }
+    let levels = [
+        CacheLevel::L0,
+        CacheLevel::L1,
+        CacheLevel::Unknown,
+        CacheLevel::L2,
+        CacheLevel::L3,
+        CacheLevel::L4,
+    ];
+    for level in levels.iter() {
+        assert_eq!(level.to_string().parse::<CacheLevel>().unwrap(), *level);
+        assert_eq!(level.to_string().to_lowercase().parse::<CacheLevel>().unwrap(), *level);
+    }
+
+    assert_eq!("data cache".parse::<CacheType>().unwrap(), CacheType::Data);
+    assert_eq!("l2".parse::<CacheLevel>().unwrap(), CacheLevel::L2);
+    assert_eq!(CacheLevel::L3.to_string(), "L3");
+    assert!("data".parse::<CacheType>().is_err());
+    assert!("L5".parse::<CacheLevel>().is_err());
+}
+
+static UNKNOWN_REGISTER_LEAVES: [FeatureLeaf; 1] = [FeatureLeaf {
+    leaf: LeafID { eax: 0x0000_0001, ecx: 0 },
+    vendor_mask: VendorMask::INTEL,
+    register: RegisterName::Unknown,
+    bits: &CUSTOM_FEATURES_0000_0001_EBX,
+}];
+
+#[test]
+fn invalid_register_does_not_panic() {
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let leaf = system.cpus[0].get_subleaf(0x0000_0001, 0).unwrap();
+    assert_eq!(leaf.output.register(RegisterName::ECX), Ok(0x7ffa_fbbf));
+    assert_eq!(
+        leaf.output.register(RegisterName::Unknown),
+        Err(CpuidError::InvalidRegister(RegisterName::Unknown))
+    );
+    assert!(!system.cpus[0].has_feature_bit(0x0000_0001, 0, RegisterName::ECX, 31));
+    assert!(system.cpus[0].has_feature_bit(0x0000_0001, 0, RegisterName::ECX, 0));
+    assert!(!system.cpus[0].has_feature_bit(0x0000_0001, 0, RegisterName::Unknown, 0));
+    assert!(!system.cpus[0].has_feature_bit(0x0000_0001, 0, RegisterName::ECX, 32));
+    let features = system.features_with(&UNKNOWN_REGISTER_LEAVES);
+    assert_eq!(features.len(), system.features.len());
+}
+
+#[test]
+fn processor_serial_number() {
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel0000683_P3_Coppermine_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(system.cpus[0].serial_number(), Some([0x0000_0683, 0x0000_e4ca, 0x6107_fb5a]));
+    assert_eq!(system.cpus[1].serial_number(), Some([0x0000_0683, 0x0003_e8aa, 0xaaf0_54d8]));
+
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(system.cpus[0].serial_number(), None);
+}
+
+#[test]
+fn query_single_leaf() {
+    let mut system =
+        System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt")).unwrap();
+    let count = system.cpus[0].leaves.len();
+
+    // Dumps are never mixed with leaves of the local CPU.
+    let leaf = system.query(0x0000_0001, 0);
+    assert_eq!(leaf.output.eax, 0x0008_06c1);
+    assert_eq!(system.try_query(0x0000_0001, 0), Some(leaf));
+    let leaf = system.query(0x0000_0000, 0x1234);
+    assert_eq!(leaf.input, LeafID { eax: 0x0000_0000, ecx: 0x1234 });
+    assert_eq!(leaf.output, Registers::new(0, 0, 0, 0));
+    assert_eq!(system.try_query(0x0000_0000, 0x1234), None);
+    assert_eq!(system.cpus[0].try_query(0x0000_0000, 0x1234), None);
+    assert_eq!(system.cpus[0].leaves.len(), count);
+    assert_eq!(System::from_slice(&[]).try_query(0x0000_0000, 0), None);
+    let mut processor = Processor::from_leaves(system.cpus[0].leaves.clone());
+    assert_eq!(processor.try_query(0x0000_0000, 0x1234), None);
+    assert_eq!(processor.leaves.len(), count);
+
+    let filter = LeafFilter::new().allow(0x0000_0000, 0x0000_0000);
+    let mut system = System::from_local_filtered(&filter);
+    let count = system.cpus[0].leaves.len();
+    let leaf = system.query(0x0000_0000, 0);
+    assert_eq!(system.cpus[0].get_subleaf(0x0000_0000, 0), Some(&leaf));
+    assert_eq!(system.cpus[0].leaves.len(), count);
+
+    let leaf = system.try_query(0x0000_0000, 0x1234).unwrap();
+    assert_eq!(leaf.input, LeafID { eax: 0x0000_0000, ecx: 0x1234 });
+    assert_eq!(system.cpus[0].leaves.len(), count + 1);
+    assert_eq!(system.cpus[0].get_subleaf(0x0000_0000, 0x1234).unwrap().output, leaf.output);
+    system.query(0x0000_0000, 0x1234);
+    assert_eq!(system.cpus[0].leaves.len(), count + 1);
+}
+
+#[test]
+fn anonymize_dump() {
+    let system =
+        System::from_file(&dump_path("GenuineIntel/GenuineIntel0000683_P3_Coppermine_CPUID.txt")).unwrap();
+    let leaf = system.cpus[1].get_subleaf(0x0000_0003, 0).unwrap();
+    assert_eq!(leaf.output, Registers::new(0, 0, 0xaaf0_54d8, 0x0003_e8aa));
+    let anonymized = system.anonymized();
+    assert_eq!(anonymized.cpus.len(), system.cpus.len());
+    assert_eq!(anonymized.cpu_count, system.cpu_count);
+    for cpu in anonymized.cpus.iter() {
+        let leaf = cpu.get_subleaf(0x0000_0003, 0).unwrap();
+        assert_eq!(leaf.output, Registers::new(0, 0, 0, 0));
+    }
+
+    let system =
+        System::from_file(&dump_path("GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt")).unwrap();
+    let anonymized = system.anonymized().with_decoded();
+    let leaf = anonymized.cpus[1].get_subleaf(0x0000_0001, 0).unwrap();
+    assert_eq!(leaf.output, Registers::new(0x0001_06a2, 0x0010_0800, 0x00bc_e3bd, 0xbfeb_fbff));
+    assert_eq!(anonymized.cpus[1].index, system.cpus[1].index);
+    assert_eq!(anonymized.name_string, "Genuine Intel(R) CPU @ 0000 @ 2.67GHz");
+
+    let system =
+        System::from_file(&dump_path("GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt")).unwrap();
+    let anonymized = system.anonymized_with(true).with_decoded();
+    assert_eq!(anonymized.name_string, "");
+}
+
+#[test]
+fn vendor_mask_names() {
+    assert_eq!(VendorMask::UNKNOWN.to_string(), "unknown");
+    assert_eq!("unknown".parse::<VendorMask>().unwrap(), VendorMask::UNKNOWN);
+    assert_eq!("".parse::<VendorMask>().unwrap(), VendorMask::UNKNOWN);
+    assert_eq!(
+        (VendorMask::VIRTUAL_CPU | VendorMask::INTEL).to_string(),
+        "Virtual CPU, GenuineIntel"
+    );
+    assert_eq!(VendorMask::INTELAMD.to_string(), "GenuineIntel/AuthenticAMD");
+    assert_eq!(
+        (VendorMask::INTEL | VendorMask::AMD).to_string(),
+        "GenuineIntel, AuthenticAMD"
+    );
+    assert_eq!(
+        VendorMask::ANY_HYPERVISOR.to_string(),
+        "Microsoft Hv, KVMKVMKVM, TCGTCGTCGTCG, XenVMMXenVMM, Parallels, VMwareVMware, bhyve bhyve, 0x800000"
+    );
+    assert_eq!(
+        "GenuineIntel,KVMKVMKVM".parse::<VendorMask>().unwrap(),
+        VendorMask::INTEL | VendorMask::KVM
+    );
+    assert!("GenuineIntel, Bogus".parse::<VendorMask>().is_err());
+    assert!("0xbogus".parse::<VendorMask>().is_err());
+
+    let round_trip = |mask: VendorMask| {
+        assert_eq!(mask.to_string().parse::<VendorMask>(), Ok(mask), "{}", mask);
+    };
+    for flag in VendorMask::FLAGS.iter() {
+        round_trip(*flag.value());
+        round_trip(*flag.value() | VendorMask::HYGON);
+        round_trip(*flag.value() | VendorMask::KVM);
+    }
+    round_trip(VendorMask::all());
+    round_trip(VendorMask::from_bits_retain(0xFFFF_FFFF));
+}
+
+#[test]
+fn import_dump_localsystem() {
+    let import = System::from_local().with_decoded();
+    #[cfg(not(target_os = "macos"))]
+    assert_eq!(import.cpu_count, import.cpus.len());
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    assert!(import.vendor.intersects(VendorMask::ANY_CPU));
+    // TODO: more tests here, probably by getting information from other sources and
+    // cross-referencing.
+}
+
+#[test]
+fn leaf_filter() {
+    let filter = LeafFilter::new();
+    assert!(filter.contains(0x0000_0000));
+    assert!(filter.contains(0x4000_0010));
+
+    let filter = LeafFilter::new()
+        .allow(0x0000_0000, 0x0000_0001)
+        .allow(0x0000_0007, 0x0000_0007)
+        .allow_base(0x8000_0000)
+        .deny(0x8000_0002, 0x8000_0004);
+    assert!(filter.contains(0x0000_0001));
+    assert!(!filter.contains(0x0000_0004));
+    assert!(filter.contains(0x0000_0007));
+    assert!(filter.contains(0x8000_0008));
+    assert!(!filter.contains(0x8000_0003));
+    assert!(!filter.contains(0x4000_0000));
+
+    let import = System::from_local_filtered(&filter).with_decoded();
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    assert!(import.vendor.intersects(VendorMask::ANY_CPU));
+    for cpu in import.cpus.iter() {
+        for leaf in cpu.leaves.iter() {
+            assert!(filter.contains(leaf.input.eax), "unexpected leaf {:08x}", leaf.input.eax);
+        }
+    }
+}
+
+#[test]
+fn leaf_filter_amd() {
+    // The leaves documented for the caches, topology and physical core count.
+    let filter = LeafFilter::new()
+        .allow(0x0000_0000, 0x0000_0002)
+        .allow(0x0000_0004, 0x0000_0004)
+        .allow(0x0000_000B, 0x0000_000B)
+        .allow(0x0000_0018, 0x0000_0018)
+        .allow(0x0000_001A, 0x0000_001A)
+        .allow(0x0000_001F, 0x0000_001F)
+        .allow(0x8000_0001, 0x8000_0001)
+        .allow(0x8000_0005, 0x8000_0006)
+        .allow(0x8000_0008, 0x8000_0008)
+        .allow(0x8000_0019, 0x8000_0019)
+        .allow(0x8000_001D, 0x8000_001E);
+    let path = dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt");
+    let full = System::from_file(&path).unwrap().with_decoded();
+    let mut filtered = System::from_file(&path).unwrap();
+    for cpu in filtered.cpus.iter_mut() {
+        // The base leaves are always collected.
+        cpu.leaves
+            .retain(|leaf| leaf.input.eax == 0x8000_0000 || filter.contains(leaf.input.eax));
+        cpu.re_fill();
+    }
+    filtered.decode();
+
+    assert!(filtered.cpus[0].get_subleaf(0x8000_0002, 0).is_none());
+    assert!(filtered.topology.valid());
+    assert_eq!(filtered.topology, full.topology);
+    assert_eq!(filtered.caches.0, full.caches.0);
+    assert_eq!(filtered.cache_domains(), full.cache_domains());
+    assert_eq!(filtered.physical_core_count(), Some(64));
+    assert_eq!(filtered.physical_core_count(), full.physical_core_count());
+}
+
+#[test]
+fn register_bit_ranges() {
+    let registers = Registers::new(0x000806c1, 0x00100800, 0x7ffafbbf, 0xbfebfbff);
+    assert_eq!(registers.bits(RegisterName::EAX, 0, 3), Ok(0x1));
+    assert_eq!(registers.bits(RegisterName::EAX, 0, 31), Ok(0x000806c1));
+    assert_eq!(registers.bits(RegisterName::EDX, 31, 31), Ok(0x1));
+    assert_eq!(registers.bit(RegisterName::EBX, 11), Ok(true));
+    assert_eq!(registers.bit(RegisterName::EBX, 12), Ok(false));
+    assert_eq!(
+        registers.bits(RegisterName::EAX, 4, 32),
+        Err(CpuidError::InvalidBitRange { lo: 4, hi: 32 })
+    );
+    assert_eq!(
+        registers.bits(RegisterName::EAX, 8, 4),
+        Err(CpuidError::InvalidBitRange { lo: 8, hi: 4 })
+    );
+    assert_eq!(registers.bit(RegisterName::ECX, 32), Err(CpuidError::InvalidBitRange { lo: 32, hi: 32 }));
+    assert_eq!(
+        registers.bit(RegisterName::Unknown, 0),
+        Err(CpuidError::InvalidRegister(RegisterName::Unknown))
+    );
+}
+
+#[test]
+fn register_names() {
+    assert_eq!(
+        RegisterName::all(),
+        [RegisterName::EAX, RegisterName::EBX, RegisterName::ECX, RegisterName::EDX]
+    );
+    for register in RegisterName::all().iter() {
+        assert_eq!(register.to_string().parse::<RegisterName>(), Ok(*register));
+    }
+    assert_eq!(RegisterName::EDX.to_string(), "EDX");
+    assert_eq!("ecx".parse::<RegisterName>(), Ok(RegisterName::ECX));
+    assert_eq!(
+        "unknown".parse::<RegisterName>(),
+        Err(ParseRegisterNameError {
+            name: "unknown".to_string()
+        })
+    );
+    assert!("R8".parse::<RegisterName>().is_err());
+}
+
+#[test]
+fn parse_dump_line() {
+    let line = "CPUID 00000004:01 = 1c004122 01c0003f 0000003f 00000000 | \"A..?...?.......";
+    let leaf: RawCPUIDResponse = line.parse().unwrap();
+    assert_eq!(leaf.input, LeafID::new(0x0000_0004, 1));
+    assert_eq!(leaf.output, Registers::new(0x1c00_4122, 0x01c0_003f, 0x0000_003f, 0));
+
+    let line = format!("{}", leaf);
+    assert_eq!(
+        line,
+        "CPUID 00000004:01 = 1c004122 01c0003f 0000003f 00000000 | \"A..?...?......."
+    );
+    assert_eq!(leaf.output.ascii(), "\"A..?...?.......");
+    let reparsed: RawCPUIDResponse = line.parse().unwrap();
+    assert_eq!(reparsed.input, leaf.input);
+    assert_eq!(reparsed.output, leaf.output);
+
+    let bare: RawCPUIDResponse =
+        "  CPUID 80000000:00 = 80000008 00000000 00000000 00000000  ".parse().unwrap();
+    assert_eq!(bare.output.eax, 0x8000_0008);
+
+    for malformed in [
+        "",
+        "CPU 0:",
+        "CPUID 40000000:00 = 00000000 000",
+        "CPUID 40000000 = 00000000 00000000 00000000 00000000",
+        "CPUID 40000000:00 = 00000000 00000000 00000000 0000000g",
+        "CPUID 40000000:00 = 00000000 00000000 00000000 00000000 00000000",
+    ]
+    .iter()
+    {
+        let err = malformed.parse::<RawCPUIDResponse>().unwrap_err();
+        assert_eq!(err, ParseRawCPUIDResponseError { line: malformed.trim().to_string() });
+    }
+}
+
+#[test]
+fn amd_1g_tlbs() {
+    let fully_associative = CacheAssociativity {
+        mapping: CacheAssociativityType::FullyAssociative,
+        ways: 0xFF,
+    };
+    for (path, expected) in [
+        (
+            "AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt",
+            vec![(CacheLevel::L1, CacheType::DataTLB), (CacheLevel::L1, CacheType::CodeTLB)],
+        ),
+        (
+            "AuthenticAMD/AuthenticAMD0A20F10_K19_Vermeer2_CPUID.txt",
+            vec![
+                (CacheLevel::L1, CacheType::DataTLB),
+                (CacheLevel::L1, CacheType::CodeTLB),
+                (CacheLevel::L2, CacheType::DataTLB),
+            ],
+        ),
+    ]
+    .iter()
+    {
+        let import = System::from_file(&dump_path(path)).unwrap().with_decoded();
+        let tlbs: Vec<&CacheDescription> =
+            import.caches.0.iter().filter(|cache| cache.flags.pages_1g()).collect();
+        assert_eq!(tlbs.len(), expected.len());
+        for tlb in tlbs.iter() {
+            assert!(expected.contains(&(tlb.level, tlb.cachetype)));
+            assert_eq!(tlb.size, 64);
+            assert_eq!(tlb.associativity, fully_associative);
+            assert!(!tlb.flags.pages_4k());
+            assert!(!tlb.flags.pages_2m());
+            assert!(!tlb.flags.pages_4m());
+        }
+    }
+}
+
+#[test]
+fn amd_legacy_associativity_from_extended_leaf() {
+    // Hide the Topology Extensions feature bit, so that the caches are
+    // described by the legacy leaf 0x8000_0006, which defers the L3
+    // associativity to leaf 0x8000_001D.
+    let host = System::from_file(&dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt")).unwrap();
+    let mut leaves: Vec<RawCPUIDResponse> = host.cpus[0].leaves.clone();
+    for leaf in leaves.iter_mut() {
+        if leaf.input.eax == 0x8000_0001 {
+            leaf.output.ecx &= !(1 << 22);
+        }
+    }
+
+    let unified = |system: &System, level: CacheLevel| {
+        system
+            .caches
+            .0
+            .iter()
+            .find(|cache| cache.level == level && cache.cachetype == CacheType::Unified)
+            .cloned()
+            .unwrap()
+    };
+
+    let system = System::from_slice(&leaves).with_decoded();
+    assert_eq!(unified(&system, CacheLevel::L3).size, 16384);
+    assert_eq!(unified(&system, CacheLevel::L3).associativity, CacheAssociativity::from_identifier(16));
+    assert_eq!(unified(&system, CacheLevel::L2).size, 512);
+    assert_eq!(unified(&system, CacheLevel::L2).associativity, CacheAssociativity::from_identifier(8));
+
+    let leaves: Vec<RawCPUIDResponse> =
+        leaves.into_iter().filter(|leaf| leaf.input.eax != 0x8000_001D).collect();
+    let system = System::from_slice(&leaves).with_decoded();
+    assert_eq!(unified(&system, CacheLevel::L3).size, 16384);
+    assert_eq!(unified(&system, CacheLevel::L3).associativity.mapping, CacheAssociativityType::Unknown);
+}
+
+#[test]
+fn compare_systems() {
+    let path = dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt");
+    let first = System::from_file(&path).unwrap().with_decoded();
+    let second = System::from_file(&path).unwrap().with_decoded();
+    assert_eq!(first, second);
+    assert_eq!(first.cpus[0], second.cpus[0]);
+    assert_ne!(first.cpus[0], second.cpus[1]);
+
+    let undecoded = System::from_file(&path).unwrap();
+    assert_ne!(first, undecoded);
+
+    let mut modified = System::from_file(&path).unwrap();
+    modified.cpus[1].leaves[0].output.ebx ^= 1;
+    let modified = modified.with_decoded();
+    assert_ne!(first, modified);
+
+    let mut set = std::collections::HashSet::new();
+    set.insert(first);
+    set.insert(second);
+    set.insert(modified);
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn leaf_status() {
+    let system = System::from_file(&dump_path("AuthenticAMD/AuthenticAMD0610F01_K15_Piledriver_CPUID.txt")).unwrap();
+    let cpu = &system.cpus[0];
+    assert_eq!(cpu.leaf_status(0x0000_0000, 0), LeafStatus::Present);
+    // Advertised by leaf 0x8000_0000, but missing from the dump.
+    assert_eq!(cpu.leaf_status(0x8000_001D, 0), LeafStatus::NotCollected);
+    assert_eq!(cpu.leaf_status(0x8000_00FF, 0), LeafStatus::AbsentAboveMax);
+    assert_eq!(cpu.leaf_status(0x0000_0007, 0x42), LeafStatus::AbsentAboveMax);
+    assert_eq!(cpu.leaf_status(0x1234_5678, 0), LeafStatus::AbsentAboveMax);
+
+    // Present leaves may read as all zeros.
+    let system = System::from_file(&dump_path("HygonGenuine/HygonGenuine0900F02_Hygon_CPUID.txt")).unwrap();
+    assert_eq!(system.cpus[0].leaf_status(0x4000_0000, 0), LeafStatus::Present);
+    assert_eq!(system.cpus[0].get_subleaf(0x4000_0000, 0).unwrap().output, Registers::new(0, 0, 0, 0));
+
+    // Features in uncollected leaves are unknown, rather than absent.
+    let mut system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    system.cpus[0].leaves.retain(|leaf| leaf.input.eax != 0x0000_0007);
+    assert_eq!(system.cpus[0].leaf_status(0x0000_0007, 0), LeafStatus::NotCollected);
+    let all = system.cpus[0].all_features(system.vendor);
+    assert!(!all.iter().any(|(feature, _)| feature.leaf.eax == 0x0000_0007));
+    assert!(all.iter().any(|(feature, present)| feature.shortname == "SSE4.2" && *present));
+}
+
+#[test]
+fn all_features_with_absent_bits() {
+    for path in [
+        "GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt",
+        "GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt",
+        "AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt",
+    ]
+    .iter()
+    {
+        let system = System::from_file(&dump_path(path)).unwrap().with_decoded();
+        let all = system.cpus[0].all_features(system.vendor);
+        let present: Vec<(u32, u32, RegisterName, u8)> = all
+            .iter()
+            .filter(|(_, present)| *present)
+            .map(|(f, _)| (f.leaf.eax, f.leaf.ecx, f.register, f.bit))
+            .collect();
+        let decoded: Vec<(u32, u32, RegisterName, u8)> = system
+            .features
+            .0
+            .iter()
+            .map(|f| (f.leaf.eax, f.leaf.ecx, f.register, f.bit))
+            .collect();
+        assert_eq!(present, decoded);
+        assert!(all.len() > present.len());
+    }
+
+    let nehalem = System::from_file(&dump_path("GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt"))
+        .unwrap()
+        .with_decoded();
+    let tigerlake = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let avx512f = |system: &System| {
+        system.cpus[0]
+            .all_features(system.vendor)
+            .into_iter()
+            .find(|(f, _)| f.shortname == "AVX512F")
+            .map(|(_, present)| present)
+    };
+    assert_eq!(avx512f(&nehalem), Some(false));
+    assert_eq!(avx512f(&tigerlake), Some(true));
+}
+
+#[test]
+fn decode_rdt() {
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00406F1_BroadwellE_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let rdt = system.rdt.unwrap();
+    assert_eq!(
+        rdt,
+        RdtInfo {
+            max_rmid: 47,
+            l3_monitoring: Some(RdtMonitoring {
+                max_rmid: 47,
+                upscaling_factor: 24576,
+                counter_width: 24,
+                occupancy: true,
+                total_bandwidth: true,
+                local_bandwidth: true,
+            }),
+            l3_allocation: Some(CacheAllocation {
+                cos_count: 16,
+                cbm_length: 20,
+                shareable_bitmap: 0x000c_0000,
+                cdp: true,
+                noncontiguous_cbm: false,
+            }),
+            l2_allocation: None,
+            memory_bandwidth_allocation: None,
+        }
+    );
+    assert_eq!(rdt.l3_allocation.unwrap().to_string(), "16 COS, 20-bit CBM, CDP");
+
+    let system = System::from_file(&dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let rdt = system.rdt.unwrap();
+    assert_eq!(rdt.max_rmid, 255);
+    assert_eq!(rdt.l3_monitoring.unwrap().upscaling_factor, 64);
+    assert_eq!(rdt.l3_allocation.unwrap().to_string(), "16 COS, 16-bit CBM, CDP");
+
+    // Allocation is supported, but the L2 CAT subleaf wasn't captured.
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(system.rdt, Some(RdtInfo::default()));
+
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(system.rdt, None);
+}
+
+#[test]
+fn avx512_subsets_and_vector_width() {
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(
+        system.avx512_subsets(),
+        vec![
+            "F",
+            "DQ",
+            "IFMA",
+            "CD",
+            "BW",
+            "VL",
+            "VBMI",
+            "VBMI2",
+            "VNNI",
+            "BITALG",
+            "VPOPCNTDQ",
+            "VP2INTERSECT"
+        ]
+    );
+    assert_eq!(system.max_vector_bits(), 512);
+
+    for (path, bits) in [
+        ("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt", 256),
+        ("GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt", 128),
+        ("GenuineIntel/GenuineIntel0000480_486_CPUID.txt", 0),
+    ]
+    .iter()
+    {
+        let system = System::from_file(&dump_path(path)).unwrap().with_decoded();
+        assert!(system.avx512_subsets().is_empty());
+        assert_eq!(system.max_vector_bits(), *bits);
+    }
+}
+
+#[test]
+fn signature_microarchitecture() {
+    for (path, name) in [
+        ("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt", "Zen 2"),
+        ("AuthenticAMD/AuthenticAMD0800F82_K17_ZenP_CPUID.txt", "Zen+"),
+        ("AuthenticAMD/AuthenticAMD0A20F10_K19_Vermeer_CPUID1.txt", "Zen 3"),
+        ("AuthenticAMD/AuthenticAMD0630F01_K15_Kaveri_CPUID.txt", "Steamroller"),
+        ("AuthenticAMD/AuthenticAMD00006A0_K7_Barton_CPUID.txt", "K7"),
+        ("GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt", "Nehalem"),
+        ("GenuineIntel/GenuineIntel0000683_P3_Coppermine_CPUID.txt", "Coppermine"),
+        ("GenuineIntel/GenuineIntel0000F24_P4_Northwood_CPUID.txt", "Northwood"),
+        ("GenuineIntel/GenuineIntel00406E3_Skylake_CPUID.txt", "Skylake"),
+        ("GenuineIntel/GenuineIntel0050657_CascadeLakeSP_CPUID1.txt", "Cascade Lake"),
+        ("GenuineIntel/GenuineIntel00906EA_Coffeelake_CPUID.txt", "Coffee Lake"),
+        ("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt", "Tiger Lake"),
+        ("GenuineIntel/GenuineIntel00506C9_Goldmont_CPUID.txt", "Goldmont"),
+        ("CentaurHauls/CentaurHauls00006FE_CNR_Isaiah_CPUID.txt", "Isaiah"),
+    ]
+    .iter()
+    {
+        let system = System::from_file(&dump_path(path)).unwrap().with_decoded();
+        let cpu = &system.cpus[0];
+        assert_eq!(cpu.signature.microarchitecture(cpu.vendor), Some(*name), "{}", path);
+    }
+
+    let unknown = Signature {
+        family: 0x06,
+        model: 0x02,
+        stepping: 0,
+    };
+    assert_eq!(unknown.microarchitecture(VendorMask::INTEL), None);
+}
+
+#[test]
+fn invariant_tsc() {
+    for (path, invariant) in [
+        ("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt", true),
+        ("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt", true),
+        ("GenuineIntel/GenuineIntel0000F24_P4_Northwood_CPUID.txt", false),
+        ("AuthenticAMD/AuthenticAMD0000F4A_K8_Clawhammer_CPUID.txt", false),
+    ]
+    .iter()
+    {
+        let system = System::from_file(&dump_path(path)).unwrap().with_decoded();
+        assert_eq!(system.cpus[0].invariant_tsc(), *invariant, "{}", path);
+        assert_eq!(
+            system.features.iter().any(|feature| feature.shortname == "TscInvariant"),
+            *invariant,
+            "{}",
+            path
+        );
+    }
+
+    let system = System::from_file(&dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    for shortname in [
+        "TS", "TTP", "HTC", "HwPstate", "CPB", "EffFreqRO", "ConnectedStandby", "RAPL",
+    ]
+    .iter()
+    {
+        assert!(
+            system.features.iter().any(|feature| feature.shortname == *shortname),
+            "{}",
+            shortname
+        );
+    }
+}
+
+#[test]
+fn ras_capabilities() {
+    let system = System::from_file(&dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let ras: Vec<&str> = system
+        .features
+        .iter()
+        .filter(|feature| feature.leaf.eax == 0x8000_0007 && feature.register == RegisterName::EBX)
+        .map(|feature| feature.shortname)
+        .collect();
+    assert_eq!(ras, vec!["McaOverflowRecov", "SUCCOR", "ScalableMca", "PFEH"]);
+
+    // The RAS bits are only defined by AMD.
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert!(!system
+        .features
+        .iter()
+        .any(|feature| feature.leaf.eax == 0x8000_0007 && feature.register == RegisterName::EBX));
+}
+
+#[test]
+fn unknown_feature_bits() {
+    let system = System::from_file(&dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert!(system.unknown_features.is_empty());
+
+    // Set reserved bit 1 of leaf 0x0000_0007 EDX.
+    let leaves: Vec<RawCPUIDResponse> = system.cpus[0]
+        .leaves
+        .iter()
+        .map(|leaf| {
+            let mut leaf = leaf.clone();
+            if leaf.input == (LeafID { eax: 0x0000_0007, ecx: 0 }) {
+                leaf.output.edx |= 1 << 1;
+            }
+            leaf
+        })
+        .collect();
+    let modified = System::from_slice(&leaves).with_decoded();
+    assert_eq!(
+        modified.unknown_features,
+        vec![(LeafID { eax: 0x0000_0007, ecx: 0 }, RegisterName::EDX, 1)]
+    );
+    assert_eq!(modified.features.len(), system.features.len());
+
+    let system = System::from_file(&dump_path("AuthenticAMD/AuthenticAMD0000F4A_K8_Clawhammer_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    assert_eq!(
+        system.unknown_features,
+        vec![(LeafID { eax: 0x8000_0001, ecx: 0 }, RegisterName::EDX, 30)]
+    );
+}
+
+#[test]
+fn decode_amd_qos() {
+    let system = System::from_file(&dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt"))
+        .unwrap()
+        .with_decoded();
+    let qos = system.amd_qos.clone().unwrap();
+    assert_eq!(
+        qos,
+        AmdQosInfo {
+            l3_mbe: true,
+            l3_smbe: false,
+            bmec: false,
+            l3_bandwidth_enforcement: None,
+        }
+    );
+    assert_eq!(qos.to_string(), "L3MBE");
+
+    // Subleaf 1 isn't populated in the dump, so fill in the enforcement limits.
+    let leaves: Vec<RawCPUIDResponse> = system.cpus[0]
+        .leaves
+        .iter()
+        .map(|leaf| {
+            let mut leaf = leaf.clone();
+            if leaf.input == (LeafID { eax: 0x8000_0020, ecx: 1 }) {
+                leaf.output.eax = 0x0000_000b;
+                leaf.output.edx = 0x0000_000f;
+            }
+            leaf
+        })
+        .collect();
+    let qos = System::from_slice(&leaves).with_decoded().amd_qos.unwrap();
+    assert_eq!(
+        qos.l3_bandwidth_enforcement,
+        Some(BandwidthEnforcement {
+            cos_count: 16,
+            bandwidth_length: 11,
+        })
+    );
+    assert_eq!(qos.to_string(), "L3MBE (16 COS, 11-bit bandwidth)");
+
+    for path in [
+        "AuthenticAMD/AuthenticAMD0800F11_K17_Zen_CPUID4.txt",
+        "GenuineIntel/GenuineIntel00406F1_BroadwellE_CPUID.txt",
+    ]
+    .iter()
+    {
+        let system = System::from_file(&dump_path(path)).unwrap().with_decoded();
+        assert_eq!(system.amd_qos, None);
+    }
 }
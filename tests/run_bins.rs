@@ -46,6 +46,219 @@ fn decode_on_existing_dump() -> Result<(), Box<dyn std::error::Error>> {
             "GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID3.txt",
         ))
         .assert()
+        .stdout(predicate::str::contains("\nRunning under hypervisor: no\n"))
+        .success();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "build-binaries")]
+fn decode_selected_cpu() -> Result<(), Box<dyn std::error::Error>> {
+    let path = dump_path("GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt");
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("-f").arg(&path).arg("--cpu").arg("3").assert().success();
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("-f")
+        .arg(&path)
+        .arg("--cpu")
+        .arg("4096")
+        .assert()
+        .stderr(
+            predicate::str::contains("CPU 4096 does not exist")
+                .and(predicate::str::contains("panicked").not()),
+        )
+        .failure();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "build-binaries")]
+fn decode_feature_category() -> Result<(), Box<dyn std::error::Error>> {
+    let path = dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt");
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("-f")
+        .arg(&path)
+        .arg("--category")
+        .arg("crypto")
+        .assert()
+        .stdout(predicate::str::contains("AES-NI").and(predicate::str::contains("AVX2").not()))
+        .success();
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("-f")
+        .arg(&path)
+        .arg("--category")
+        .arg("bogus")
+        .assert()
+        .stderr(
+            predicate::str::contains("unknown feature category")
+                .and(predicate::str::contains("panicked").not()),
+        )
+        .failure();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "build-binaries")]
+fn decode_verbose_rdt() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("-v")
+        .arg("-f")
+        .arg(dump_path("GenuineIntel/GenuineIntel00406F1_BroadwellE_CPUID.txt"))
+        .assert()
+        .stdout(
+            predicate::str::contains(
+                "   L3 Monitoring: 48 RMIDs, occupancy, total bandwidth, local bandwidth\n",
+            )
+            .and(predicate::str::contains("          L3 CAT: 16 COS, 20-bit CBM, CDP\n"))
+            .and(predicate::str::contains("L2 CAT: ").not())
+            .and(predicate::str::contains(" MBA: ").not()),
+        )
+        .success();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "build-binaries")]
+fn decode_csv() -> Result<(), Box<dyn std::error::Error>> {
+    let path = dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt");
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("-f")
+        .arg(&path)
+        .arg("--format")
+        .arg("csv")
+        .assert()
+        .stdout(
+            predicate::str::starts_with("cpu,leaf,register,bit,shortname,name\n")
+                .and(predicate::str::contains(
+                    "\n0,00000007:00,EBX,5,AVX2,Advanced Vector Extensions 2.0\n",
+                ))
+                .and(predicate::str::contains("Vendor(s)").not()),
+        )
+        .success();
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("-f")
+        .arg(&path)
+        .arg("--format")
+        .arg("bogus")
+        .assert()
+        .stderr(
+            predicate::str::contains("unknown output format").and(predicate::str::contains("panicked").not()),
+        )
+        .failure();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "build-binaries")]
+fn decode_verbose_amd_qos() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("-v")
+        .arg("-f")
+        .arg(dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt"))
+        .assert()
+        .stdout(
+            predicate::str::contains(" QoS Enforcement: L3MBE\n").and(predicate::str::contains(
+                "  Mem Encryption: SME, SEV, SEV-ES, C-bit 47, 5-bit address reduction, 509 encrypted guests\n",
+            )),
+        )
+        .success();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "build-binaries")]
+fn decode_verbose_apic_ids() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("-v")
+        .arg("-f")
+        .arg(dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .assert()
+        .stdout(predicate::str::contains("Logical CPU APIC IDs:\n  CPU 0: 0x0\n  CPU 1: 0x1\n"))
+        .success();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "build-binaries")]
+fn decode_verbose_validation_warnings() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("-v")
+        .arg("-f")
+        .arg(dump_path("AuthenticAMD/AuthenticAMD0610F01_K15_Piledriver_CPUID.txt"))
+        .assert()
+        .stdout(predicate::str::contains(
+            "Validation warnings:\n  CPU 0: leaf 80000001:00 was captured more than once\n",
+        ))
+        .success();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "build-binaries")]
+fn decode_topology_tree() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("--tree")
+        .arg("-f")
+        .arg(dump_path("GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt"))
+        .assert()
+        .stdout(predicate::str::starts_with("Socket 0\n  Core 0\n    Thread 0: CPU 0\n"))
+        .success();
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("--tree")
+        .arg("-f")
+        .arg(dump_path("CyrixInstead/CyrixInstead0000520_6x86_CPUID.txt"))
+        .assert()
+        .stdout("Topology IDs are not available\n")
+        .success();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "build-binaries")]
+fn decode_verbose_unknown_features() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("-v")
+        .arg("-f")
+        .arg(dump_path("AuthenticAMD/AuthenticAMD0000F4A_K8_Clawhammer_CPUID.txt"))
+        .assert()
+        .stdout(predicate::str::contains(
+            "\nUnknown feature bits:\n  Leaf 80000001:00, register EDX, bit 30\n",
+        ))
+        .success();
+
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("-v")
+        .arg("-f")
+        .arg(dump_path("GenuineIntel/GenuineIntel00806C1_TigerLake_CPUID.txt"))
+        .assert()
+        .stdout(predicate::str::contains("Unknown feature bits").not())
+        .success();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "build-binaries")]
+fn decode_verbose_address_sizes() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("-v")
+        .arg("-f")
+        .arg(dump_path("AuthenticAMD/AuthenticAMD0830F10_K17_Rome_CPUID.txt"))
+        .assert()
+        .stdout(
+            predicate::str::contains("   Address Sizes: 48-bit physical, 48-bit virtual\n")
+                .and(predicate::str::contains("  Physical Cores: 64\n")),
+        )
+        .success();
+    let mut cmd = Command::cargo_bin("decode")?;
+    cmd.arg("-v")
+        .arg("-f")
+        .arg(dump_path("GenuineIntel/GenuineIntel0000480_486_CPUID.txt"))
+        .assert()
+        .stdout(
+            predicate::str::contains("Address Sizes")
+                .not()
+                .and(predicate::str::contains("Physical Cores").not()),
+        )
         .success();
     Ok(())
 }
@@ -63,3 +276,60 @@ fn dump_generates_identical_dump() -> Result<(), Box<dyn std::error::Error>> {
         .success();
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "build-binaries")]
+fn dump_without_ascii() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("dump")?;
+    cmd.arg("--no-ascii")
+        .arg("-f")
+        .arg(dump_path("GenuineIntel/GenuineIntel00006F6_Merom_CPUID.txt"))
+        .assert()
+        .stdout(predicate::str::contains("CPUID 00000000:00 = ").and(predicate::str::contains("|").not()))
+        .success();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "build-binaries")]
+fn dump_sorted() -> Result<(), Box<dyn std::error::Error>> {
+    let path = dump_path("AuthenticAMD/AuthenticAMD0670F00_K15_StoneyRidge_CPUID.txt");
+    let mut cmd = Command::cargo_bin("dump")?;
+    cmd.arg("--sort")
+        .arg("--no-ascii")
+        .arg("-f")
+        .arg(&path)
+        .assert()
+        .stdout(predicate::str::contains(
+            "CPUID 80000001:00 = 00670f00 00000000 2fabbfff 2fd3fbff\n\
+             CPUID 80000001:00 = 00000000 00000000 00000000 00000000\n\
+             CPUID 80000002:00 = ",
+        ))
+        .success();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "build-binaries")]
+fn dump_selected_cpus() -> Result<(), Box<dyn std::error::Error>> {
+    let path = dump_path("GenuineIntel/GenuineIntel00106A2_Nehalem-EP_CPUID_2.txt");
+    let mut cmd = Command::cargo_bin("dump")?;
+    cmd.arg("-f")
+        .arg(&path)
+        .arg("--cpu")
+        .arg("1-2,5")
+        .assert()
+        .stdout(
+            predicate::str::contains("CPU 1:\n")
+                .and(predicate::str::contains("CPU 2:\n"))
+                .and(predicate::str::contains("CPU 5:\n"))
+                .and(predicate::str::contains("CPU 0:\n").not())
+                .and(predicate::str::contains("CPU 3:\n").not()),
+        )
+        .success();
+    let mut cmd = Command::cargo_bin("dump")?;
+    cmd.arg("-f").arg(&path).arg("--cpu").arg("bogus").assert().failure();
+    let mut cmd = Command::cargo_bin("dump")?;
+    cmd.arg("-f").arg(&path).arg("--cpu").arg("4096").assert().failure();
+    Ok(())
+}